@@ -0,0 +1,248 @@
+use composure_core::models::{
+    parse_interaction_strict, Interaction, InteractionEnvelope, InteractionResponse,
+};
+
+use crate::{Error, Result};
+
+/// Largest body accepted by default, real interactions are a few kilobytes
+pub const DEFAULT_MAX_BODY_SIZE: usize = 256 * 1024;
+
+/// Rejects a request announcing a body over `max` before any of it is read
+///
+/// A missing header is let through, the body's actual size is checked once read.
+pub(crate) fn check_content_length(content_length: Option<&str>, max: usize) -> Result<()> {
+    let length = match content_length {
+        Some(length) => length
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| Error::InvalidBody("invalid Content-Length".to_string()))?,
+        None => return Ok(()),
+    };
+
+    check_size(length, max)
+}
+
+fn check_size(size: usize, max: usize) -> Result<()> {
+    if size > max {
+        Err(Error::BodyTooLarge { size, max })
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks the Ed25519 signature Discord sends with every interaction
+pub(crate) fn verify_signature(
+    public_key: &str,
+    signature: Option<&str>,
+    timestamp: Option<&str>,
+    body: &[u8],
+) -> Result<()> {
+    let (signature, timestamp) = signature.zip(timestamp).ok_or(Error::ValidationError)?;
+
+    composure_core::auth::validate_request(public_key, signature, timestamp, body)
+        .map_err(|_| Error::ValidationError)
+}
+
+/// Size check then `validate`, the size is checked first so oversized bodies are never hashed
+fn verify(body: &[u8], max: usize, validate: impl FnOnce(&[u8]) -> Result<()>) -> Result<&str> {
+    check_size(body.len(), max)?;
+    validate(body)?;
+
+    if body.is_empty() {
+        return Err(Error::InvalidBody("empty body".to_string()));
+    }
+
+    std::str::from_utf8(body).map_err(|_| Error::InvalidBody("body is not UTF-8".to_string()))
+}
+
+/// Size check, `validate`, then parsing, everything a body goes through before reaching a handler
+///
+/// Returns the interaction along with the paths of fields the models don't know about when
+/// `strict`.
+pub(crate) fn verify_and_parse(
+    body: &[u8],
+    max: usize,
+    strict: bool,
+    validate: impl FnOnce(&[u8]) -> Result<()>,
+) -> Result<(Interaction, Vec<String>)> {
+    let body = verify(body, max, validate)?;
+
+    if strict {
+        parse_interaction_strict(body)
+    } else {
+        serde_json::from_str(body).map(|interaction| (interaction, Vec::new()))
+    }
+    .map_err(|e| Error::InvalidBody(e.to_string()))
+}
+
+/// [verify_and_parse] for raw handlers, only the envelope is parsed
+pub(crate) fn verify_and_parse_envelope(
+    body: &[u8],
+    max: usize,
+    validate: impl FnOnce(&[u8]) -> Result<()>,
+) -> Result<InteractionEnvelope> {
+    let body = verify(body, max, validate)?;
+
+    InteractionEnvelope::parse(body.as_bytes()).map_err(|e| Error::InvalidBody(e.to_string()))
+}
+
+/// JSON sent back to Discord, failures are kept apart from handler errors
+pub(crate) fn serialize_response(response: &InteractionResponse) -> Result<String> {
+    response.to_json().map_err(Error::ResponseSerialization)
+}
+
+#[cfg(test)]
+mod tests {
+    use composure_core::models::{ActionRow, Component, ModalCallbackData};
+    use ed25519_dalek::{ExpandedSecretKey, PublicKey, SecretKey};
+
+    use super::*;
+
+    const TIMESTAMP: &str = "1682372142";
+    const PING: &[u8] = br#"{"id":"700000000000000001","application_id":"100000000000000001","type":1,"token":"A_UNIQUE_TOKEN","version":1}"#;
+
+    /// Public key and a signature over `body`, as Discord would send them
+    fn sign(body: &[u8]) -> (String, String) {
+        let secret = SecretKey::from_bytes(&[7; 32]).unwrap();
+        let public = PublicKey::from(&secret);
+        let message = [TIMESTAMP.as_bytes(), body].concat();
+        let signature = ExpandedSecretKey::from(&secret).sign(&message, &public);
+
+        (
+            hex::encode(public.as_bytes()),
+            hex::encode(signature.to_bytes()),
+        )
+    }
+
+    /// Runs `body` through the pipeline with a valid signature
+    fn signed(body: &[u8], max: usize) -> Result<(Interaction, Vec<String>)> {
+        let (public_key, signature) = sign(body);
+
+        verify_and_parse(body, max, false, |body| {
+            verify_signature(&public_key, Some(&signature), Some(TIMESTAMP), body)
+        })
+    }
+
+    #[test]
+    pub fn content_length() {
+        let cases = [
+            (None, None),
+            (Some("0"), None),
+            (Some("1024"), None),
+            (Some(" 1024 "), None),
+            (Some("1025"), Some(413)),
+            (Some("99999999999999999999999"), Some(400)),
+            (Some("-1"), Some(400)),
+            (Some(""), Some(400)),
+        ];
+
+        for (header, status) in cases {
+            let res = check_content_length(header, 1024);
+
+            assert_eq!(res.err().map(|e| e.status()), status, "{header:?}");
+        }
+    }
+
+    #[test]
+    pub fn valid_ping() {
+        let (interaction, unknown) = signed(PING, DEFAULT_MAX_BODY_SIZE).unwrap();
+
+        assert!(matches!(interaction, Interaction::Ping(_)));
+        assert!(unknown.is_empty());
+    }
+
+    #[test]
+    pub fn oversized_body_is_not_validated() {
+        let body = vec![b' '; DEFAULT_MAX_BODY_SIZE + 1];
+
+        let res = verify_and_parse(&body, DEFAULT_MAX_BODY_SIZE, false, |_| {
+            panic!("oversized body reached validation")
+        });
+
+        let err = res.unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::BodyTooLarge { size, max }
+                if size == DEFAULT_MAX_BODY_SIZE + 1 && max == DEFAULT_MAX_BODY_SIZE
+        ));
+        assert_eq!(err.status(), 413);
+    }
+
+    #[test]
+    pub fn signed_empty_body() {
+        let err = signed(b"", DEFAULT_MAX_BODY_SIZE).unwrap_err();
+
+        assert!(matches!(&err, Error::InvalidBody(reason) if reason == "empty body"));
+        assert_eq!(err.status(), 400);
+    }
+
+    #[test]
+    pub fn signed_invalid_utf8() {
+        let body = [b'{', 0xff, 0xfe, 0x80, b'}'];
+
+        for strict in [false, true] {
+            let (public_key, signature) = sign(&body);
+            let err = verify_and_parse(&body, DEFAULT_MAX_BODY_SIZE, strict, |body| {
+                verify_signature(&public_key, Some(&signature), Some(TIMESTAMP), body)
+            })
+            .unwrap_err();
+
+            assert!(matches!(&err, Error::InvalidBody(reason) if reason == "body is not UTF-8"));
+            assert_eq!(err.status(), 400);
+        }
+    }
+
+    #[test]
+    pub fn signed_malformed_json() {
+        for body in [&b"{"[..], b"null", b"[]", br#"{"type": 99}"#] {
+            let err = signed(body, DEFAULT_MAX_BODY_SIZE).unwrap_err();
+
+            assert!(matches!(err, Error::InvalidBody(_)), "{err:?}");
+            assert_eq!(err.status(), 400);
+        }
+    }
+
+    #[test]
+    pub fn missing_or_bad_signature() {
+        let (public_key, signature) = sign(PING);
+
+        let cases = [
+            (None, Some(TIMESTAMP)),
+            (Some(signature.as_str()), None),
+            (Some("not hex"), Some(TIMESTAMP)),
+            (Some(signature.as_str()), Some("1682372143")),
+        ];
+
+        for (signature, timestamp) in cases {
+            let res = verify_and_parse(PING, DEFAULT_MAX_BODY_SIZE, false, |body| {
+                verify_signature(&public_key, signature, timestamp, body)
+            });
+
+            assert!(matches!(res, Err(Error::ValidationError)));
+        }
+
+        assert_eq!(Error::ValidationError.status(), 401);
+    }
+
+    #[test]
+    pub fn response_serialization() {
+        let json = serialize_response(&InteractionResponse::Pong).unwrap();
+        assert_eq!(json, r#"{"type":1}"#);
+
+        let modal = InteractionResponse::Modal(
+            ModalCallbackData::new("feedback", "Feedback").with_components(vec![ActionRow::new(
+                vec![Component::link_button(
+                    "Docs".to_string(),
+                    "https://discord.com/developers/docs".to_string(),
+                )],
+            )
+            .into()]),
+        );
+
+        let err = serialize_response(&modal).unwrap_err();
+
+        assert!(matches!(err, Error::ResponseSerialization(_)));
+        assert_eq!(err.status(), 500);
+    }
+}
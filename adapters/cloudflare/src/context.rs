@@ -0,0 +1,83 @@
+use composure_core::{models::Snowflake, util::InteractionResponder};
+use worker::Env;
+
+/// Everything a handler gets alongside the interaction
+pub struct InteractionContext<'a> {
+    /// The worker's bindings, secrets and variables
+    pub env: &'a Env,
+
+    /// Id of the application, from [CloudflareInteractionBot::with_application_info](crate::CloudflareInteractionBot::with_application_info) or the interaction
+    pub application_id: Snowflake,
+
+    /// Id of the application's bot user, only known if set with [CloudflareInteractionBot::with_application_info](crate::CloudflareInteractionBot::with_application_info)
+    pub bot_user_id: Option<Snowflake>,
+
+    responder: InteractionResponder,
+}
+
+impl<'a> InteractionContext<'a> {
+    pub(crate) fn new(
+        env: &'a Env,
+        info: Option<&ApplicationInfo>,
+        interaction_application_id: &Snowflake,
+        responder: InteractionResponder,
+    ) -> Self {
+        let (application_id, bot_user_id) = resolve(info, interaction_application_id);
+
+        InteractionContext {
+            env,
+            application_id,
+            bot_user_id,
+            responder,
+        }
+    }
+
+    /// Answers the interaction, the request is answered with its response instead of the one
+    /// the handler returns
+    pub fn responder(&self) -> InteractionResponder {
+        self.responder.clone()
+    }
+
+    /// true if `id` is the bot user, for ignoring the bot's own mentions
+    pub fn is_bot_user(&self, id: &Snowflake) -> bool {
+        self.bot_user_id.as_ref() == Some(id)
+    }
+}
+
+/// Ids given to [CloudflareInteractionBot::with_application_info](crate::CloudflareInteractionBot::with_application_info)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ApplicationInfo {
+    pub application_id: Snowflake,
+    pub bot_user_id: Snowflake,
+}
+
+/// Configured ids win, otherwise only the application id is known from the interaction
+fn resolve(
+    info: Option<&ApplicationInfo>,
+    interaction_application_id: &Snowflake,
+) -> (Snowflake, Option<Snowflake>) {
+    match info {
+        Some(info) => (info.application_id.clone(), Some(info.bot_user_id.clone())),
+        None => (interaction_application_id.clone(), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn resolve_application_info() {
+        let interaction = Snowflake::from_u64(100000000000000001);
+        let info = ApplicationInfo {
+            application_id: Snowflake::from_u64(100000000000000002),
+            bot_user_id: Snowflake::from_u64(100000000000000003),
+        };
+
+        assert_eq!(
+            resolve(Some(&info), &interaction),
+            (info.application_id.clone(), Some(info.bot_user_id.clone()))
+        );
+        assert_eq!(resolve(None, &interaction), (interaction, None));
+    }
+}
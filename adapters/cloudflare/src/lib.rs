@@ -1,9 +1,22 @@
 use async_trait::async_trait;
-use composure::models::{
-    ApplicationCommandInteraction, Embed, Interaction, InteractionResponse,
-    MessageComponentInteraction,
+use composure_core::{
+    models::{
+        ApplicationCommandInteraction, Embed, Interaction, InteractionResponse,
+        MessageComponentInteraction, ModalSubmitInteraction, Snowflake,
+    },
+    util::{BotConfig, HandlerError, HandlerResult, OptionRules},
 };
-use worker::{console_debug, console_error, console_warn, Env, Headers, Request, Response};
+use worker::{Env, Headers, Request, Response};
+
+mod body;
+mod context;
+mod log;
+mod pipeline;
+
+pub use body::DEFAULT_MAX_BODY_SIZE;
+pub use context::InteractionContext;
+pub use log::{ConsoleLogger, LogLevel, Logger};
+pub use pipeline::{Pipeline, PipelineResponse};
 
 #[derive(Debug)]
 pub enum Error {
@@ -11,6 +24,72 @@ pub enum Error {
     ValidationError,
     WorkerError(worker::Error),
     NoCommandHandler,
+
+    /// the body, or its Content-Length, is over the bot's maximum body size
+    BodyTooLarge {
+        size: usize,
+        max: usize,
+    },
+
+    /// the body is empty, not UTF-8 or not an interaction
+    InvalidBody(String),
+
+    /// the handler's response couldn't be serialized, like a modal with components other than text inputs
+    ResponseSerialization(serde_json::Error),
+}
+
+impl Error {
+    /// HTTP status the bot responds with when a request fails with this error
+    pub fn status(&self) -> u16 {
+        match self {
+            Error::ValidationError => 401,
+            Error::BodyTooLarge { .. } => 413,
+            Error::InvalidBody(_) => 400,
+            _ => 500,
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::CommandNotFound(name) => write!(f, "command not found: {name}"),
+            Error::ValidationError => write!(f, "validation failed"),
+            Error::WorkerError(e) => write!(f, "worker error: {e}"),
+            Error::NoCommandHandler => write!(f, "no command handler"),
+            Error::BodyTooLarge { size, max } => {
+                write!(f, "body of {size} bytes is over the {max} byte limit")
+            }
+            Error::InvalidBody(reason) => write!(f, "invalid body: {reason}"),
+            Error::ResponseSerialization(e) => write!(f, "failed to serialize response: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::WorkerError(e) => Some(e),
+            Error::ResponseSerialization(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for composure_core::Error {
+    fn from(err: Error) -> Self {
+        composure_core::Error::Cloudflare(Box::new(err))
+    }
+}
+
+/// Unwraps worker errors, everything else becomes a [worker::Error::RustError]
+impl From<Error> for worker::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::WorkerError(e) => e,
+            err => worker::Error::RustError(err.to_string()),
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -26,28 +105,56 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub fn validate_request(env: &Env, headers: &Headers, body: &[u8]) -> Result<()> {
     let signature = headers
         .get("X-Signature-Ed25519")
-        .map_err(|e| Error::WorkerError(e))?
-        .expect("Missing Ed25519");
+        .map_err(Error::WorkerError)?;
 
     let timestamp = headers
         .get("X-Signature-Timestamp")
-        .map_err(|e| Error::WorkerError(e))?
-        .expect("Missing Timestamp");
+        .map_err(Error::WorkerError)?;
 
     let public_key = env
         .secret("DISCORD_PUBLIC_KEY")
         .map_err(|e| Error::WorkerError(e))?
         .to_string();
 
-    composure::auth::validate_request(&public_key, &signature, &timestamp, body)
-        .map_err(|_| Error::ValidationError)
+    body::verify_signature(
+        &public_key,
+        signature.as_deref(),
+        timestamp.as_deref(),
+        body,
+    )
 }
 
 /// Interaction bot for Cloudflare
+///
+/// `skip_validation` only exists in debug builds with the `dev` feature, so signature checks
+/// can't be turned off in a deployed worker
+///
+#[cfg_attr(
+    not(all(feature = "dev", debug_assertions)),
+    doc = r#"```compile_fail
+use composure_adapter_cloudflare::{CloudflareCommandHandler, CloudflareInteractionBot};
+
+fn local<F: CloudflareCommandHandler>(bot: CloudflareInteractionBot<F>) -> CloudflareInteractionBot<F> {
+    bot.skip_validation()
+}
+```"#
+)]
+#[cfg_attr(
+    all(feature = "dev", debug_assertions),
+    doc = r#"```
+use composure_adapter_cloudflare::{CloudflareCommandHandler, CloudflareInteractionBot};
+
+fn local<F: CloudflareCommandHandler>(bot: CloudflareInteractionBot<F>) -> CloudflareInteractionBot<F> {
+    bot.skip_validation()
+}
+```"#
+)]
 pub struct CloudflareInteractionBot<F: CloudflareCommandHandler + 'static> {
     req: Request,
     env: Env,
     handler: Option<F>,
+    pipeline: Pipeline,
+    application: Option<context::ApplicationInfo>,
 }
 
 impl<F: CloudflareCommandHandler + 'static> CloudflareInteractionBot<F> {
@@ -57,6 +164,8 @@ impl<F: CloudflareCommandHandler + 'static> CloudflareInteractionBot<F> {
             req,
             env,
             handler: None,
+            pipeline: Pipeline::new(),
+            application: None,
         }
     }
 
@@ -65,79 +174,243 @@ impl<F: CloudflareCommandHandler + 'static> CloudflareInteractionBot<F> {
         self
     }
 
+    /// Ids handed to handlers in [InteractionContext], interactions only carry the application id
+    pub fn with_application_info(
+        mut self,
+        application_id: Snowflake,
+        bot_user_id: Snowflake,
+    ) -> Self {
+        self.application = Some(context::ApplicationInfo {
+            application_id,
+            bot_user_id,
+        });
+        self
+    }
+
+    /// Applies a [BotConfig]
+    ///
+    /// Disabled commands are answered with an ephemeral "temporarily disabled" message, in
+    /// maintenance mode everyone but the owners gets a maintenance message, and
+    /// `ephemeral_by_default` makes every message the handler sends ephemeral.
+    pub fn with_config(mut self, config: &BotConfig) -> Self {
+        self.pipeline = self.pipeline.with_config(config);
+        self
+    }
+
+    /// Answers the command called `name` with the first rule its options break, see
+    /// [Pipeline::with_option_rules]
+    pub fn with_option_rules(mut self, name: impl Into<String>, rules: OptionRules) -> Self {
+        self.pipeline = self.pipeline.with_option_rules(name, rules);
+        self
+    }
+
+    /// Answers the command called `name` with an ephemeral message unless the app is installed
+    /// to the guild it was used in, see [Pipeline::guild_install_only]
+    pub fn guild_install_only(mut self, name: impl Into<String>) -> Self {
+        self.pipeline = self.pipeline.guild_install_only(name);
+        self
+    }
+
+    /// Largest body in bytes the bot accepts, defaults to [DEFAULT_MAX_BODY_SIZE]
+    ///
+    /// Larger requests are answered with 413 before their signature is checked or they are parsed.
+    pub fn with_max_body_size(mut self, max_body_size: usize) -> Self {
+        self.pipeline = self.pipeline.with_max_body_size(max_body_size);
+        self
+    }
+
+    /// Logs fields the models don't know about at debug level, useful for spotting API changes
+    pub fn with_strict_parsing(mut self) -> Self {
+        self.pipeline = self.pipeline.with_strict_parsing();
+        self
+    }
+
+    /// Enables debug logs, only warnings and errors are logged otherwise
+    pub fn debug(self, debug: bool) -> Self {
+        self.with_log_level(match debug {
+            true => LogLevel::Debug,
+            false => LogLevel::Warn,
+        })
+    }
+
+    pub fn with_log_level(mut self, level: LogLevel) -> Self {
+        self.pipeline = self.pipeline.with_log_level(level);
+        self
+    }
+
+    /// Routes logs somewhere other than the worker console
+    pub fn with_logger(mut self, logger: impl Logger + 'static) -> Self {
+        self.pipeline = self.pipeline.with_logger(logger);
+        self
+    }
+
+    /// **Dangerous**: accepts requests without checking their signature, for replaying captured
+    /// payloads locally without Discord's keys
+    ///
+    /// Only available in debug builds with the `dev` feature.
+    #[cfg(all(feature = "dev", debug_assertions))]
+    pub fn skip_validation(mut self) -> Self {
+        self.pipeline = self.pipeline.skip_validation();
+        self
+    }
+
     pub async fn process(mut self) -> worker::Result<Response> {
-        console_debug!("Processing request");
+        let content_length = self.req.headers().get("Content-Length")?;
+        if let Some(rejection) = self
+            .pipeline
+            .check_content_length(content_length.as_deref())?
+        {
+            return into_response(rejection);
+        }
 
         let bytes = self.req.bytes().await?;
-        let validation = validate_request(&self.env, self.req.headers(), &bytes);
-
-        if let Err(err) = validation {
-            match err {
-                Error::ValidationError => {
-                    console_warn!("Validation failed");
-                    return Response::error("Validation failed", 401);
-                }
-                Error::WorkerError(e) => {
-                    console_error!("Worker error: {}", e);
-                    // passing error up
-                    return Err(e);
-                }
-                _ => {
-                    console_error!("Unknown error: {:?}", err);
-                    return Response::error("Unknown error", 500);
-                }
-            }
-        }
 
-        // console_debug!("{}", str::from_utf8(&bytes).unwrap());
-
-        let interaction: Interaction = serde_json::from_slice(&bytes)?;
-
-        // console_debug!("Interaction: {:#?}", interaction);
-
-        let interaction_response = match interaction {
-            Interaction::Ping(_) => Ok(InteractionResponse::Pong),
-            Interaction::ApplicationCommand(command) => match self.handler {
-                Some(handler) => handler.command(command).await,
-                None => Ok(InteractionResponse::respond_with_embed(
-                    Embed::new()
-                        .with_title("No command handler")
-                        .with_color(0xf04747),
-                )),
-            },
-            Interaction::MessageComponent(component) => match self.handler {
-                Some(handler) => handler.component(component).await,
-                None => Ok(InteractionResponse::respond_with_embed(
-                    Embed::new()
-                        .with_title("No component handler")
-                        .with_color(0xf04747),
-                )),
-            },
-            Interaction::ApplicationCommandAutocomplete(_) => todo!(),
-            Interaction::ModalSubmit(_) => todo!(),
-        };
-
-        match interaction_response {
-            Ok(interaction_response) => Response::from_json(&interaction_response),
-            Err(e) => match e {
-                _ => {
-                    console_error!("Unknown error: {:?}", e);
-                    Response::error("Unknown error", 400)
-                }
-            },
-        }
+        let headers = self.req.headers();
+        let env = &self.env;
+        let application = self.application.as_ref();
+        let handler = self.handler.as_ref();
+
+        let response = self
+            .pipeline
+            .process(
+                &bytes,
+                |name| headers.get(name).map_err(Error::WorkerError),
+                || {
+                    Ok(env
+                        .secret("DISCORD_PUBLIC_KEY")
+                        .map_err(Error::WorkerError)?
+                        .to_string())
+                },
+                |interaction, responder| async move {
+                    let ctx = InteractionContext::new(
+                        env,
+                        application,
+                        &interaction.common().application_id,
+                        responder,
+                    );
+
+                    match interaction {
+                        Interaction::ApplicationCommand(command) => match handler {
+                            Some(handler) => handler.command(command, &ctx).await,
+                            None => Ok(InteractionResponse::respond_with_embed(
+                                Embed::new()
+                                    .with_title("No command handler")
+                                    .with_color(0xf04747),
+                            )),
+                        },
+                        Interaction::MessageComponent(component) => match handler {
+                            Some(handler) => handler.component(component, &ctx).await,
+                            None => Ok(InteractionResponse::respond_with_embed(
+                                Embed::new()
+                                    .with_title("No component handler")
+                                    .with_color(0xf04747),
+                            )),
+                        },
+                        Interaction::ApplicationCommandAutocomplete(command) => match handler {
+                            Some(handler) => handler.autocomplete(command, &ctx).await,
+                            None => Ok(InteractionResponse::respond_with_autocomplete_choices(
+                                vec![],
+                            )),
+                        },
+                        Interaction::ModalSubmit(modal) => match handler {
+                            Some(handler) => handler.modal_submit(modal, &ctx).await,
+                            None => Err(HandlerError::NotFound("Modal handler".to_string())),
+                        },
+                        Interaction::Ping(_) => Ok(InteractionResponse::Pong),
+                    }
+                },
+            )
+            .await?;
+
+        into_response(response)
     }
 }
 
-#[async_trait]
+/// Worker response for what the pipeline answered
+fn into_response(response: PipelineResponse) -> worker::Result<Response> {
+    if !response.json {
+        return Response::error(response.body, response.status);
+    }
+
+    let mut headers = Headers::new();
+    headers.set("Content-Type", "application/json")?;
+
+    Ok(Response::ok(response.body)?
+        .with_headers(headers)
+        .with_status(response.status))
+}
+
+/// Handles interactions for [CloudflareInteractionBot]
+///
+/// Workers are single threaded and [InteractionContext] borrows the worker's `Env`, so implement
+/// this with `#[async_trait(?Send)]`. A [HandlerError] other than `Internal` is answered with an
+/// ephemeral message, wrap a [worker::Error] with [HandlerError::internal].
+#[async_trait(?Send)]
 pub trait CloudflareCommandHandler {
     async fn command(
         &self,
         command: ApplicationCommandInteraction,
-    ) -> worker::Result<InteractionResponse>;
+        ctx: &InteractionContext<'_>,
+    ) -> HandlerResult;
 
     async fn component(
         &self,
         component: MessageComponentInteraction,
-    ) -> worker::Result<InteractionResponse>;
+        ctx: &InteractionContext<'_>,
+    ) -> HandlerResult;
+
+    /// Suggestions for the focused option, none unless implemented
+    async fn autocomplete(
+        &self,
+        _command: ApplicationCommandInteraction,
+        _ctx: &InteractionContext<'_>,
+    ) -> HandlerResult {
+        Ok(InteractionResponse::respond_with_autocomplete_choices(
+            vec![],
+        ))
+    }
+
+    /// Answers a submitted modal, a [HandlerError::NotFound] unless implemented
+    async fn modal_submit(
+        &self,
+        _modal: ModalSubmitInteraction,
+        _ctx: &InteractionContext<'_>,
+    ) -> HandlerResult {
+        Err(HandlerError::NotFound("Modal handler".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handler() -> HandlerResult {
+        Err(worker::Error::RustError("KV unavailable".to_string()))
+            .map_err(HandlerError::internal)?
+    }
+
+    #[test]
+    pub fn worker_errors_are_internal() {
+        let err = handler().unwrap_err();
+
+        assert!(matches!(err, HandlerError::Internal(_)));
+        assert!(err.response().is_none());
+        assert_eq!(err.status(), 500);
+    }
+
+    #[test]
+    pub fn into_composure_error() {
+        use std::error::Error as _;
+
+        let err: composure_core::Error =
+            Error::ResponseSerialization(serde_json::from_str::<u8>("{").unwrap_err()).into();
+
+        let source = err.source().unwrap();
+        assert!(source.is::<Error>());
+        assert!(source
+            .to_string()
+            .starts_with("failed to serialize response: "));
+        assert_eq!(err.to_string(), format!("cloudflare error: {source}"));
+        assert!(source.source().unwrap().is::<serde_json::Error>());
+    }
 }
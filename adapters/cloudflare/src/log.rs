@@ -0,0 +1,138 @@
+use worker::{console_debug, console_error, console_warn};
+
+/// Verbosity of the adapter's logs, each level includes the ones above it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Debug,
+}
+
+/// Destination for the adapter's logs
+pub trait Logger {
+    fn log(&self, level: LogLevel, message: &str);
+}
+
+/// Writes to the worker console, the default logger
+pub struct ConsoleLogger;
+
+impl Logger for ConsoleLogger {
+    fn log(&self, level: LogLevel, message: &str) {
+        match level {
+            LogLevel::Off => {}
+            LogLevel::Error => console_error!("{}", message),
+            LogLevel::Warn => console_warn!("{}", message),
+            LogLevel::Debug => console_debug!("{}", message),
+        }
+    }
+}
+
+/// Filters messages by level before handing them to the logger
+pub(crate) struct Log {
+    level: LogLevel,
+    logger: Box<dyn Logger>,
+}
+
+impl Log {
+    pub fn set_level(&mut self, level: LogLevel) {
+        self.level = level;
+    }
+
+    pub fn set_logger(&mut self, logger: impl Logger + 'static) {
+        self.logger = Box::new(logger);
+    }
+
+    pub fn enabled(&self, level: LogLevel) -> bool {
+        level != LogLevel::Off && level <= self.level
+    }
+
+    pub fn error(&self, message: &str) {
+        self.log(LogLevel::Error, message);
+    }
+
+    pub fn warn(&self, message: &str) {
+        self.log(LogLevel::Warn, message);
+    }
+
+    pub fn debug(&self, message: &str) {
+        self.log(LogLevel::Debug, message);
+    }
+
+    fn log(&self, level: LogLevel, message: &str) {
+        if self.enabled(level) {
+            self.logger.log(level, message);
+        }
+    }
+}
+
+impl Default for Log {
+    fn default() -> Self {
+        Log {
+            level: LogLevel::Warn,
+            logger: Box::new(ConsoleLogger),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Recorder(Rc<RefCell<Vec<(LogLevel, String)>>>);
+
+    impl Logger for Recorder {
+        fn log(&self, level: LogLevel, message: &str) {
+            self.0.borrow_mut().push((level, message.to_string()));
+        }
+    }
+
+    fn log_with(recorder: &Recorder) -> Log {
+        let mut log = Log::default();
+        log.set_logger(recorder.clone());
+        log
+    }
+
+    #[test]
+    pub fn debug_suppressed_by_default() {
+        let recorder = Recorder::default();
+        let log = log_with(&recorder);
+
+        log.debug("processing");
+        log.warn("validation failed");
+        log.error("worker error");
+
+        assert_eq!(
+            *recorder.0.borrow(),
+            [
+                (LogLevel::Warn, "validation failed".to_string()),
+                (LogLevel::Error, "worker error".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn levels_filter() {
+        let cases = [
+            (LogLevel::Off, 0),
+            (LogLevel::Error, 1),
+            (LogLevel::Warn, 2),
+            (LogLevel::Debug, 3),
+        ];
+
+        for (level, expected) in cases {
+            let recorder = Recorder::default();
+            let mut log = log_with(&recorder);
+            log.set_level(level);
+
+            log.debug("debug");
+            log.warn("warn");
+            log.error("error");
+
+            assert_eq!(recorder.0.borrow().len(), expected, "{:?}", level);
+        }
+    }
+}
@@ -0,0 +1,442 @@
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+};
+
+use composure_core::{
+    models::{
+        InstallContext, Interaction, InteractionEnvelope, InteractionResponse, MessageCallbackData,
+    },
+    util::{BotConfig, HandlerError, HandlerResult, InteractionResponder, OptionRules},
+};
+
+use crate::{body, log, Error, LogLevel, Logger, Result};
+
+/// Status and body a request is answered with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipelineResponse {
+    pub status: u16,
+    pub body: String,
+
+    /// `body` is JSON, plain text otherwise
+    pub json: bool,
+}
+
+impl PipelineResponse {
+    fn json(body: String) -> Self {
+        PipelineResponse {
+            status: 200,
+            body,
+            json: true,
+        }
+    }
+
+    fn error(message: &str, status: u16) -> Self {
+        PipelineResponse {
+            status,
+            body: message.to_string(),
+            json: false,
+        }
+    }
+}
+
+/// Everything [CloudflareInteractionBot](crate::CloudflareInteractionBot) does with a request,
+/// without the worker's `Request`, `Env` or `Response`
+///
+/// The bot reads the body, headers and public key from the worker and turns the
+/// [PipelineResponse] back into a worker response, so the pipeline runs and is tested natively.
+pub struct Pipeline {
+    strict: bool,
+    log: log::Log,
+    max_body_size: usize,
+    config: Option<BotConfig>,
+    option_rules: HashMap<String, OptionRules>,
+    guild_install_only: HashSet<String>,
+    #[cfg(all(feature = "dev", debug_assertions))]
+    skip_validation: bool,
+}
+
+impl Default for Pipeline {
+    fn default() -> Self {
+        Pipeline {
+            strict: false,
+            log: log::Log::default(),
+            max_body_size: body::DEFAULT_MAX_BODY_SIZE,
+            config: None,
+            option_rules: HashMap::new(),
+            guild_install_only: HashSet::new(),
+            #[cfg(all(feature = "dev", debug_assertions))]
+            skip_validation: false,
+        }
+    }
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Largest body in bytes accepted, defaults to [DEFAULT_MAX_BODY_SIZE](crate::DEFAULT_MAX_BODY_SIZE)
+    pub fn with_max_body_size(mut self, max_body_size: usize) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    /// Logs fields the models don't know about at debug level
+    pub fn with_strict_parsing(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    pub fn with_log_level(mut self, level: LogLevel) -> Self {
+        self.log.set_level(level);
+        self
+    }
+
+    pub fn with_logger(mut self, logger: impl Logger + 'static) -> Self {
+        self.log.set_logger(logger);
+        self
+    }
+
+    /// Answers disabled commands and, in maintenance mode, everyone but the owners without
+    /// running the handler, and makes messages ephemeral if `config` asks for it
+    pub fn with_config(mut self, config: &BotConfig) -> Self {
+        self.config = Some(config.clone());
+        self
+    }
+
+    /// Answers the command called `name` with the first rule its options break, without running
+    /// the handler
+    pub fn with_option_rules(mut self, name: impl Into<String>, rules: OptionRules) -> Self {
+        self.option_rules.insert(name.into(), rules);
+        self
+    }
+
+    /// Answers the command called `name` with an ephemeral message unless the app is installed
+    /// to the guild it was used in, see [InstallContext]
+    pub fn guild_install_only(mut self, name: impl Into<String>) -> Self {
+        self.guild_install_only.insert(name.into());
+        self
+    }
+
+    /// **Dangerous**: accepts requests without checking their signature
+    ///
+    /// Only available in debug builds with the `dev` feature.
+    #[cfg(all(feature = "dev", debug_assertions))]
+    pub fn skip_validation(mut self) -> Self {
+        self.skip_validation = true;
+        self
+    }
+
+    /// Answers a request announcing a body over the limit, before any of it is read
+    ///
+    /// [process](Self::process) only checks the body it is given, call this with the
+    /// `Content-Length` header first.
+    pub fn check_content_length(
+        &self,
+        content_length: Option<&str>,
+    ) -> Result<Option<PipelineResponse>> {
+        body::check_content_length(content_length, self.max_body_size)
+            .err()
+            .map(|err| self.reject(err))
+            .transpose()
+    }
+
+    /// Verifies and parses `body`, answers pings and hands every other interaction to `handle`
+    ///
+    /// `header` reads a request header and `public_key` the application's public key, it is
+    /// only asked for once the body passed the size check. Their [Error::WorkerError]s are passed
+    /// up, every other failure is answered with a [PipelineResponse].
+    ///
+    /// `handle` gets an [InteractionResponder] for the request. The pipeline answers with the
+    /// response recorded through it, a handler that didn't use it is answered with the response
+    /// it returned. Followups queued on it aren't sent, the request is over by then.
+    pub async fn process<H, K, D, Fut>(
+        &self,
+        body: &[u8],
+        header: H,
+        public_key: K,
+        handle: D,
+    ) -> Result<PipelineResponse>
+    where
+        H: Fn(&str) -> Result<Option<String>>,
+        K: FnOnce() -> Result<String>,
+        D: FnOnce(Interaction, InteractionResponder) -> Fut,
+        Fut: Future<Output = HandlerResult>,
+    {
+        self.log.debug("Processing request");
+
+        let interaction =
+            match body::verify_and_parse(body, self.max_body_size, self.strict, |body| {
+                self.validate(body, &header, public_key)
+            }) {
+                Ok((interaction, unknown)) => {
+                    for path in unknown {
+                        self.log.debug(&format!("Unknown field: {}", path));
+                    }
+
+                    interaction
+                }
+                Err(err) => return self.reject(err),
+            };
+
+        let interaction_response = match interaction {
+            Interaction::Ping(_) => InteractionResponse::Pong,
+            interaction => match self.intercept(&interaction) {
+                Some(response) => response,
+                None => match self.handle(interaction, handle).await {
+                    Ok(response) => self.apply_defaults(response),
+                    Err(err) => match err.response() {
+                        Some(response) => {
+                            self.log.debug(&format!("Handler error: {}", err));
+                            response
+                        }
+                        None => {
+                            self.log.error(&format!("Handler error: {}", err));
+                            return Ok(PipelineResponse::error("Internal error", err.status()));
+                        }
+                    },
+                },
+            },
+        };
+
+        match body::serialize_response(&interaction_response) {
+            Ok(json) => Ok(PipelineResponse::json(json)),
+            Err(err) => {
+                self.log
+                    .error(&format!("Failed to serialize response: {:?}", err));
+                Ok(PipelineResponse::error("Invalid response", err.status()))
+            }
+        }
+    }
+
+    /// Runs `handle` with a fresh [InteractionResponder] and takes the response from it
+    async fn handle<D, Fut>(&self, interaction: Interaction, handle: D) -> HandlerResult
+    where
+        D: FnOnce(Interaction, InteractionResponder) -> Fut,
+        Fut: Future<Output = HandlerResult>,
+    {
+        let responder = InteractionResponder::new();
+        let returned = handle(interaction, responder.clone()).await?;
+
+        let followups = responder.take_followups();
+        if !followups.is_empty() {
+            self.log.warn(&format!(
+                "Dropping {} followup(s), send them with the interaction's token",
+                followups.len()
+            ));
+        }
+
+        match responder.take_response() {
+            Some(response) => {
+                self.log
+                    .debug("Answering with the responder's response, ignoring the returned one");
+                Ok(response)
+            }
+            None => Ok(returned),
+        }
+    }
+
+    /// [process](Self::process) for handlers with their own models, the body is verified but only
+    /// its [InteractionEnvelope] is parsed
+    ///
+    /// Pings are still answered by the pipeline. `handle` gets the verified body and returns the
+    /// JSON to respond with, a [HandlerError] is answered like in [process](Self::process). The
+    /// [config](Self::with_config) isn't applied, there is no command name or user to check.
+    pub async fn process_raw<'a, H, K, D, Fut>(
+        &self,
+        body: &'a [u8],
+        header: H,
+        public_key: K,
+        handle: D,
+    ) -> Result<PipelineResponse>
+    where
+        H: Fn(&str) -> Result<Option<String>>,
+        K: FnOnce() -> Result<String>,
+        D: FnOnce(&'a [u8], InteractionEnvelope) -> Fut,
+        Fut: Future<Output = std::result::Result<Vec<u8>, HandlerError>>,
+    {
+        self.log.debug("Processing raw request");
+
+        let envelope = match body::verify_and_parse_envelope(body, self.max_body_size, |body| {
+            self.validate(body, &header, public_key)
+        }) {
+            Ok(envelope) => envelope,
+            Err(err) => return self.reject(err),
+        };
+
+        if envelope.is_ping() {
+            return match body::serialize_response(&InteractionResponse::Pong) {
+                Ok(json) => Ok(PipelineResponse::json(json)),
+                Err(err) => self.reject(err),
+            };
+        }
+
+        let response = match handle(body, envelope).await {
+            Ok(response) => response,
+            Err(err) => {
+                let response = match err.response() {
+                    Some(response) => response,
+                    None => {
+                        self.log.error(&format!("Handler error: {}", err));
+                        return Ok(PipelineResponse::error("Internal error", err.status()));
+                    }
+                };
+
+                self.log.debug(&format!("Handler error: {}", err));
+                return match body::serialize_response(&response) {
+                    Ok(json) => Ok(PipelineResponse::json(json)),
+                    Err(err) => self.reject(err),
+                };
+            }
+        };
+
+        match String::from_utf8(response) {
+            Ok(json) => Ok(PipelineResponse::json(json)),
+            Err(_) => {
+                self.log.error("Raw handler response is not UTF-8");
+                Ok(PipelineResponse::error("Invalid response", 500))
+            }
+        }
+    }
+
+    /// Response the config answers `interaction` with instead of its handler
+    ///
+    /// Autocomplete can't be answered with a message, so it always reaches the handler.
+    fn intercept(&self, interaction: &Interaction) -> Option<InteractionResponse> {
+        if let Interaction::ApplicationCommandAutocomplete(_) = interaction {
+            return None;
+        }
+
+        if let Some(response) = self.intercept_config(interaction) {
+            return Some(response);
+        }
+
+        match interaction {
+            Interaction::ApplicationCommand(command)
+                if self.guild_install_only.contains(&command.data.name)
+                    && command.common.install_context() != InstallContext::GuildInstall =>
+            {
+                self.log.debug(&format!(
+                    "Command {} needs a guild install",
+                    command.data.name
+                ));
+                Some(guild_install_response(&command.data.name))
+            }
+            Interaction::ApplicationCommand(command) => {
+                let rules = self.option_rules.get(&command.data.name)?;
+                let err = rules.check(&command.data.options).err()?;
+
+                self.log.debug(&format!(
+                    "Command {} broke an option rule: {}",
+                    command.data.name, err
+                ));
+                Some(err.to_response())
+            }
+            _ => None,
+        }
+    }
+
+    /// Maintenance mode and disabled commands from the [BotConfig]
+    fn intercept_config(&self, interaction: &Interaction) -> Option<InteractionResponse> {
+        let config = self.config.as_ref()?;
+
+        let is_owner = interaction
+            .common()
+            .invoker_id()
+            .map_or(false, |id| config.is_owner(id));
+
+        if config.maintenance && !is_owner {
+            self.log.debug("Maintenance mode, handler skipped");
+            return Some(BotConfig::maintenance_response());
+        }
+
+        match interaction {
+            Interaction::ApplicationCommand(command) if config.is_disabled(&command.data.name) => {
+                self.log
+                    .debug(&format!("Command {} is disabled", command.data.name));
+                Some(BotConfig::disabled_response(&command.data.name))
+            }
+            _ => None,
+        }
+    }
+
+    /// Makes a handler's message ephemeral when the config asks for it
+    fn apply_defaults(&self, response: InteractionResponse) -> InteractionResponse {
+        match (response, &self.config) {
+            (InteractionResponse::ChannelMessageWithSource(data), Some(config))
+                if config.ephemeral_by_default =>
+            {
+                InteractionResponse::ChannelMessageWithSource(data.ephemeral())
+            }
+            (response, _) => response,
+        }
+    }
+
+    fn validate(
+        &self,
+        body: &[u8],
+        header: impl Fn(&str) -> Result<Option<String>>,
+        public_key: impl FnOnce() -> Result<String>,
+    ) -> Result<()> {
+        #[cfg(all(feature = "dev", debug_assertions))]
+        if self.skip_validation {
+            self.log
+                .warn("Signature validation skipped, never use skip_validation in production");
+            return Ok(());
+        }
+
+        let signature = header("X-Signature-Ed25519")?;
+        let timestamp = header("X-Signature-Timestamp")?;
+        let public_key = public_key()?;
+
+        body::verify_signature(
+            &public_key,
+            signature.as_deref(),
+            timestamp.as_deref(),
+            body,
+        )
+    }
+
+    /// Responds to a request that failed before reaching a handler
+    fn reject(&self, err: Error) -> Result<PipelineResponse> {
+        match err {
+            Error::WorkerError(e) => {
+                self.log.error(&format!("Worker error: {}", e));
+                // passing error up
+                Err(Error::WorkerError(e))
+            }
+            Error::ValidationError => {
+                self.log.warn("Validation failed");
+                Ok(PipelineResponse::error("Validation failed", 401))
+            }
+            Error::BodyTooLarge { size, max } => {
+                self.log.warn(&format!(
+                    "Body of {size} bytes is over the {max} byte limit"
+                ));
+                Ok(PipelineResponse::error("Payload too large", 413))
+            }
+            Error::InvalidBody(reason) => {
+                self.log.warn(&format!("Invalid body: {reason}"));
+                Ok(PipelineResponse::error("Invalid body", 400))
+            }
+            err => {
+                self.log.error(&format!("Unknown error: {:?}", err));
+                Ok(PipelineResponse::error("Unknown error", err.status()))
+            }
+        }
+    }
+}
+
+/// Ephemeral message for a [guild_install_only](Pipeline::guild_install_only) command used
+/// through a user install
+fn guild_install_response(command: &str) -> InteractionResponse {
+    InteractionResponse::ChannelMessageWithSource(
+        MessageCallbackData::new()
+            .with_content(format!(
+                "`/{}` only works in servers the app is added to",
+                command
+            ))
+            .ephemeral(),
+    )
+}
@@ -0,0 +1,542 @@
+use std::{fs, path::PathBuf};
+
+use composure_adapter_cloudflare::{Error, LogLevel, Pipeline, PipelineResponse};
+use composure_core::{
+    models::{Interaction, InteractionEnvelope, InteractionResponse, Snowflake, UpdateMessageData},
+    util::{BotConfig, HandlerError, HandlerResult, InteractionResponder, OptionRule, OptionRules},
+};
+use ed25519_dalek::{ExpandedSecretKey, PublicKey, SecretKey};
+use futures::executor::block_on;
+use serde_json::{json, Value};
+
+const TIMESTAMP: &str = "1682372142";
+
+/// Payload from the shared fixtures at the workspace root
+fn fixture(name: &str) -> Vec<u8> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../tests/fixtures")
+        .join(format!("{name}.json"));
+
+    fs::read(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+/// Public key and a signature over `body`, as Discord would send them
+fn sign(body: &[u8]) -> (String, String) {
+    let secret = SecretKey::from_bytes(&[7; 32]).unwrap();
+    let public = PublicKey::from(&secret);
+    let message = [TIMESTAMP.as_bytes(), body].concat();
+    let signature = ExpandedSecretKey::from(&secret).sign(&message, &public);
+
+    (
+        hex::encode(public.as_bytes()),
+        hex::encode(signature.to_bytes()),
+    )
+}
+
+fn pipeline() -> Pipeline {
+    Pipeline::new().with_log_level(LogLevel::Off)
+}
+
+async fn handle(interaction: Interaction) -> HandlerResult {
+    match interaction {
+        Interaction::ApplicationCommand(command) => Ok(InteractionResponse::respond_with_message(
+            format!("Hello from /{}", command.data.name),
+        )),
+        Interaction::MessageComponent(component) => Ok(InteractionResponse::UpdateMessage(
+            UpdateMessageData::new().with_content(format!("Pressed {}", component.data.custom_id)),
+        )),
+        _ => Err(HandlerError::NotFound("Handler".to_string())),
+    }
+}
+
+/// Runs a signed `body` through the pipeline with `handler`
+fn run<Fut>(body: &[u8], handler: impl FnOnce(Interaction) -> Fut) -> PipelineResponse
+where
+    Fut: std::future::Future<Output = HandlerResult>,
+{
+    run_with(pipeline(), body, handler)
+}
+
+fn run_with<Fut>(
+    pipeline: Pipeline,
+    body: &[u8],
+    handler: impl FnOnce(Interaction) -> Fut,
+) -> PipelineResponse
+where
+    Fut: std::future::Future<Output = HandlerResult>,
+{
+    run_responding(pipeline, body, |interaction, _| handler(interaction))
+}
+
+/// [run_with] for handlers that answer through their [InteractionResponder]
+fn run_responding<Fut>(
+    pipeline: Pipeline,
+    body: &[u8],
+    handler: impl FnOnce(Interaction, InteractionResponder) -> Fut,
+) -> PipelineResponse
+where
+    Fut: std::future::Future<Output = HandlerResult>,
+{
+    let (public_key, signature) = sign(body);
+
+    block_on(pipeline.process(
+        body,
+        |name| {
+            Ok(match name {
+                "X-Signature-Ed25519" => Some(signature.clone()),
+                "X-Signature-Timestamp" => Some(TIMESTAMP.to_string()),
+                _ => None,
+            })
+        },
+        || Ok(public_key),
+        handler,
+    ))
+    .unwrap()
+}
+
+fn json_body(response: &PipelineResponse) -> Value {
+    assert!(response.json, "{response:?}");
+    serde_json::from_str(&response.body).unwrap()
+}
+
+#[test]
+pub fn ping() {
+    let response = run(&fixture("ping"), |_| async {
+        panic!("pings are answered by the pipeline")
+    });
+
+    assert_eq!(response.status, 200);
+    assert_eq!(json_body(&response), json!({"type": 1}));
+}
+
+#[test]
+pub fn command() {
+    let response = run(&fixture("chat_input_command"), handle);
+
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        json_body(&response),
+        json!({"type": 4, "data": {"content": "Hello from /search"}})
+    );
+}
+
+#[test]
+pub fn component() {
+    let response = run(&fixture("component_button"), handle);
+
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        json_body(&response),
+        json!({"type": 7, "data": {"content": "Pressed click_one"}})
+    );
+}
+
+#[test]
+pub fn bad_signature() {
+    let body = fixture("chat_input_command");
+    let (public_key, signature) = sign(&body);
+
+    let cases = [
+        (Some(signature.clone()), Some("1682372143")),
+        (None, Some(TIMESTAMP)),
+        (Some(signature), None),
+    ];
+
+    for (signature, timestamp) in cases {
+        let response = block_on(pipeline().process(
+            &body,
+            |name| {
+                Ok(match name {
+                    "X-Signature-Ed25519" => signature.clone(),
+                    "X-Signature-Timestamp" => timestamp.map(str::to_string),
+                    _ => None,
+                })
+            },
+            || Ok(public_key.clone()),
+            |_, _| async { panic!("unverified request reached the handler") },
+        ))
+        .unwrap();
+
+        assert_eq!(
+            response,
+            PipelineResponse {
+                status: 401,
+                body: "Validation failed".to_string(),
+                json: false,
+            }
+        );
+    }
+}
+
+#[test]
+pub fn oversized_body() {
+    let body = fixture("chat_input_command");
+
+    let response = block_on(pipeline().with_max_body_size(body.len() - 1).process(
+        &body,
+        |_| Ok(None),
+        || panic!("oversized request asked for the public key"),
+        |_, _| async { panic!("oversized request reached the handler") },
+    ))
+    .unwrap();
+
+    assert_eq!(response.status, 413);
+    assert!(!response.json);
+}
+
+#[test]
+pub fn content_length() {
+    let pipeline = pipeline().with_max_body_size(10);
+
+    assert_eq!(pipeline.check_content_length(None).unwrap(), None);
+    assert_eq!(pipeline.check_content_length(Some("10")).unwrap(), None);
+    assert_eq!(
+        pipeline.check_content_length(Some("11")).unwrap(),
+        Some(PipelineResponse {
+            status: 413,
+            body: "Payload too large".to_string(),
+            json: false,
+        })
+    );
+    assert_eq!(
+        pipeline
+            .check_content_length(Some("ten"))
+            .unwrap()
+            .map(|response| response.status),
+        Some(400)
+    );
+}
+
+#[test]
+pub fn handler_errors() {
+    let response = run(&fixture("chat_input_command"), |_| async {
+        Err(HandlerError::user_facing("Pick a tag first"))
+    });
+
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        json_body(&response),
+        json!({"type": 4, "data": {"content": "Pick a tag first", "flags": 64}})
+    );
+
+    let response = run(&fixture("chat_input_command"), |_| async {
+        Err(HandlerError::internal(worker::Error::RustError(
+            "KV unavailable".to_string(),
+        )))
+    });
+
+    assert_eq!(
+        response,
+        PipelineResponse {
+            status: 500,
+            body: "Internal error".to_string(),
+            json: false,
+        }
+    );
+}
+
+#[test]
+pub fn responder_answers() {
+    let response = run_responding(
+        pipeline(),
+        &fixture("chat_input_command"),
+        |_, responder| async move {
+            responder
+                .clone()
+                .reply(InteractionResponse::respond_with_message(
+                    "From the responder",
+                ))?;
+            responder.reply(InteractionResponse::respond_with_message("Twice"))?;
+            unreachable!("a second reply is refused")
+        },
+    );
+
+    // the handler failed after answering, the error wins
+    assert_eq!(response.status, 500);
+
+    let response = run_responding(
+        pipeline(),
+        &fixture("chat_input_command"),
+        |_, responder| async move {
+            responder
+                .clone()
+                .reply(InteractionResponse::respond_with_message(
+                    "From the responder",
+                ))?;
+            Ok(InteractionResponse::respond_with_message("Returned"))
+        },
+    );
+
+    assert_eq!(
+        json_body(&response),
+        json!({"type": 4, "data": {"content": "From the responder"}})
+    );
+}
+
+#[test]
+pub fn worker_errors_are_passed_up() {
+    let body = fixture("ping");
+    let (_, signature) = sign(&body);
+
+    let res = block_on(pipeline().process(
+        &body,
+        |name| Ok((name == "X-Signature-Ed25519").then(|| signature.clone())),
+        || {
+            Err(Error::WorkerError(worker::Error::RustError(
+                "no DISCORD_PUBLIC_KEY secret".to_string(),
+            )))
+        },
+        |interaction, _| handle(interaction),
+    ));
+
+    assert!(matches!(
+        res,
+        Err(Error::WorkerError(worker::Error::RustError(_)))
+    ));
+}
+
+fn config() -> BotConfig {
+    BotConfig::new(Snowflake::from_u64(100000000000000001))
+}
+
+#[test]
+pub fn disabled_command() {
+    let config = BotConfig {
+        disabled_commands: vec!["search".to_string()],
+        ..config()
+    };
+
+    let response = run_with(
+        pipeline().with_config(&config),
+        &fixture("chat_input_command"),
+        |_| async { panic!("disabled command reached the handler") },
+    );
+
+    assert_eq!(
+        json_body(&response),
+        json!({"type": 4, "data": {"content": "`/search` is temporarily disabled", "flags": 64}})
+    );
+
+    // components of a disabled command's messages still work
+    let response = run_with(
+        pipeline().with_config(&config),
+        &fixture("component_button"),
+        handle,
+    );
+    assert_eq!(json_body(&response)["data"]["content"], "Pressed click_one");
+}
+
+#[test]
+pub fn option_rules() {
+    let broken = OptionRules::new().rule(OptionRule::MutuallyExclusive("query", "user"));
+    let response = run_with(
+        pipeline().with_option_rules("search", broken),
+        &fixture("chat_input_command"),
+        |_| async { panic!("broken option rule reached the handler") },
+    );
+
+    assert_eq!(
+        json_body(&response),
+        json!({"type": 4, "data": {"content": "`query` and `user` can't be used together", "flags": 64}})
+    );
+
+    // rules of other commands don't apply
+    let other = OptionRules::new().rule(OptionRule::Requires("query", "page"));
+    let response = run_with(
+        pipeline().with_option_rules("tag", other),
+        &fixture("chat_input_command"),
+        handle,
+    );
+    assert_eq!(
+        json_body(&response)["data"]["content"],
+        "Hello from /search"
+    );
+}
+
+#[test]
+pub fn guild_install_only() {
+    let cases = [
+        ("user_installed_command", false),
+        ("user_installed_guild_command", false),
+        ("chat_input_command", true),
+    ];
+
+    for (name, handled) in cases {
+        let response = run_with(
+            pipeline()
+                .guild_install_only("ping")
+                .guild_install_only("search"),
+            &fixture(name),
+            handle,
+        );
+
+        let body = json_body(&response);
+        assert_eq!(body["data"]["flags"].is_null(), handled, "{name}: {body}");
+        if !handled {
+            assert_eq!(
+                body["data"]["content"],
+                "`/ping` only works in servers the app is added to"
+            );
+        }
+    }
+}
+
+#[test]
+pub fn maintenance() {
+    let config = BotConfig {
+        maintenance: true,
+        ..config()
+    };
+
+    let response = run_with(
+        pipeline().with_config(&config),
+        &fixture("chat_input_command"),
+        |_| async { panic!("maintenance mode reached the handler") },
+    );
+
+    assert_eq!(
+        json_body(&response),
+        json!({
+            "type": 4,
+            "data": {"content": "The bot is down for maintenance, try again later", "flags": 64}
+        })
+    );
+
+    // the invoking user of the fixture
+    let config = BotConfig {
+        owner_ids: vec![Snowflake::from_u64(400000000000000001)],
+        ..config
+    };
+
+    let response = run_with(
+        pipeline().with_config(&config),
+        &fixture("chat_input_command"),
+        handle,
+    );
+    assert_eq!(
+        json_body(&response)["data"]["content"],
+        "Hello from /search"
+    );
+}
+
+#[test]
+pub fn ephemeral_by_default() {
+    let config = BotConfig {
+        ephemeral_by_default: true,
+        ..config()
+    };
+
+    let response = run_with(
+        pipeline().with_config(&config),
+        &fixture("chat_input_command"),
+        handle,
+    );
+    assert_eq!(
+        json_body(&response),
+        json!({"type": 4, "data": {"content": "Hello from /search", "flags": 64}})
+    );
+
+    // updates keep the flags of the message they edit
+    let response = run_with(
+        pipeline().with_config(&config),
+        &fixture("component_button"),
+        handle,
+    );
+    assert_eq!(
+        json_body(&response),
+        json!({"type": 7, "data": {"content": "Pressed click_one"}})
+    );
+}
+
+/// Runs a signed `body` through the pipeline's raw mode with `handler`
+fn run_raw<'a, Fut>(
+    body: &'a [u8],
+    handler: impl FnOnce(&'a [u8], InteractionEnvelope) -> Fut,
+) -> PipelineResponse
+where
+    Fut: std::future::Future<Output = Result<Vec<u8>, HandlerError>>,
+{
+    let (public_key, signature) = sign(body);
+
+    block_on(pipeline().process_raw(
+        body,
+        |name| {
+            Ok(match name {
+                "X-Signature-Ed25519" => Some(signature.clone()),
+                "X-Signature-Timestamp" => Some(TIMESTAMP.to_string()),
+                _ => None,
+            })
+        },
+        || Ok(public_key),
+        handler,
+    ))
+    .unwrap()
+}
+
+#[test]
+pub fn raw() {
+    let body = fixture("unknown_fields");
+
+    let response = run_raw(&body, |raw, envelope| async move {
+        // the handler parses the body with its own models
+        let raw: Value = serde_json::from_slice(raw).unwrap();
+        assert_eq!(raw["novel_root"], true);
+        assert_eq!(
+            envelope.id,
+            Snowflake::from_u64(raw["id"].as_str().unwrap().parse().unwrap())
+        );
+        assert_eq!(envelope.token, raw["token"]);
+
+        Ok(br#"{"type":4,"data":{"content":"raw"}}"#.to_vec())
+    });
+
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        json_body(&response),
+        json!({"type": 4, "data": {"content": "raw"}})
+    );
+}
+
+#[test]
+pub fn raw_passes_payloads_the_models_reject() {
+    let mut payload: Value = serde_json::from_slice(&fixture("chat_input_command")).unwrap();
+    payload["type"] = json!(99);
+    let body = serde_json::to_vec(&payload).unwrap();
+
+    let response = run(&body, |_| async {
+        panic!("unparsable interaction reached the handler")
+    });
+    assert_eq!(response.status, 400);
+
+    let response = run_raw(&body, |_, envelope| async move {
+        assert_eq!(envelope.t, 99);
+        Ok(br#"{"type":5}"#.to_vec())
+    });
+    assert_eq!(json_body(&response), json!({"type": 5}));
+}
+
+#[test]
+pub fn raw_ping() {
+    let response = run_raw(&fixture("ping"), |_, _| async {
+        panic!("pings are answered by the pipeline")
+    });
+
+    assert_eq!(json_body(&response), json!({"type": 1}));
+}
+
+#[test]
+pub fn raw_handler_errors() {
+    let body = fixture("chat_input_command");
+
+    let response = run_raw(&body, |_, _| async {
+        Err(HandlerError::user_facing("Pick a tag first"))
+    });
+    assert_eq!(
+        json_body(&response),
+        json!({"type": 4, "data": {"content": "Pick a tag first", "flags": 64}})
+    );
+
+    let response = run_raw(&body, |_, _| async { Ok(vec![0xff]) });
+    assert_eq!(response.status, 500);
+    assert!(!response.json);
+}
@@ -1,12 +1,22 @@
 use composure_commands::command::ApplicationCommand;
+use serde::Serialize;
 
-use crate::{DiscordClient, Error, Result, DISCORD_API};
+use crate::{DiscordClient, Error, Result};
+
+/// Query parameters for listing application commands
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct CommandQuery {
+    /// Return every localization instead of only the ones matching the request's locale, needed
+    /// to compare the registered commands with local ones that have localizations
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub with_localizations: bool,
+}
 
 impl DiscordClient {
     pub fn get_global_commands(&self) -> Result<Vec<ApplicationCommand>> {
         let url = format!(
-            "{DISCORD_API}/applications/{}/commands",
-            self.application_id
+            "{}/applications/{}/commands",
+            self.base_url, self.application_id
         );
         let commands: Vec<ApplicationCommand> = self.get(url)?;
         Ok(commands)
@@ -14,20 +24,45 @@ impl DiscordClient {
 
     pub fn get_guild_commands(&self, guild_id: &str) -> Result<Vec<ApplicationCommand>> {
         let url = format!(
-            "{DISCORD_API}/applications/{}/guilds/{}/commands",
-            self.application_id, guild_id
+            "{}/applications/{}/guilds/{}/commands",
+            self.base_url, self.application_id, guild_id
         );
         let commands: Vec<ApplicationCommand> = self.get(url)?;
         Ok(commands)
     }
 
+    pub fn get_global_commands_with_query(
+        &self,
+        query: &CommandQuery,
+    ) -> Result<Vec<ApplicationCommand>> {
+        let url = format!(
+            "{}/applications/{}/commands",
+            self.base_url, self.application_id
+        );
+
+        self.get_with_query(url, query)
+    }
+
+    pub fn get_guild_commands_with_query(
+        &self,
+        guild_id: &str,
+        query: &CommandQuery,
+    ) -> Result<Vec<ApplicationCommand>> {
+        let url = format!(
+            "{}/applications/{}/guilds/{}/commands",
+            self.base_url, self.application_id, guild_id
+        );
+
+        self.get_with_query(url, query)
+    }
+
     pub fn create_global_command(
         &self,
         command: &ApplicationCommand,
     ) -> Result<ApplicationCommand> {
         let url = format!(
-            "{DISCORD_API}/applications/{}/commands",
-            self.application_id
+            "{}/applications/{}/commands",
+            self.base_url, self.application_id
         );
 
         let command = self.post(url, command)?;
@@ -41,8 +76,8 @@ impl DiscordClient {
         command: &ApplicationCommand,
     ) -> Result<ApplicationCommand> {
         let url = format!(
-            "{DISCORD_API}/applications/{}/guilds/{}/commands",
-            self.application_id, guild_id
+            "{}/applications/{}/guilds/{}/commands",
+            self.base_url, self.application_id, guild_id
         );
 
         let command = self.post(url, command)?;
@@ -58,8 +93,8 @@ impl DiscordClient {
         commands: &Vec<&ApplicationCommand>,
     ) -> Result<Vec<ApplicationCommand>> {
         let url = format!(
-            "{DISCORD_API}/applications/{}/commands",
-            self.application_id
+            "{}/applications/{}/commands",
+            self.base_url, self.application_id
         );
 
         let response = self.put(url, commands);
@@ -73,6 +108,20 @@ impl DiscordClient {
         response
     }
 
+    /// Sets the list of global commands, returning the commands that were replaced along with the
+    /// new ones as `(previous, new)` so a deploy can be rolled back.
+    ///
+    /// WARNING: All existing commands will be deleted
+    pub fn overwrite_global_commands_with_previous(
+        &self,
+        commands: &Vec<&ApplicationCommand>,
+    ) -> Result<(Vec<ApplicationCommand>, Vec<ApplicationCommand>)> {
+        let previous = self.get_global_commands()?;
+        let new = self.overwrite_global_commands(commands)?;
+
+        Ok((previous, new))
+    }
+
     /// Sets the list of guild commands.
     ///
     /// WARNING: All existing commands will be deleted
@@ -82,8 +131,8 @@ impl DiscordClient {
         commands: &Vec<&ApplicationCommand>,
     ) -> Result<Vec<ApplicationCommand>> {
         let url = format!(
-            "{DISCORD_API}/applications/{}/guilds/{}/commands",
-            self.application_id, guild_id
+            "{}/applications/{}/guilds/{}/commands",
+            self.base_url, self.application_id, guild_id
         );
 
         let commands = self.put(url, commands)?;
@@ -96,7 +145,10 @@ impl DiscordClient {
 pub mod tests {
     use std::env;
 
+    use composure_commands::command::ApplicationCommandOption;
+
     use super::*;
+    use crate::mock::MockServer;
 
     fn setup<'a>() {
         dotenv::from_filename(".env.test").unwrap();
@@ -114,6 +166,170 @@ pub mod tests {
         env::var("DISCORD_TOKEN").unwrap()
     }
 
+    const COMMAND: &str = r#"{
+        "id": "800000000000000001",
+        "application_id": "100000000000000001",
+        "version": "900000000000000001",
+        "default_member_permissions": null,
+        "type": 1,
+        "name": "ping",
+        "description": "pong",
+        "dm_permission": true,
+        "nsfw": false
+    }"#;
+
+    /// Listing captured with `with_localizations=true`
+    const LOCALIZED_COMMANDS: &str = r#"[{
+        "id": "800000000000000002",
+        "application_id": "100000000000000001",
+        "version": "900000000000000002",
+        "default_member_permissions": null,
+        "type": 1,
+        "name": "hello",
+        "name_localizations": {"fr": "bonjour", "de": "hallo"},
+        "description": "Say hello",
+        "description_localizations": {"fr": "Dire bonjour"},
+        "options": [{
+            "type": 3,
+            "name": "name",
+            "name_localizations": {"fr": "nom"},
+            "description": "Who to greet",
+            "description_localizations": null
+        }],
+        "dm_permission": true,
+        "nsfw": false
+    }, {
+        "id": "800000000000000003",
+        "application_id": "100000000000000001",
+        "version": "900000000000000003",
+        "default_member_permissions": null,
+        "type": 1,
+        "name": "plain",
+        "name_localizations": null,
+        "description": "No localizations",
+        "description_localizations": null,
+        "dm_permission": true,
+        "nsfw": false
+    }]"#;
+
+    fn localizations(pairs: &[(&str, &str)]) -> Option<std::collections::HashMap<String, String>> {
+        Some(
+            pairs
+                .iter()
+                .map(|(locale, value)| (locale.to_string(), value.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    pub fn get_commands_with_localizations() {
+        let server = MockServer::start(&[(200, LOCALIZED_COMMANDS), (200, "[]")]);
+        let client = server.client();
+
+        let query = CommandQuery {
+            with_localizations: true,
+        };
+        let commands = client.get_global_commands_with_query(&query).unwrap();
+        client
+            .get_guild_commands_with_query("200000000000000001", &CommandQuery::default())
+            .unwrap();
+
+        let mut hello = ApplicationCommand::new_chat_input_command(
+            "hello".to_string(),
+            "Say hello".to_string(),
+            None,
+            None,
+            None,
+            Some(vec![ApplicationCommandOption::new_string_option(
+                "name".to_string(),
+                "Who to greet".to_string(),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )]),
+        );
+        if let ApplicationCommand::ChatInputCommand(ref mut c) = hello {
+            c.details.name_localizations = localizations(&[("de", "hallo"), ("fr", "bonjour")]);
+            c.description_localizations = localizations(&[("fr", "Dire bonjour")]);
+            if let Some(ApplicationCommandOption::String(ref mut o)) =
+                c.options.as_mut().unwrap().first_mut()
+            {
+                o.name_localizations = localizations(&[("fr", "nom")]);
+            }
+        }
+
+        let plain = ApplicationCommand::new_chat_input_command(
+            "plain".to_string(),
+            "No localizations".to_string(),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(commands, [hello, plain]);
+
+        let requests = server.requests();
+
+        assert_eq!(
+            requests[0].path,
+            "/applications/100000000000000001/commands?with_localizations=true"
+        );
+        assert_eq!(
+            requests[1].path,
+            "/applications/100000000000000001/guilds/200000000000000001/commands"
+        );
+    }
+
+    #[test]
+    pub fn overwrite_with_previous() {
+        let server = MockServer::start(&[(200, &format!("[{COMMAND}]")), (200, "[]")]);
+
+        let (previous, new) = server
+            .client()
+            .overwrite_global_commands_with_previous(&vec![])
+            .unwrap();
+
+        assert_eq!(previous.len(), 1);
+        assert_eq!(
+            previous[0].as_chat_input_command().unwrap().details.name,
+            "ping"
+        );
+        assert!(new.is_empty());
+
+        let requests = server.requests();
+
+        let calls: Vec<_> = requests
+            .iter()
+            .map(|r| (r.method.as_str(), r.path.as_str()))
+            .collect();
+
+        assert_eq!(
+            calls,
+            [
+                ("GET", "/applications/100000000000000001/commands"),
+                ("PUT", "/applications/100000000000000001/commands"),
+            ]
+        );
+        assert_eq!(requests[1].body, "[]");
+    }
+
+    #[test]
+    pub fn overwrite_with_previous_stops_on_failed_fetch() {
+        let server = MockServer::start(&[(500, r#"{"message": "oops"}"#)]);
+
+        let res = server
+            .client()
+            .overwrite_global_commands_with_previous(&vec![]);
+
+        assert!(matches!(res, Err(Error::UnknownResponse(_))));
+
+        // only the GET was made, the commands were left alone
+        assert_eq!(server.requests().len(), 1);
+    }
+
     #[test]
     pub fn global_commands() {
         setup();
@@ -0,0 +1,94 @@
+use composure_core::models::Application;
+
+use crate::{DiscordClient, Result};
+
+impl DiscordClient {
+    /// [Get Current Application](https://discord.com/developers/docs/resources/application#get-current-application)
+    /// for the bot token the client was created with
+    pub fn get_current_application(&self) -> Result<Application> {
+        let url = format!("{}/applications/@me", self.base_url);
+
+        self.get(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use composure_core::models::{ApplicationFlags, Permissions, Snowflake};
+
+    use crate::{mock::MockServer, Error};
+
+    const APPLICATION: &str = r#"{
+        "id": "100000000000000001",
+        "name": "composure",
+        "icon": "0123456789abcdef0123456789abcdef",
+        "description": "Discord bot framework for running on the edge",
+        "summary": "",
+        "type": null,
+        "bot_public": true,
+        "bot_require_code_grant": false,
+        "terms_of_service_url": "https://example.com/tos",
+        "privacy_policy_url": null,
+        "verify_key": "852aec10972ef6dd0431747902c779342cc411ad6d42c2de16ef4c87895c61ad",
+        "flags": 8912896,
+        "tags": ["utility"],
+        "install_params": {
+            "scopes": ["applications.commands", "bot"],
+            "permissions": "2048"
+        },
+        "owner": {
+            "id": "400000000000000001",
+            "username": "user",
+            "avatar": null,
+            "discriminator": "0",
+            "global_name": "User",
+            "public_flags": 0,
+            "flags": 0
+        },
+        "team": null,
+        "hook": true,
+        "approximate_guild_count": 12,
+        "interactions_endpoint_url": "https://example.com/interactions",
+        "redirect_uris": []
+    }"#;
+
+    #[test]
+    pub fn current_application() {
+        let server = MockServer::start(&[(200, APPLICATION)]);
+
+        let application = server.client().get_current_application().unwrap();
+
+        assert_eq!(application.id, Snowflake::from_u64(100000000000000001));
+        assert_eq!(application.name, "composure");
+        assert!(application.bot_public);
+        assert_eq!(application.owner.unwrap().username, "user");
+
+        let flags = application.flags.unwrap();
+        assert!(flags.contains(ApplicationFlags::GatewayMessageContentLimited));
+        assert!(flags.contains(ApplicationFlags::ApplicationCommandBadge));
+
+        let install_params = application.install_params.unwrap();
+        assert_eq!(install_params.scopes, ["applications.commands", "bot"]);
+        assert_eq!(
+            install_params.permissions.bits(),
+            Permissions::SendMessages.bits()
+        );
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/applications/@me");
+        assert_eq!(requests[0].header("authorization"), Some("Bot TOKEN"));
+    }
+
+    #[test]
+    pub fn current_application_unauthorized() {
+        let server = MockServer::start(&[(401, r#"{"message": "401: Unauthorized", "code": 0}"#)]);
+
+        let res = server.client().get_current_application();
+
+        assert!(matches!(res, Err(Error::Unauthorized)));
+
+        server.requests();
+    }
+}
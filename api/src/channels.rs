@@ -0,0 +1,188 @@
+use composure_core::models::{Channel, CreateChannel, ModifyChannel, Snowflake};
+
+use crate::{with_reason, DiscordClient, Result};
+
+impl DiscordClient {
+    /// [Create Guild Channel](https://discord.com/developers/docs/resources/guild#create-guild-channel),
+    /// needs the MANAGE_CHANNELS permission and MANAGE_ROLES to set overwrites
+    pub fn create_guild_channel(
+        &self,
+        guild_id: &Snowflake,
+        channel: &CreateChannel,
+        reason: Option<&str>,
+    ) -> Result<Channel> {
+        let url = format!("{}/guilds/{}/channels", self.base_url, guild_id);
+
+        self.send_json(with_reason(self.client.post(url).json(channel), reason))
+    }
+
+    /// [Modify Channel](https://discord.com/developers/docs/resources/channel#modify-channel)
+    pub fn modify_channel(
+        &self,
+        channel_id: &Snowflake,
+        channel: &ModifyChannel,
+        reason: Option<&str>,
+    ) -> Result<Channel> {
+        let url = format!("{}/channels/{}", self.base_url, channel_id);
+
+        self.send_json(with_reason(self.client.patch(url).json(channel), reason))
+    }
+
+    /// [Delete/Close Channel](https://discord.com/developers/docs/resources/channel#deleteclose-channel),
+    /// returns the deleted channel, deleting a category leaves its channels without a parent
+    pub fn delete_channel(&self, channel_id: &Snowflake, reason: Option<&str>) -> Result<Channel> {
+        let url = format!("{}/channels/{}", self.base_url, channel_id);
+
+        self.send_json(with_reason(self.client.delete(url), reason))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use composure_core::models::{
+        ChannelType, DefaultReaction, ForumTagData, Overwrite, Permissions, SortOrderType,
+    };
+    use serde_json::json;
+
+    use super::*;
+    use crate::mock::MockServer;
+
+    const FORUM: &str = r#"{
+        "id": "300000000000000004",
+        "type": 15,
+        "guild_id": "200000000000000001",
+        "name": "help",
+        "position": 3,
+        "parent_id": "300000000000000010",
+        "nsfw": false,
+        "topic": null,
+        "rate_limit_per_user": 0,
+        "flags": 0,
+        "permission_overwrites": [],
+        "available_tags": [
+            {"id": "800000000000000001", "name": "bug", "moderated": false, "emoji_id": null, "emoji_name": "🐛"},
+            {"id": "800000000000000002", "name": "solved", "moderated": true, "emoji_id": null, "emoji_name": null}
+        ],
+        "default_reaction_emoji": {"emoji_id": null, "emoji_name": "👍"},
+        "default_sort_order": 1,
+        "default_forum_layout": 0,
+        "default_thread_rate_limit_per_user": 0
+    }"#;
+
+    const TEXT: &str = r#"{
+        "id": "300000000000000001",
+        "type": 0,
+        "guild_id": "200000000000000001",
+        "name": "general",
+        "position": 0,
+        "parent_id": null,
+        "nsfw": false,
+        "topic": null,
+        "rate_limit_per_user": 30,
+        "flags": 0,
+        "last_message_id": null
+    }"#;
+
+    fn body(request: &crate::mock::MockRequest) -> serde_json::Value {
+        serde_json::from_str(&request.body).unwrap()
+    }
+
+    #[test]
+    pub fn create_forum_channel() {
+        let server = MockServer::start(&[(201, FORUM)]);
+        let guild = Snowflake::from_u64(200000000000000001);
+
+        let forum = CreateChannel::new("help", ChannelType::GuildForum)
+            .with_parent(Snowflake::from_u64(300000000000000010))
+            .with_permission_overwrites(vec![Overwrite::role(
+                guild.clone(),
+                Permissions::empty(),
+                Permissions::SendMessages,
+            )])
+            .with_available_tags(vec![
+                ForumTagData::new("bug").with_emoji_name("🐛"),
+                ForumTagData::new("solved").moderated(),
+            ])
+            .with_default_reaction_emoji(DefaultReaction {
+                emoji_id: None,
+                emoji_name: Some("👍".to_string()),
+            })
+            .with_default_sort_order(SortOrderType::CreationDate);
+
+        let channel = server
+            .client()
+            .create_guild_channel(&guild, &forum, Some("setup"))
+            .unwrap();
+
+        assert_eq!(channel.t, ChannelType::GuildForum);
+        assert_eq!(channel.available_tags.unwrap().len(), 2);
+        assert_eq!(
+            channel.default_sort_order,
+            Some(SortOrderType::CreationDate)
+        );
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/guilds/200000000000000001/channels");
+        assert_eq!(requests[0].header("x-audit-log-reason"), Some("setup"));
+        assert_eq!(
+            body(&requests[0]),
+            json!({
+                "name": "help",
+                "type": 15,
+                "parent_id": "300000000000000010",
+                "permission_overwrites": [
+                    {"id": "200000000000000001", "type": 0, "allow": "0", "deny": "2048"}
+                ],
+                "available_tags": [
+                    {"name": "bug", "moderated": false, "emoji_id": null, "emoji_name": "🐛"},
+                    {"name": "solved", "moderated": true, "emoji_id": null, "emoji_name": null}
+                ],
+                "default_reaction_emoji": {"emoji_id": null, "emoji_name": "👍"},
+                "default_sort_order": 1
+            })
+        );
+    }
+
+    #[test]
+    pub fn modify_slowmode() {
+        let server = MockServer::start(&[(200, TEXT)]);
+
+        let channel = server
+            .client()
+            .modify_channel(
+                &Snowflake::from_u64(300000000000000001),
+                &ModifyChannel::new().with_rate_limit_per_user(30),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(channel.rate_limit_per_user, Some(30));
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "PATCH");
+        assert_eq!(requests[0].path, "/channels/300000000000000001");
+        assert_eq!(requests[0].header("x-audit-log-reason"), None);
+        assert_eq!(body(&requests[0]), json!({ "rate_limit_per_user": 30 }));
+    }
+
+    #[test]
+    pub fn delete_channel() {
+        let server = MockServer::start(&[(200, TEXT)]);
+
+        let channel = server
+            .client()
+            .delete_channel(&Snowflake::from_u64(300000000000000001), Some("unused"))
+            .unwrap();
+
+        assert_eq!(channel.name.as_deref(), Some("general"));
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "DELETE");
+        assert_eq!(requests[0].path, "/channels/300000000000000001");
+        assert_eq!(requests[0].header("x-audit-log-reason"), Some("unused"));
+    }
+}
@@ -0,0 +1,330 @@
+use composure_core::models::{
+    Message, MessageCallbackData, MessageFlags, Snowflake, UpdateMessageData,
+};
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use crate::{DiscordClient, Error, Result};
+
+/// [JSON error codes](https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes)
+/// Discord answers with once an interaction token is no longer usable
+const UNKNOWN_WEBHOOK: u32 = 10015;
+const INVALID_WEBHOOK_TOKEN: u32 = 50027;
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    code: u32,
+}
+
+/// true if `body` is one of Discord's errors for an expired interaction token
+fn is_expired_token(body: &str) -> bool {
+    matches!(
+        serde_json::from_str::<ErrorBody>(body),
+        Ok(ErrorBody {
+            code: UNKNOWN_WEBHOOK | INVALID_WEBHOOK_TOKEN
+        })
+    )
+}
+
+/// Followup urls hold the interaction token, reqwest's errors print the url they failed on
+fn without_url(err: Error) -> Error {
+    match err {
+        Error::RequestError(e) => Error::RequestError(e.without_url()),
+        err => err,
+    }
+}
+
+impl DiscordClient {
+    /// Like [send_json](Self::send_json), but an expired token is reported as
+    /// [Error::InteractionExpired], the body is checked before the status since Discord answers
+    /// an invalid webhook token with a 401
+    fn send_followup(&self, request: reqwest::blocking::RequestBuilder) -> Result<Message> {
+        let response = self.execute(request).map_err(without_url)?;
+
+        match response.status() {
+            status if status.is_success() => Ok(response
+                .json()
+                .map_err(|e| Error::RequestError(e.without_url()))?),
+            status => {
+                let body = response
+                    .text()
+                    .map_err(|e| Error::RequestError(e.without_url()))?;
+
+                if is_expired_token(&body) {
+                    Err(Error::InteractionExpired)
+                } else if status == StatusCode::UNAUTHORIZED {
+                    Err(Error::Unauthorized)
+                } else {
+                    Err(Error::UnknownResponse(body))
+                }
+            }
+        }
+    }
+
+    fn followup_url(&self, interaction_token: &str) -> String {
+        format!(
+            "{}/webhooks/{}/{}",
+            self.base_url, self.application_id, interaction_token
+        )
+    }
+
+    /// [Create Followup Message](https://discord.com/developers/docs/interactions/receiving-and-responding#create-followup-message)
+    ///
+    /// A followup can only be made ephemeral when it is created, set [MessageFlags::Ephemeral] in
+    /// `data.flags` to do so. Fails with [Error::InteractionExpired] once the token has expired.
+    pub fn create_followup(
+        &self,
+        interaction_token: &str,
+        data: &MessageCallbackData,
+    ) -> Result<Message> {
+        let url = self.followup_url(interaction_token);
+
+        self.send_followup(self.client.post(url).json(data))
+    }
+
+    /// Followup with `content` only visible to the user who invoked the interaction
+    pub fn create_ephemeral_followup(
+        &self,
+        interaction_token: &str,
+        content: impl Into<String>,
+    ) -> Result<Message> {
+        let data = MessageCallbackData {
+            tts: None,
+            content: Some(content.into()),
+            embeds: None,
+            allowed_mentions: None,
+            flags: Some(MessageFlags::Ephemeral),
+            components: None,
+            attachments: None,
+        };
+
+        self.create_followup(interaction_token, &data)
+    }
+
+    /// [Edit Followup Message](https://discord.com/developers/docs/interactions/receiving-and-responding#edit-followup-message)
+    ///
+    /// Only the fields set or cleared in `data` change, so components can be swapped without
+    /// resending the content. Ephemeral followups can still be edited until the token expires, after
+    /// which this fails with [Error::InteractionExpired].
+    pub fn edit_followup(
+        &self,
+        interaction_token: &str,
+        message_id: &Snowflake,
+        data: &UpdateMessageData,
+    ) -> Result<Message> {
+        let url = format!(
+            "{}/messages/{}",
+            self.followup_url(interaction_token),
+            message_id
+        );
+
+        self.send_followup(self.client.patch(url).json(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use composure_core::models::{ActionRow, Component, Patch};
+
+    use super::*;
+    use crate::mock::MockServer;
+
+    const FOLLOWUP: &str = r#"{
+        "id": "600000000000000002",
+        "channel_id": "300000000000000001",
+        "author": {
+            "id": "100000000000000001",
+            "username": "composure",
+            "avatar": null,
+            "discriminator": "0",
+            "public_flags": 0
+        },
+        "content": "hello",
+        "timestamp": "2023-01-01T00:00:00.000000+00:00",
+        "edited_timestamp": null,
+        "tts": false,
+        "mention_everyone": false,
+        "mentions": [],
+        "mention_roles": [],
+        "attachments": [],
+        "embeds": [],
+        "pinned": false,
+        "type": 0,
+        "flags": 64
+    }"#;
+
+    const UNKNOWN_WEBHOOK_BODY: &str = r#"{"message": "Unknown Webhook", "code": 10015}"#;
+
+    fn body(request: &crate::mock::MockRequest) -> serde_json::Value {
+        serde_json::from_str(&request.body).unwrap()
+    }
+
+    #[test]
+    pub fn ephemeral_followup_sets_flags() {
+        let server = MockServer::start(&[(200, FOLLOWUP)]);
+
+        let message = server
+            .client()
+            .create_ephemeral_followup("INTERACTION_TOKEN", "hello")
+            .unwrap();
+
+        assert!(message.flags.unwrap().contains(MessageFlags::Ephemeral));
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(
+            requests[0].path,
+            "/webhooks/100000000000000001/INTERACTION_TOKEN"
+        );
+        assert_eq!(
+            body(&requests[0]),
+            serde_json::json!({ "content": "hello", "flags": 64 })
+        );
+    }
+
+    #[test]
+    pub fn followup_without_flags() {
+        let server = MockServer::start(&[(200, FOLLOWUP)]);
+
+        let data = MessageCallbackData {
+            tts: None,
+            content: Some("hello".to_string()),
+            embeds: None,
+            allowed_mentions: None,
+            flags: None,
+            components: None,
+            attachments: None,
+        };
+
+        server
+            .client()
+            .create_followup("INTERACTION_TOKEN", &data)
+            .unwrap();
+
+        let requests = server.requests();
+
+        assert!(body(&requests[0]).get("flags").is_none());
+    }
+
+    #[test]
+    pub fn edit_followup_swaps_components() {
+        let server = MockServer::start(&[(200, FOLLOWUP)]);
+
+        let data = UpdateMessageData {
+            components: Patch::Set(vec![ActionRow::new(vec![Component::link_button(
+                "Open".to_string(),
+                "https://example.com".to_string(),
+            )])]),
+            ..Default::default()
+        };
+
+        server
+            .client()
+            .edit_followup(
+                "INTERACTION_TOKEN",
+                &Snowflake::from_u64(600000000000000002),
+                &data,
+            )
+            .unwrap();
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "PATCH");
+        assert_eq!(
+            requests[0].path,
+            "/webhooks/100000000000000001/INTERACTION_TOKEN/messages/600000000000000002"
+        );
+
+        let body = body(&requests[0]);
+        assert!(body.get("content").is_none());
+        assert!(body.get("flags").is_none());
+        assert_eq!(
+            body["components"][0]["components"][0]["url"],
+            "https://example.com"
+        );
+    }
+
+    #[test]
+    pub fn expired_token_errors() {
+        let cases = [
+            (404, UNKNOWN_WEBHOOK_BODY, true),
+            (
+                401,
+                r#"{"message": "Invalid Webhook Token", "code": 50027}"#,
+                true,
+            ),
+            (
+                400,
+                r#"{"message": "Invalid Form Body", "code": 50035}"#,
+                false,
+            ),
+            (
+                403,
+                r#"{"message": "Missing Access", "code": 50001}"#,
+                false,
+            ),
+            (500, "not json", false),
+        ];
+
+        for (status, response, expired) in cases {
+            let server = MockServer::start(&[(status, response)]);
+
+            let res = server.client().edit_followup(
+                "INTERACTION_TOKEN",
+                &Snowflake::from_u64(600000000000000002),
+                &UpdateMessageData::new().with_content("edited"),
+            );
+
+            match res {
+                Err(Error::InteractionExpired) => assert!(expired, "{response}"),
+                Err(Error::UnknownResponse(body)) => {
+                    assert!(!expired, "{response}");
+                    assert_eq!(body, response);
+                }
+                other => panic!("unexpected result {other:?}"),
+            }
+
+            server.requests();
+        }
+    }
+
+    #[test]
+    pub fn request_errors_hide_token() {
+        let server = MockServer::start(&[(200, "not json")]);
+
+        let errors = [
+            server
+                .client()
+                .create_ephemeral_followup("INTERACTION_TOKEN", "hello")
+                .unwrap_err(),
+            // nothing listens on port 1
+            server
+                .client()
+                .with_base_url("http://127.0.0.1:1")
+                .create_ephemeral_followup("INTERACTION_TOKEN", "hello")
+                .unwrap_err(),
+        ];
+
+        for err in errors {
+            assert!(matches!(err, Error::RequestError(_)), "{err:?}");
+            assert!(!err.to_string().contains("INTERACTION_TOKEN"), "{err}");
+            assert!(!format!("{err:?}").contains("INTERACTION_TOKEN"), "{err:?}");
+        }
+
+        server.requests();
+    }
+
+    #[test]
+    pub fn create_followup_expired() {
+        let server = MockServer::start(&[(404, UNKNOWN_WEBHOOK_BODY)]);
+
+        let res = server
+            .client()
+            .create_ephemeral_followup("INTERACTION_TOKEN", "late");
+
+        assert!(matches!(res, Err(Error::InteractionExpired)));
+
+        server.requests();
+    }
+}
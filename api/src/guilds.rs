@@ -0,0 +1,529 @@
+use composure_core::models::{
+    Guild, GuildTemplate, GuildVanityUrl, GuildWidget, GuildWidgetSettings, Integration, Snowflake,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{DiscordClient, Result};
+
+/// Most days of inactivity a prune can count
+pub const PRUNE_MAX_DAYS: u8 = 30;
+
+#[derive(Serialize)]
+struct GetGuildQuery {
+    with_counts: bool,
+}
+
+#[derive(Serialize)]
+struct PruneQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    days: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_roles: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PruneCount {
+    pruned: u32,
+}
+
+/// Ids joined with commas the way Discord expects them in a query string, `None` when empty
+fn comma_separated(ids: &[Snowflake]) -> Option<String> {
+    if ids.is_empty() {
+        return None;
+    }
+
+    Some(
+        ids.iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(","),
+    )
+}
+
+#[derive(Serialize)]
+struct CreateGuildTemplate<'a> {
+    name: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+}
+
+impl DiscordClient {
+    /// [Get Guild](https://discord.com/developers/docs/resources/guild#get-guild)
+    ///
+    /// With `with_counts` the guild has its approximate member and presence counts
+    pub fn get_guild(&self, guild_id: &Snowflake, with_counts: bool) -> Result<Guild> {
+        let url = format!("{}/guilds/{}", self.base_url, guild_id);
+
+        self.get_with_query(url, &GetGuildQuery { with_counts })
+    }
+
+    /// [Get Guild Prune Count](https://discord.com/developers/docs/resources/guild#get-guild-prune-count),
+    /// how many members a prune would kick without pruning any
+    ///
+    /// `days` of inactivity is clamped to 1-30, Discord defaults to 7. Members are only counted
+    /// when they have no roles, or only roles in `include_roles`.
+    pub fn get_guild_prune_count(
+        &self,
+        guild_id: &Snowflake,
+        days: Option<u8>,
+        include_roles: &[Snowflake],
+    ) -> Result<u32> {
+        let url = format!("{}/guilds/{}/prune", self.base_url, guild_id);
+
+        let query = PruneQuery {
+            days: days.map(|d| d.clamp(1, PRUNE_MAX_DAYS)),
+            include_roles: comma_separated(include_roles),
+        };
+
+        let count: PruneCount = self.get_with_query(url, &query)?;
+
+        Ok(count.pruned)
+    }
+
+    /// [Get Guild Widget Settings](https://discord.com/developers/docs/resources/guild#get-guild-widget-settings)
+    pub fn get_guild_widget_settings(&self, guild_id: &Snowflake) -> Result<GuildWidgetSettings> {
+        let url = format!("{}/guilds/{}/widget", self.base_url, guild_id);
+
+        self.get(url)
+    }
+
+    /// [Modify Guild Widget](https://discord.com/developers/docs/resources/guild#modify-guild-widget)
+    ///
+    /// A `channel_id` of `None` removes the widget's invite channel
+    pub fn modify_guild_widget(
+        &self,
+        guild_id: &Snowflake,
+        enabled: bool,
+        channel_id: Option<Snowflake>,
+    ) -> Result<GuildWidgetSettings> {
+        let url = format!("{}/guilds/{}/widget", self.base_url, guild_id);
+
+        self.patch(
+            url,
+            &GuildWidgetSettings {
+                enabled,
+                channel_id,
+            },
+        )
+    }
+
+    /// [Get Guild Widget](https://discord.com/developers/docs/resources/guild#get-guild-widget)
+    ///
+    /// Fails unless the widget is enabled for the guild
+    pub fn get_guild_widget(&self, guild_id: &Snowflake) -> Result<GuildWidget> {
+        let url = format!("{}/guilds/{}/widget.json", self.base_url, guild_id);
+
+        self.get(url)
+    }
+
+    /// [Get Guild Vanity URL](https://discord.com/developers/docs/resources/guild#get-guild-vanity-url)
+    pub fn get_guild_vanity_url(&self, guild_id: &Snowflake) -> Result<GuildVanityUrl> {
+        let url = format!("{}/guilds/{}/vanity-url", self.base_url, guild_id);
+
+        self.get(url)
+    }
+
+    /// [Get Guild Integrations](https://discord.com/developers/docs/resources/guild#get-guild-integrations)
+    pub fn get_guild_integrations(&self, guild_id: &Snowflake) -> Result<Vec<Integration>> {
+        let url = format!("{}/guilds/{}/integrations", self.base_url, guild_id);
+
+        self.get(url)
+    }
+
+    /// [Delete Guild Integration](https://discord.com/developers/docs/resources/guild#delete-guild-integration),
+    /// removing a bot integration also kicks the bot
+    pub fn delete_guild_integration(
+        &self,
+        guild_id: &Snowflake,
+        integration_id: &Snowflake,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/guilds/{}/integrations/{}",
+            self.base_url, guild_id, integration_id
+        );
+
+        self.send_no_content(self.client.delete(url), reason)
+    }
+
+    /// [Get Guild Template](https://discord.com/developers/docs/resources/guild-template#get-guild-template)
+    pub fn get_template(&self, code: &str) -> Result<GuildTemplate> {
+        let url = format!("{}/guilds/templates/{}", self.base_url, code);
+
+        self.get(url)
+    }
+
+    /// [Get Guild Templates](https://discord.com/developers/docs/resources/guild-template#get-guild-templates)
+    pub fn get_guild_templates(&self, guild_id: &Snowflake) -> Result<Vec<GuildTemplate>> {
+        let url = format!("{}/guilds/{}/templates", self.base_url, guild_id);
+
+        self.get(url)
+    }
+
+    /// [Create Guild Template](https://discord.com/developers/docs/resources/guild-template#create-guild-template)
+    ///
+    /// `name` is 1-100 characters, `description` up to 120
+    pub fn create_guild_template(
+        &self,
+        guild_id: &Snowflake,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GuildTemplate> {
+        let url = format!("{}/guilds/{}/templates", self.base_url, guild_id);
+
+        self.send_json(
+            self.client
+                .post(url)
+                .json(&CreateGuildTemplate { name, description }),
+        )
+    }
+
+    /// [Sync Guild Template](https://discord.com/developers/docs/resources/guild-template#sync-guild-template),
+    /// updates the template to the guild's current state
+    pub fn sync_guild_template(&self, guild_id: &Snowflake, code: &str) -> Result<GuildTemplate> {
+        let url = format!("{}/guilds/{}/templates/{}", self.base_url, guild_id, code);
+
+        self.send_json(self.client.put(url))
+    }
+
+    /// [Delete Guild Template](https://discord.com/developers/docs/resources/guild-template#delete-guild-template),
+    /// returns the deleted template
+    pub fn delete_guild_template(&self, guild_id: &Snowflake, code: &str) -> Result<GuildTemplate> {
+        let url = format!("{}/guilds/{}/templates/{}", self.base_url, guild_id, code);
+
+        self.send_json(self.client.delete(url))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockServer;
+
+    fn guild() -> Snowflake {
+        Snowflake::from_u64(200000000000000001)
+    }
+
+    const GUILD: &str = r#"{
+        "id": "200000000000000001",
+        "name": "Test Server",
+        "icon": null,
+        "splash": null,
+        "discovery_splash": null,
+        "owner_id": "400000000000000001",
+        "afk_channel_id": null,
+        "afk_timeout": 300,
+        "verification_level": 2,
+        "default_message_notifications": 1,
+        "explicit_content_filter": 2,
+        "roles": [],
+        "emojis": [],
+        "features": ["COMMUNITY"],
+        "mfa_level": 0,
+        "application_id": null,
+        "system_channel_id": "300000000000000001",
+        "system_channel_flags": 0,
+        "rules_channel_id": null,
+        "vanity_url_code": null,
+        "description": null,
+        "banner": null,
+        "premium_tier": 1,
+        "premium_subscription_count": 3,
+        "preferred_locale": "en-US",
+        "public_updates_channel_id": null,
+        "nsfw_level": 0,
+        "premium_progress_bar_enabled": false,
+        "approximate_member_count": 120,
+        "approximate_presence_count": 42
+    }"#;
+
+    #[test]
+    pub fn get_guild() {
+        let mut without_counts: serde_json::Value = serde_json::from_str(GUILD).unwrap();
+        let fields = without_counts.as_object_mut().unwrap();
+        fields.remove("approximate_member_count");
+        fields.remove("approximate_presence_count");
+        let without_counts = without_counts.to_string();
+
+        let server = MockServer::start(&[(200, GUILD), (200, &without_counts)]);
+        let client = server.client();
+
+        let guild_with_counts = client.get_guild(&guild(), true).unwrap();
+        assert_eq!(guild_with_counts.name, "Test Server");
+        assert_eq!(
+            guild_with_counts.verification_level,
+            composure_core::models::VerificationLevel::Medium
+        );
+        assert_eq!(guild_with_counts.approximate_member_count, Some(120));
+        assert_eq!(guild_with_counts.approximate_presence_count, Some(42));
+
+        let guild_without_counts = client.get_guild(&guild(), false).unwrap();
+        assert!(guild_without_counts.approximate_member_count.is_none());
+        assert!(guild_without_counts.approximate_presence_count.is_none());
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(
+            requests[0].path,
+            "/guilds/200000000000000001?with_counts=true"
+        );
+        assert_eq!(
+            requests[1].path,
+            "/guilds/200000000000000001?with_counts=false"
+        );
+    }
+
+    #[test]
+    pub fn prune_count() {
+        let server = MockServer::start(&[(200, r#"{"pruned": 12}"#), (200, r#"{"pruned": 3}"#)]);
+        let client = server.client();
+
+        assert_eq!(
+            client.get_guild_prune_count(&guild(), None, &[]).unwrap(),
+            12
+        );
+
+        let roles = [
+            Snowflake::from_u64(500000000000000001),
+            Snowflake::from_u64(500000000000000002),
+        ];
+        assert_eq!(
+            client
+                .get_guild_prune_count(&guild(), Some(90), &roles)
+                .unwrap(),
+            3
+        );
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/guilds/200000000000000001/prune");
+        assert_eq!(
+            requests[1].path,
+            "/guilds/200000000000000001/prune?days=30&include_roles=500000000000000001%2C500000000000000002"
+        );
+    }
+
+    #[test]
+    pub fn widget_settings() {
+        let server = MockServer::start(&[
+            (
+                200,
+                r#"{"enabled": true, "channel_id": "300000000000000001"}"#,
+            ),
+            (200, r#"{"enabled": false, "channel_id": null}"#),
+        ]);
+        let client = server.client();
+
+        let settings = client.get_guild_widget_settings(&guild()).unwrap();
+        assert!(settings.enabled);
+        assert_eq!(
+            settings.channel_id,
+            Some(Snowflake::from_u64(300000000000000001))
+        );
+
+        let settings = client.modify_guild_widget(&guild(), false, None).unwrap();
+        assert!(!settings.enabled);
+        assert!(settings.channel_id.is_none());
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/guilds/200000000000000001/widget");
+        assert_eq!(requests[1].method, "PATCH");
+        assert_eq!(requests[1].path, "/guilds/200000000000000001/widget");
+        assert_eq!(requests[1].body, r#"{"enabled":false,"channel_id":null}"#);
+    }
+
+    #[test]
+    pub fn widget() {
+        let server = MockServer::start(&[(
+            200,
+            r#"{
+                "id": "200000000000000001",
+                "name": "Test Server",
+                "instant_invite": null,
+                "channels": [],
+                "members": [{
+                    "id": "0",
+                    "username": "user",
+                    "discriminator": "0000",
+                    "avatar": null,
+                    "status": "dnd",
+                    "avatar_url": "https://cdn.discordapp.com/widget-avatars/abc/def"
+                }],
+                "presence_count": 1
+            }"#,
+        )]);
+
+        let widget = server.client().get_guild_widget(&guild()).unwrap();
+
+        assert_eq!(widget.presence_count, 1);
+        assert_eq!(widget.members[0].status, "dnd");
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].path, "/guilds/200000000000000001/widget.json");
+    }
+
+    #[test]
+    pub fn widget_disabled() {
+        let server =
+            MockServer::start(&[(403, r#"{"message": "Widget Disabled", "code": 50004}"#)]);
+
+        let res = server.client().get_guild_widget(&guild());
+
+        assert!(matches!(res, Err(crate::Error::UnknownResponse(_))));
+
+        server.requests();
+    }
+
+    #[test]
+    pub fn vanity_url() {
+        let server = MockServer::start(&[
+            (200, r#"{"code": "composure", "uses": 42}"#),
+            (200, r#"{"code": null, "uses": 0}"#),
+        ]);
+        let client = server.client();
+
+        let vanity = client.get_guild_vanity_url(&guild()).unwrap();
+        assert_eq!(vanity.code.as_deref(), Some("composure"));
+        assert_eq!(vanity.uses, 42);
+
+        let vanity = client.get_guild_vanity_url(&guild()).unwrap();
+        assert!(vanity.code.is_none());
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].path, "/guilds/200000000000000001/vanity-url");
+    }
+
+    const TEMPLATE: &str = r#"{
+        "code": "hgM48av5Q69A",
+        "name": "Base",
+        "description": null,
+        "usage_count": 0,
+        "creator_id": "400000000000000001",
+        "creator": {
+            "id": "400000000000000001",
+            "username": "user",
+            "avatar": null,
+            "discriminator": "0",
+            "public_flags": 0
+        },
+        "created_at": "2023-01-01T00:00:00+00:00",
+        "updated_at": "2023-01-01T00:00:00+00:00",
+        "source_guild_id": "200000000000000001",
+        "serialized_source_guild": {"name": "Base", "roles": [], "channels": []},
+        "is_dirty": true
+    }"#;
+
+    #[test]
+    pub fn templates() {
+        let server = MockServer::start(&[
+            (200, TEMPLATE),
+            (200, &format!("[{TEMPLATE}]")),
+            (200, TEMPLATE),
+            (200, TEMPLATE),
+            (200, TEMPLATE),
+        ]);
+        let client = server.client();
+
+        let template = client.get_template("hgM48av5Q69A").unwrap();
+        assert_eq!(template.name, "Base");
+        assert_eq!(template.is_dirty, Some(true));
+
+        let templates = client.get_guild_templates(&guild()).unwrap();
+        assert_eq!(templates.len(), 1);
+
+        client
+            .create_guild_template(&guild(), "Base", None)
+            .unwrap();
+        client
+            .sync_guild_template(&guild(), "hgM48av5Q69A")
+            .unwrap();
+        client
+            .delete_guild_template(&guild(), "hgM48av5Q69A")
+            .unwrap();
+
+        let requests = server.requests();
+
+        let calls: Vec<_> = requests
+            .iter()
+            .map(|r| (r.method.as_str(), r.path.as_str()))
+            .collect();
+
+        assert_eq!(
+            calls,
+            [
+                ("GET", "/guilds/templates/hgM48av5Q69A"),
+                ("GET", "/guilds/200000000000000001/templates"),
+                ("POST", "/guilds/200000000000000001/templates"),
+                ("PUT", "/guilds/200000000000000001/templates/hgM48av5Q69A"),
+                (
+                    "DELETE",
+                    "/guilds/200000000000000001/templates/hgM48av5Q69A"
+                ),
+            ]
+        );
+        assert_eq!(requests[2].body, r#"{"name":"Base"}"#);
+    }
+
+    #[test]
+    pub fn template_not_found() {
+        let server = MockServer::start(&[(
+            404,
+            r#"{"message": "Unknown Guild Template", "code": 10057}"#,
+        )]);
+
+        let res = server.client().get_template("missing");
+
+        assert!(matches!(res, Err(crate::Error::UnknownResponse(_))));
+
+        server.requests();
+    }
+
+    #[test]
+    pub fn integrations() {
+        let server = MockServer::start(&[
+            (
+                200,
+                r#"[{
+                    "id": "600000000000000011",
+                    "name": "Composure",
+                    "type": "discord",
+                    "enabled": true,
+                    "account": {"id": "100000000000000001", "name": "Composure"},
+                    "application": {
+                        "id": "100000000000000001",
+                        "name": "Composure",
+                        "icon": null,
+                        "description": ""
+                    }
+                }]"#,
+            ),
+            (204, ""),
+        ]);
+        let client = server.client();
+
+        let integrations = client.get_guild_integrations(&guild()).unwrap();
+        assert!(integrations[0].is_bot());
+
+        client
+            .delete_guild_integration(&guild(), &integrations[0].id, Some("stale"))
+            .unwrap();
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].path, "/guilds/200000000000000001/integrations");
+        assert_eq!(requests[1].method, "DELETE");
+        assert_eq!(
+            requests[1].path,
+            "/guilds/200000000000000001/integrations/600000000000000011"
+        );
+        assert_eq!(requests[1].header("x-audit-log-reason"), Some("stale"));
+    }
+}
@@ -1,13 +1,32 @@
 use composure_commands::command::{ApplicationCommand, CommandsBuilder};
 use reqwest::{
+    blocking::{RequestBuilder, Response},
     header::{self, AUTHORIZATION},
     IntoUrl, StatusCode,
 };
 use serde::{de::DeserializeOwned, Serialize};
 
 mod application_commands;
+mod applications;
+mod channels;
+mod followups;
+mod guilds;
+mod members;
+mod messages;
+mod roles;
+mod skus;
+mod users;
+
+#[cfg(test)]
+mod mock;
 
 pub use application_commands::*;
+pub use followups::*;
+pub use guilds::*;
+pub use members::*;
+pub use messages::*;
+pub use skus::*;
+pub use users::*;
 
 pub const DISCORD_API: &str = "https://discord.com/api/v10";
 
@@ -16,14 +35,50 @@ pub enum Error {
     RequestError(reqwest::Error),
     HeaderError(header::InvalidHeaderValue),
     Unauthorized,
+
+    /// the interaction token expired or its webhook no longer exists, followups can only be sent or
+    /// edited for 15 minutes after the interaction
+    InteractionExpired,
     UnknownResponse(String),
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::RequestError(e) => write!(f, "request failed: {e}"),
+            Error::HeaderError(e) => write!(f, "invalid header: {e}"),
+            Error::Unauthorized => write!(f, "unauthorized, check the bot token"),
+            Error::InteractionExpired => write!(f, "interaction expired"),
+            Error::UnknownResponse(body) => write!(f, "unknown response: {body}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::RequestError(e) => Some(e),
+            Error::HeaderError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for composure_core::Error {
+    fn from(err: Error) -> Self {
+        composure_core::Error::Api(Box::new(err))
+    }
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
 
 pub struct DiscordClient {
     client: reqwest::blocking::Client,
+
+    /// bot authorization added to each request, for clients built without it as a default header
+    authorization: Option<header::HeaderValue>,
     application_id: String,
+    base_url: String,
 }
 
 impl DiscordClient {
@@ -43,23 +98,84 @@ impl DiscordClient {
 
         Ok(DiscordClient {
             client,
+            authorization: None,
             application_id: application_id.to_string(),
+            base_url: DISCORD_API.to_string(),
         })
     }
 
+    /// Reuses an already configured client, like one with a proxy, TLS settings or a shared
+    /// connection pool
+    ///
+    /// `client` doesn't need the bot authorization, it is added to every request that doesn't set
+    /// its own.
+    pub fn from_client(
+        client: reqwest::blocking::Client,
+        token: &str,
+        application_id: &str,
+    ) -> Result<DiscordClient> {
+        let mut authorization = header::HeaderValue::from_str(format!("Bot {token}").as_str())
+            .map_err(Error::HeaderError)?;
+        authorization.set_sensitive(true);
+
+        Ok(DiscordClient {
+            client,
+            authorization: Some(authorization),
+            application_id: application_id.to_string(),
+            base_url: DISCORD_API.to_string(),
+        })
+    }
+
+    /// Sends requests somewhere other than [DISCORD_API], such as a proxy or a mock server
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sends a request, adding the bot authorization when the client doesn't add it itself
+    fn execute(&self, request: RequestBuilder) -> Result<Response> {
+        let mut request = request.build().map_err(Error::RequestError)?;
+
+        if let Some(authorization) = &self.authorization {
+            // like a default header, a bearer token set on the request takes precedence
+            if !request.headers().contains_key(AUTHORIZATION) {
+                request
+                    .headers_mut()
+                    .insert(AUTHORIZATION, authorization.clone());
+            }
+        }
+
+        self.client.execute(request).map_err(Error::RequestError)
+    }
+
     fn get<T, U: DeserializeOwned>(&self, url: T) -> Result<U>
     where
         T: IntoUrl,
     {
-        let response = self
-            .client
-            .get(url)
-            .send()
-            .map_err(|e| Error::RequestError(e))?;
+        let response = self.execute(self.client.get(url))?;
 
         match response.status() {
             StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
-            _ => Ok(response.json().map_err(|e| Error::RequestError(e))?),
+            status if status.is_success() => Ok(response.json().map_err(Error::RequestError)?),
+            _ => Err(Error::UnknownResponse(
+                response.text().map_err(Error::RequestError)?,
+            )),
+        }
+    }
+
+    fn get_with_query<T, Q, U: DeserializeOwned>(&self, url: T, query: &Q) -> Result<U>
+    where
+        T: IntoUrl,
+        Q: Serialize,
+    {
+        let response = self.execute(self.client.get(url).query(query))?;
+
+        match response.status() {
+            StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
+            status if status.is_success() => Ok(response.json().map_err(Error::RequestError)?),
+            _ => Err(Error::UnknownResponse(
+                response.text().map_err(Error::RequestError)?,
+            )),
         }
     }
 
@@ -68,12 +184,7 @@ impl DiscordClient {
         T: IntoUrl,
         U: Serialize,
     {
-        let response = self
-            .client
-            .post(url)
-            .json(body)
-            .send()
-            .map_err(|e| Error::RequestError(e))?;
+        let response = self.execute(self.client.post(url).json(body))?;
 
         match response.status() {
             StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
@@ -86,12 +197,7 @@ impl DiscordClient {
         T: IntoUrl,
         U: Serialize,
     {
-        let response = self
-            .client
-            .put(url)
-            .json(body)
-            .send()
-            .map_err(|e| Error::RequestError(e))?;
+        let response = self.execute(self.client.put(url).json(body))?;
 
         match response.status() {
             StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
@@ -103,6 +209,95 @@ impl DiscordClient {
             )),
         }
     }
+
+    fn patch<T, U, R: DeserializeOwned>(&self, url: T, body: &U) -> Result<R>
+    where
+        T: IntoUrl,
+        U: Serialize,
+    {
+        let response = self.execute(self.client.patch(url).json(body))?;
+
+        match response.status() {
+            StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
+            status if status.is_success() => Ok(response.json().map_err(Error::RequestError)?),
+            _ => Err(Error::UnknownResponse(
+                response.text().map_err(Error::RequestError)?,
+            )),
+        }
+    }
+
+    /// Sends a request and parses the JSON it returns, for requests with no body or a reason
+    fn send_json<R: DeserializeOwned>(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> Result<R> {
+        let response = self.execute(request)?;
+
+        match response.status() {
+            StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
+            status if status.is_success() => Ok(response.json().map_err(Error::RequestError)?),
+            _ => Err(Error::UnknownResponse(
+                response.text().map_err(Error::RequestError)?,
+            )),
+        }
+    }
+
+    /// Sends a request that doesn't return a body, optionally with an audit log reason
+    fn send_no_content(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let response = self.execute(with_reason(request, reason))?;
+
+        match response.status() {
+            StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
+            status if status.is_success() => Ok(()),
+            _ => Err(Error::UnknownResponse(
+                response.text().map_err(Error::RequestError)?,
+            )),
+        }
+    }
+}
+
+/// Per request override of the client's bot authorization
+trait WithBearer {
+    /// Authenticates the request with a user's OAuth2 access token instead of the bot token
+    fn with_bearer(self, token: &str) -> Self;
+}
+
+impl WithBearer for reqwest::blocking::RequestBuilder {
+    fn with_bearer(self, token: &str) -> Self {
+        // headers set on the request take precedence over the client's default headers
+        self.bearer_auth(token)
+    }
+}
+
+/// Header used to attach a reason to the audit log entry of an action
+const AUDIT_LOG_REASON: &str = "X-Audit-Log-Reason";
+
+/// Attaches `reason` to the request's audit log entry, if there is one
+fn with_reason(
+    request: reqwest::blocking::RequestBuilder,
+    reason: Option<&str>,
+) -> reqwest::blocking::RequestBuilder {
+    match reason {
+        Some(reason) => request.header(AUDIT_LOG_REASON, encode_reason(reason)),
+        None => request,
+    }
+}
+
+/// Percent encodes an audit log reason, Discord expects it URL encoded so it may hold any UTF-8
+fn encode_reason(reason: &str) -> String {
+    reason
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
 }
 
 pub trait UpdateCommands {
@@ -123,3 +318,73 @@ impl UpdateCommands for CommandsBuilder {
         Ok(updated_commands)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use super::*;
+    use crate::mock::MockServer;
+
+    #[test]
+    pub fn from_client() {
+        let server = MockServer::start(&[(200, "[]"), (200, "[]")]);
+
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::USER_AGENT,
+            header::HeaderValue::from_static("my-app/1.0"),
+        );
+        let client = reqwest::blocking::Client::builder()
+            .default_headers(headers)
+            .build()
+            .unwrap();
+
+        let client = DiscordClient::from_client(client, "TOKEN", "100000000000000001")
+            .unwrap()
+            .with_base_url(server.url());
+
+        assert!(client.get_global_commands().unwrap().is_empty());
+        assert!(client.get_user_connections("ACCESS").unwrap().is_empty());
+
+        let requests = server.requests();
+
+        assert_eq!(
+            requests[0].path,
+            "/applications/100000000000000001/commands"
+        );
+        assert_eq!(requests[0].header("authorization"), Some("Bot TOKEN"));
+        assert_eq!(requests[0].header("user-agent"), Some("my-app/1.0"));
+
+        // a bearer token replaces the bot authorization rather than being sent alongside it
+        let authorization: Vec<_> = requests[1]
+            .headers
+            .iter()
+            .filter(|(key, _)| key.eq_ignore_ascii_case("authorization"))
+            .map(|(_, value)| value.as_str())
+            .collect();
+        assert_eq!(authorization, ["Bearer ACCESS"]);
+
+        assert!(matches!(
+            DiscordClient::from_client(reqwest::blocking::Client::new(), "\n", "1"),
+            Err(Error::HeaderError(_))
+        ));
+    }
+
+    #[test]
+    pub fn into_composure_error() {
+        let err: composure_core::Error = Error::UnknownResponse("{}".to_string()).into();
+
+        assert!(matches!(err, composure_core::Error::Api(_)));
+        assert_eq!(err.to_string(), "api error: unknown response: {}");
+        assert!(err.source().unwrap().is::<Error>());
+
+        let header = header::HeaderValue::from_str("\n").unwrap_err();
+        let message = header.to_string();
+        let err: composure_core::Error = Error::HeaderError(header).into();
+
+        let source = err.source().unwrap();
+        assert_eq!(source.to_string(), format!("invalid header: {message}"));
+        assert_eq!(source.source().unwrap().to_string(), message);
+    }
+}
@@ -0,0 +1,212 @@
+use composure_core::models::{
+    ApplicationCommandOptionChoice, ApplicationCommandOptionChoiceValue, Member, Snowflake,
+    AUTOCOMPLETE_CHOICE_LIMIT,
+};
+use serde::Serialize;
+
+use crate::{DiscordClient, Result};
+
+/// Most members returned by one search or list request
+pub const MEMBER_LIMIT: u16 = 1000;
+
+#[derive(Serialize)]
+struct SearchQuery<'a> {
+    query: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u16>,
+}
+
+#[derive(Serialize)]
+struct ListQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u16>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<Snowflake>,
+}
+
+impl DiscordClient {
+    /// [Search Guild Members](https://discord.com/developers/docs/resources/guild#search-guild-members)
+    /// whose username or nickname starts with `query`
+    ///
+    /// `limit` is clamped to 1-1000, Discord defaults to 1
+    pub fn search_guild_members(
+        &self,
+        guild_id: &Snowflake,
+        query: &str,
+        limit: Option<u16>,
+    ) -> Result<Vec<Member>> {
+        let url = format!("{}/guilds/{}/members/search", self.base_url, guild_id);
+
+        let query = SearchQuery {
+            query,
+            limit: limit.map(|l| l.clamp(1, MEMBER_LIMIT)),
+        };
+
+        self.get_with_query(url, &query)
+    }
+
+    /// [List Guild Members](https://discord.com/developers/docs/resources/guild#list-guild-members),
+    /// needs the privileged GUILD_MEMBERS intent
+    ///
+    /// `limit` is clamped to 1-1000, Discord defaults to 1. Members are ordered by user id, pass
+    /// the last id of a page as `after` for the next one.
+    pub fn list_guild_members(
+        &self,
+        guild_id: &Snowflake,
+        limit: Option<u16>,
+        after: Option<Snowflake>,
+    ) -> Result<Vec<Member>> {
+        let url = format!("{}/guilds/{}/members", self.base_url, guild_id);
+
+        let query = ListQuery {
+            limit: limit.map(|l| l.clamp(1, MEMBER_LIMIT)),
+            after,
+        };
+
+        self.get_with_query(url, &query)
+    }
+}
+
+/// Autocomplete choices for the first [AUTOCOMPLETE_CHOICE_LIMIT] members, named after their
+/// [display_name](Member::display_name) with their user id as the value
+///
+/// Pass the result to `InteractionResponse::respond_with_autocomplete_choices`.
+pub fn member_choices(members: &[Member]) -> Vec<ApplicationCommandOptionChoice> {
+    members
+        .iter()
+        .take(AUTOCOMPLETE_CHOICE_LIMIT)
+        .map(|member| ApplicationCommandOptionChoice {
+            name: member.display_name().to_string(),
+            name_localizations: None,
+            value: ApplicationCommandOptionChoiceValue::String(member.user.id.to_string()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use composure_core::models::InteractionResponse;
+
+    use super::*;
+    use crate::mock::MockServer;
+
+    fn member(id: u64, username: &str, nick: Option<&str>) -> String {
+        format!(
+            r#"{{
+                "user": {{
+                    "id": "{id}",
+                    "username": "{username}",
+                    "global_name": null,
+                    "avatar": null,
+                    "discriminator": "0",
+                    "public_flags": 0
+                }},
+                "nick": {nick},
+                "roles": [],
+                "joined_at": "2023-01-01T00:00:00.000000+00:00",
+                "deaf": false,
+                "mute": false,
+                "flags": 0
+            }}"#,
+            nick = nick.map_or("null".to_string(), |n| format!("\"{n}\""))
+        )
+    }
+
+    fn guild() -> Snowflake {
+        Snowflake::from_u64(200000000000000001)
+    }
+
+    #[test]
+    pub fn search_encodes_unicode_query() {
+        let zoe = member(400000000000000001, "zoë", Some("Zoë 🌸"));
+        let server = MockServer::start(&[(200, &format!("[{zoe}]")), (200, "[]")]);
+        let client = server.client();
+
+        let members = client
+            .search_guild_members(&guild(), "Zoë 🌸", Some(10))
+            .unwrap();
+        assert_eq!(members[0].display_name(), "Zoë 🌸");
+
+        client
+            .search_guild_members(&guild(), "a&b=c", Some(5000))
+            .unwrap();
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(
+            requests[0].path,
+            "/guilds/200000000000000001/members/search?query=Zo%C3%AB+%F0%9F%8C%B8&limit=10"
+        );
+        assert_eq!(
+            requests[1].path,
+            "/guilds/200000000000000001/members/search?query=a%26b%3Dc&limit=1000"
+        );
+    }
+
+    #[test]
+    pub fn list_members() {
+        let page = format!(
+            "[{}, {}]",
+            member(400000000000000001, "one", None),
+            member(400000000000000002, "two", None)
+        );
+        let server = MockServer::start(&[(200, &page), (200, "[]")]);
+        let client = server.client();
+
+        let members = client.list_guild_members(&guild(), Some(2), None).unwrap();
+        assert_eq!(members.len(), 2);
+
+        let last = members.last().unwrap().user.id.clone();
+        let members = client
+            .list_guild_members(&guild(), Some(0), Some(last))
+            .unwrap();
+        assert!(members.is_empty());
+
+        let requests = server.requests();
+
+        assert_eq!(
+            requests[0].path,
+            "/guilds/200000000000000001/members?limit=2"
+        );
+        assert_eq!(
+            requests[1].path,
+            "/guilds/200000000000000001/members?limit=1&after=400000000000000002"
+        );
+    }
+
+    #[test]
+    pub fn choices_from_members() {
+        let members: Vec<Member> = serde_json::from_str(&format!(
+            "[{}]",
+            (0..30)
+                .map(|i| match i {
+                    0 => member(400000000000000000, "nicked", Some("Nick")),
+                    i => member(400000000000000000 + i, &format!("user{i}"), None),
+                })
+                .collect::<Vec<_>>()
+                .join(",")
+        ))
+        .unwrap();
+
+        let choices = member_choices(&members);
+
+        assert_eq!(choices.len(), AUTOCOMPLETE_CHOICE_LIMIT);
+        assert_eq!(choices[0].name, "Nick");
+        assert_eq!(choices[1].name, "user1");
+
+        let json = serde_json::to_value(InteractionResponse::respond_with_autocomplete_choices(
+            choices,
+        ))
+        .unwrap();
+
+        assert_eq!(json["type"], 8);
+        assert_eq!(json["data"]["choices"][0]["name"], "Nick");
+        assert_eq!(json["data"]["choices"][0]["value"], "400000000000000000");
+        assert_eq!(json["data"]["choices"][24]["value"], "400000000000000024");
+
+        assert!(member_choices(&[]).is_empty());
+    }
+}
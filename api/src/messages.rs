@@ -0,0 +1,447 @@
+use std::time::{Duration, SystemTime};
+
+use composure_core::{
+    models::{AllowedMentions, Message, MessageCallbackData, Snowflake},
+    util::{Reminder, ReminderSender},
+};
+use serde::Serialize;
+
+use crate::{DiscordClient, Error, Result};
+
+/// Messages older than this can't be bulk deleted
+pub const BULK_DELETE_MAX_AGE: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+/// Most messages that can be fetched or bulk deleted in one request
+pub const MESSAGE_LIMIT: usize = 100;
+
+/// Where to start fetching messages from, only one may be given per request
+#[derive(Debug, Clone)]
+pub enum MessageAnchor {
+    /// messages before this id
+    Before(Snowflake),
+
+    /// messages after this id
+    After(Snowflake),
+
+    /// messages around this id
+    Around(Snowflake),
+}
+
+#[derive(Serialize)]
+struct MessageQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<Snowflake>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<Snowflake>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    around: Option<Snowflake>,
+}
+
+#[derive(Serialize)]
+struct BulkDelete<'a> {
+    messages: &'a [Snowflake],
+}
+
+/// Outcome of [DiscordClient::bulk_delete_messages]
+#[derive(Debug, Default, PartialEq)]
+pub struct BulkDeleteSummary {
+    /// ids that were sent to Discord for deletion
+    pub deleted: Vec<Snowflake>,
+
+    /// ids skipped because they are older than [BULK_DELETE_MAX_AGE]
+    pub too_old: Vec<Snowflake>,
+}
+
+/// Splits ids into those young enough to bulk delete and those that are too old
+pub fn partition_bulk_deletable(
+    ids: &[Snowflake],
+    now: SystemTime,
+) -> (Vec<Snowflake>, Vec<Snowflake>) {
+    ids.iter().cloned().partition(|id| {
+        now.duration_since(id.created_at())
+            .map(|age| age < BULK_DELETE_MAX_AGE)
+            // created in the future according to our clock, treat as new
+            .unwrap_or(true)
+    })
+}
+
+impl DiscordClient {
+    /// [Get Channel Messages](https://discord.com/developers/docs/resources/channel#get-channel-messages)
+    ///
+    /// `limit` is clamped to 1-100, Discord defaults to 50
+    pub fn get_channel_messages(
+        &self,
+        channel_id: &Snowflake,
+        limit: Option<u8>,
+        anchor: Option<MessageAnchor>,
+    ) -> Result<Vec<Message>> {
+        let url = format!("{}/channels/{}/messages", self.base_url, channel_id);
+
+        let mut query = MessageQuery {
+            limit: limit.map(|l| l.clamp(1, MESSAGE_LIMIT as u8)),
+            before: None,
+            after: None,
+            around: None,
+        };
+
+        match anchor {
+            Some(MessageAnchor::Before(id)) => query.before = Some(id),
+            Some(MessageAnchor::After(id)) => query.after = Some(id),
+            Some(MessageAnchor::Around(id)) => query.around = Some(id),
+            None => {}
+        }
+
+        self.get_with_query(url, &query)
+    }
+
+    /// [Create Message](https://discord.com/developers/docs/resources/channel#create-message)
+    pub fn create_message(
+        &self,
+        channel_id: &Snowflake,
+        data: &MessageCallbackData,
+    ) -> Result<Message> {
+        let url = format!("{}/channels/{}/messages", self.base_url, channel_id);
+
+        self.send_json(self.client.post(url).json(data))
+    }
+
+    /// [Delete Message](https://discord.com/developers/docs/resources/channel#delete-message)
+    pub fn delete_message(
+        &self,
+        channel_id: &Snowflake,
+        message_id: &Snowflake,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let url = format!(
+            "{}/channels/{}/messages/{}",
+            self.base_url, channel_id, message_id
+        );
+
+        self.send_no_content(self.client.delete(url), reason)
+    }
+
+    /// [Bulk Delete Messages](https://discord.com/developers/docs/resources/channel#bulk-delete-messages)
+    ///
+    /// Discord rejects the whole request if any message is older than two weeks, so those are
+    /// filtered out beforehand and reported in the summary. Ids are sent in batches of 100, a
+    /// lone id is deleted on its own since bulk delete needs at least two.
+    pub fn bulk_delete_messages(
+        &self,
+        channel_id: &Snowflake,
+        message_ids: &[Snowflake],
+        reason: Option<&str>,
+    ) -> Result<BulkDeleteSummary> {
+        let (deleted, too_old) = partition_bulk_deletable(message_ids, SystemTime::now());
+
+        let url = format!(
+            "{}/channels/{}/messages/bulk-delete",
+            self.base_url, channel_id
+        );
+
+        for chunk in deleted.chunks(MESSAGE_LIMIT) {
+            match chunk {
+                [id] => self.delete_message(channel_id, id, reason)?,
+                messages => self.send_no_content(
+                    self.client.post(&url).json(&BulkDelete { messages }),
+                    reason,
+                )?,
+            }
+        }
+
+        Ok(BulkDeleteSummary { deleted, too_old })
+    }
+}
+
+/// Sends the reminder to its channel, mentioning only the user it's for
+impl ReminderSender for DiscordClient {
+    type Error = Error;
+
+    fn send_reminder(&self, reminder: &Reminder) -> Result<()> {
+        let data = MessageCallbackData::new()
+            .with_content(format!("<@{}> {}", reminder.user_id, reminder.content))
+            .with_allowed_mentions(AllowedMentions {
+                users: vec![reminder.user_id.clone()],
+                ..AllowedMentions::none()
+            });
+
+        self.create_message(&reminder.channel_id, &data)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::UNIX_EPOCH;
+
+    use super::*;
+    use crate::mock::MockServer;
+
+    const DISCORD_EPOCH: u64 = 1420070400000;
+
+    /// Snowflake created `age` before `now`
+    fn snowflake_aged(now: SystemTime, age: Duration) -> Snowflake {
+        let created = now - age;
+        let millis = created.duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        Snowflake::from_u64((millis - DISCORD_EPOCH) << 22)
+    }
+
+    const MESSAGE: &str = r#"{
+        "id": "600000000000000001",
+        "channel_id": "300000000000000001",
+        "author": {
+            "id": "400000000000000001",
+            "username": "user",
+            "avatar": null,
+            "discriminator": "0",
+            "public_flags": 0
+        },
+        "content": "hello",
+        "timestamp": "2023-01-01T00:00:00.000000+00:00",
+        "edited_timestamp": null,
+        "tts": false,
+        "mention_everyone": false,
+        "mentions": [],
+        "mention_roles": [],
+        "attachments": [],
+        "embeds": [],
+        "pinned": false,
+        "type": 0
+    }"#;
+
+    #[test]
+    pub fn bulk_delete_age_filter() {
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+
+        let cases = [
+            (Duration::from_secs(0), true),
+            (day, true),
+            (13 * day, true),
+            (BULK_DELETE_MAX_AGE - Duration::from_secs(1), true),
+            (BULK_DELETE_MAX_AGE + Duration::from_secs(1), false),
+            (15 * day, false),
+            (365 * day, false),
+        ];
+
+        for (age, eligible) in cases {
+            let id = snowflake_aged(now, age);
+            let (young, old) = partition_bulk_deletable(&[id.clone()], now);
+
+            assert_eq!(young.contains(&id), eligible, "{:?}", age);
+            assert_eq!(old.contains(&id), !eligible, "{:?}", age);
+        }
+    }
+
+    #[test]
+    pub fn bulk_delete_age_filter_future() {
+        let now = SystemTime::now();
+        let id = snowflake_aged(now + Duration::from_secs(60), Duration::from_secs(0));
+
+        let (young, old) = partition_bulk_deletable(&[id], now);
+
+        assert_eq!(young.len(), 1);
+        assert!(old.is_empty());
+    }
+
+    #[test]
+    pub fn get_channel_messages_query() {
+        let server = MockServer::start(&[(200, "[]"), (200, "[]")]);
+        let client = server.client();
+        let channel = Snowflake::from_u64(300000000000000001);
+
+        client
+            .get_channel_messages(
+                &channel,
+                Some(10),
+                Some(MessageAnchor::Before(Snowflake::from_u64(
+                    600000000000000001,
+                ))),
+            )
+            .unwrap();
+        client.get_channel_messages(&channel, None, None).unwrap();
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(
+            requests[0].path,
+            "/channels/300000000000000001/messages?limit=10&before=600000000000000001"
+        );
+        assert_eq!(requests[0].header("authorization"), Some("Bot TOKEN"));
+        assert_eq!(requests[1].path, "/channels/300000000000000001/messages");
+    }
+
+    #[test]
+    pub fn get_channel_messages_parses() {
+        let server = MockServer::start(&[(200, &format!("[{MESSAGE}]"))]);
+
+        let messages = server
+            .client()
+            .get_channel_messages(&Snowflake::from_u64(300000000000000001), Some(1), None)
+            .unwrap();
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].content, "hello");
+
+        server.requests();
+    }
+
+    #[test]
+    pub fn bulk_delete_sends_eligible() {
+        let server = MockServer::start(&[(204, "")]);
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+
+        let young = [snowflake_aged(now, day), snowflake_aged(now, 2 * day)];
+        let old = snowflake_aged(now, 20 * day);
+
+        let summary = server
+            .client()
+            .bulk_delete_messages(
+                &Snowflake::from_u64(300000000000000001),
+                &[young[0].clone(), old.clone(), young[1].clone()],
+                Some("purge: spam & ads"),
+            )
+            .unwrap();
+
+        assert_eq!(summary.deleted, young);
+        assert_eq!(summary.too_old, [old]);
+
+        let requests = server.requests();
+
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(
+            requests[0].path,
+            "/channels/300000000000000001/messages/bulk-delete"
+        );
+        assert_eq!(
+            requests[0].header("x-audit-log-reason"),
+            Some("purge%3A%20spam%20%26%20ads")
+        );
+        assert_eq!(
+            requests[0].body,
+            format!(r#"{{"messages":["{}","{}"]}}"#, young[0], young[1])
+        );
+    }
+
+    #[test]
+    pub fn bulk_delete_single_message() {
+        let server = MockServer::start(&[(204, "")]);
+        let id = snowflake_aged(SystemTime::now(), Duration::from_secs(60));
+
+        let summary = server
+            .client()
+            .bulk_delete_messages(
+                &Snowflake::from_u64(300000000000000001),
+                &[id.clone()],
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(summary.deleted, [id.clone()]);
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "DELETE");
+        assert_eq!(
+            requests[0].path,
+            format!("/channels/300000000000000001/messages/{id}")
+        );
+        assert_eq!(requests[0].header("x-audit-log-reason"), None);
+    }
+
+    #[test]
+    pub fn bulk_delete_nothing_eligible() {
+        let old = snowflake_aged(SystemTime::now(), Duration::from_secs(30 * 24 * 60 * 60));
+
+        // no server, nothing should be sent
+        let client = DiscordClient::new("TOKEN", "100000000000000001")
+            .unwrap()
+            .with_base_url("http://127.0.0.1:1");
+
+        let summary = client
+            .bulk_delete_messages(&Snowflake::from_u64(1), &[old.clone()], None)
+            .unwrap();
+
+        assert!(summary.deleted.is_empty());
+        assert_eq!(summary.too_old, [old]);
+    }
+
+    #[test]
+    pub fn bulk_delete_error_response() {
+        let server = MockServer::start(&[(400, r#"{"message": "bad request"}"#)]);
+        let now = SystemTime::now();
+        let ids = [
+            snowflake_aged(now, Duration::from_secs(1)),
+            snowflake_aged(now, Duration::from_secs(2)),
+        ];
+
+        let res = server
+            .client()
+            .bulk_delete_messages(&Snowflake::from_u64(1), &ids, None);
+
+        assert!(matches!(res, Err(crate::Error::UnknownResponse(_))));
+
+        server.requests();
+    }
+
+    #[test]
+    pub fn deliver_reminders() {
+        use composure_core::util::{deliver_due_reminders, MemoryStore};
+
+        let server = MockServer::start(&[
+            (200, MESSAGE),
+            (403, r#"{"message": "Missing Access", "code": 50001}"#),
+        ]);
+        let client = server.client();
+        let mut store = MemoryStore::new();
+        let now = SystemTime::now();
+
+        for (i, channel) in [300000000000000001, 300000000000000002].iter().enumerate() {
+            Reminder::new(
+                now,
+                Duration::from_secs(i as u64),
+                Snowflake::from_u64(*channel),
+                Snowflake::from_u64(400000000000000001),
+                "@everyone stand up",
+            )
+            .save(
+                &mut store,
+                &Snowflake::from_u64(700000000000000001 + i as u64),
+            )
+            .unwrap();
+        }
+
+        let delivery =
+            deliver_due_reminders(&mut store, &client, now + Duration::from_secs(60)).unwrap();
+
+        assert_eq!(delivery.delivered.len(), 1);
+        assert_eq!(delivery.failed.len(), 1);
+        assert!(matches!(delivery.failed[0].1, Error::UnknownResponse(_)));
+        assert_eq!(store.len(), 1);
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/channels/300000000000000001/messages");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&requests[0].body).unwrap(),
+            serde_json::json!({
+                "content": "<@400000000000000001> @everyone stand up",
+                "allowed_mentions": {
+                    "parse": [],
+                    "roles": [],
+                    "users": ["400000000000000001"],
+                    "replied_user": false
+                }
+            })
+        );
+        assert_eq!(requests[1].path, "/channels/300000000000000002/messages");
+    }
+}
@@ -0,0 +1,110 @@
+//! Minimal HTTP server for testing the client without hitting Discord
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    thread::{self, JoinHandle},
+};
+
+use crate::DiscordClient;
+
+#[derive(Debug)]
+pub struct MockRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Answers one request per response, in order, then stops
+pub struct MockServer {
+    url: String,
+    handle: JoinHandle<Vec<MockRequest>>,
+}
+
+impl MockServer {
+    pub fn start(responses: &[(u16, &str)]) -> Self {
+        let responses: Vec<(u16, String)> = responses
+            .iter()
+            .map(|(status, body)| (*status, body.to_string()))
+            .collect();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut parts = line.split_whitespace();
+                let method = parts.next().unwrap().to_string();
+                let path = parts.next().unwrap().to_string();
+
+                let mut headers = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    let (key, value) = line.split_once(':').unwrap();
+                    headers.push((key.to_string(), value.trim().to_string()));
+                }
+
+                let length = headers
+                    .iter()
+                    .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+                    .map(|(_, value)| value.parse().unwrap())
+                    .unwrap_or(0);
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+
+                requests.push(MockRequest {
+                    method,
+                    path,
+                    headers,
+                    body: String::from_utf8(request_body).unwrap(),
+                });
+
+                let response = format!(
+                    "HTTP/1.1 {status} MOCK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.into_inner().write_all(response.as_bytes()).unwrap();
+            }
+
+            requests
+        });
+
+        MockServer { url, handle }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn client(&self) -> DiscordClient {
+        DiscordClient::new("TOKEN", "100000000000000001")
+            .unwrap()
+            .with_base_url(&self.url)
+    }
+
+    /// Waits for every response to be served and returns the requests received
+    pub fn requests(self) -> Vec<MockRequest> {
+        self.handle.join().unwrap()
+    }
+}
@@ -0,0 +1,304 @@
+use composure_core::models::{CreateRole, ModifyRole, Role, RolePosition, Snowflake};
+
+use crate::{with_reason, DiscordClient, Result};
+
+impl DiscordClient {
+    /// [Get Guild Roles](https://discord.com/developers/docs/resources/guild#get-guild-roles)
+    pub fn get_guild_roles(&self, guild_id: &Snowflake) -> Result<Vec<Role>> {
+        let url = format!("{}/guilds/{}/roles", self.base_url, guild_id);
+
+        self.get(url)
+    }
+
+    /// [Create Guild Role](https://discord.com/developers/docs/resources/guild#create-guild-role),
+    /// needs the MANAGE_ROLES permission
+    pub fn create_guild_role(
+        &self,
+        guild_id: &Snowflake,
+        role: &CreateRole,
+        reason: Option<&str>,
+    ) -> Result<Role> {
+        let url = format!("{}/guilds/{}/roles", self.base_url, guild_id);
+
+        self.send_json(with_reason(self.client.post(url).json(role), reason))
+    }
+
+    /// [Modify Guild Role Positions](https://discord.com/developers/docs/resources/guild#modify-guild-role-positions),
+    /// returns every role in the guild
+    pub fn modify_guild_role_positions(
+        &self,
+        guild_id: &Snowflake,
+        positions: &[RolePosition],
+        reason: Option<&str>,
+    ) -> Result<Vec<Role>> {
+        let url = format!("{}/guilds/{}/roles", self.base_url, guild_id);
+
+        self.send_json(with_reason(self.client.patch(url).json(positions), reason))
+    }
+
+    /// [Modify Guild Role](https://discord.com/developers/docs/resources/guild#modify-guild-role)
+    pub fn modify_guild_role(
+        &self,
+        guild_id: &Snowflake,
+        role_id: &Snowflake,
+        role: &ModifyRole,
+        reason: Option<&str>,
+    ) -> Result<Role> {
+        let url = format!("{}/guilds/{}/roles/{}", self.base_url, guild_id, role_id);
+
+        self.send_json(with_reason(self.client.patch(url).json(role), reason))
+    }
+
+    /// [Delete Guild Role](https://discord.com/developers/docs/resources/guild#delete-guild-role)
+    pub fn delete_guild_role(
+        &self,
+        guild_id: &Snowflake,
+        role_id: &Snowflake,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let url = format!("{}/guilds/{}/roles/{}", self.base_url, guild_id, role_id);
+
+        self.send_no_content(self.client.delete(url), reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use composure_core::{
+        models::Permissions,
+        util::{ImageData, ROLE_ICON_SIZE_LIMIT},
+    };
+    use serde_json::json;
+
+    use super::*;
+    use crate::{mock::MockServer, Error};
+
+    const ROLE: &str = r#"{
+        "id": "500000000000000002",
+        "name": "mods",
+        "color": 3447003,
+        "hoist": true,
+        "icon": "0123456789abcdef0123456789abcdef",
+        "unicode_emoji": null,
+        "position": 2,
+        "permissions": "6",
+        "managed": false,
+        "mentionable": true,
+        "flags": 0
+    }"#;
+
+    const EVERYONE: &str = r#"{
+        "id": "200000000000000001",
+        "name": "@everyone",
+        "color": 0,
+        "hoist": false,
+        "icon": null,
+        "unicode_emoji": null,
+        "position": 0,
+        "permissions": "1024",
+        "managed": false,
+        "mentionable": false,
+        "flags": 0
+    }"#;
+
+    fn guild() -> Snowflake {
+        Snowflake::from_u64(200000000000000001)
+    }
+
+    fn body(request: &crate::mock::MockRequest) -> serde_json::Value {
+        serde_json::from_str(&request.body).unwrap()
+    }
+
+    #[test]
+    pub fn get_roles() {
+        let server = MockServer::start(&[(200, &format!("[{EVERYONE}, {ROLE}]"))]);
+
+        let roles = server.client().get_guild_roles(&guild()).unwrap();
+
+        assert_eq!(roles.len(), 2);
+        assert_eq!(roles[0].name, "@everyone");
+        assert_eq!(
+            roles[1].permissions,
+            Permissions::KickMembers | Permissions::BanMembers
+        );
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/guilds/200000000000000001/roles");
+    }
+
+    #[test]
+    pub fn create_role_with_icon() {
+        let server = MockServer::start(&[(200, ROLE)]);
+
+        let role = CreateRole::new("mods")
+            .with_permissions(Permissions::KickMembers | Permissions::BanMembers)
+            .with_hoist(true)
+            .with_mentionable(true)
+            .with_icon(ImageData::from_png_bytes(b"\x89PNG\r\n\x1a\n").unwrap());
+
+        let created = server
+            .client()
+            .create_guild_role(&guild(), &role, Some("new moderators"))
+            .unwrap();
+
+        assert_eq!(created.id, Snowflake::from_u64(500000000000000002));
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/guilds/200000000000000001/roles");
+        assert_eq!(
+            requests[0].header("x-audit-log-reason"),
+            Some("new%20moderators")
+        );
+        assert_eq!(
+            body(&requests[0]),
+            json!({
+                "name": "mods",
+                "permissions": "6",
+                "hoist": true,
+                "icon": "data:image/png;base64,iVBORw0KGgo=",
+                "mentionable": true
+            })
+        );
+    }
+
+    #[test]
+    pub fn create_role_with_unicode_emoji() {
+        let server = MockServer::start(&[(200, ROLE)]);
+
+        let role = CreateRole::new("mods").with_unicode_emoji("🛡️");
+
+        server
+            .client()
+            .create_guild_role(&guild(), &role, None)
+            .unwrap();
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].header("x-audit-log-reason"), None);
+        assert_eq!(
+            body(&requests[0]),
+            json!({ "name": "mods", "unicode_emoji": "🛡️" })
+        );
+    }
+
+    #[test]
+    pub fn modify_role() {
+        let server = MockServer::start(&[(200, ROLE)]);
+
+        let role = ModifyRole::new().with_color(3447003).clear_icon();
+
+        server
+            .client()
+            .modify_guild_role(
+                &guild(),
+                &Snowflake::from_u64(500000000000000002),
+                &role,
+                None,
+            )
+            .unwrap();
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "PATCH");
+        assert_eq!(
+            requests[0].path,
+            "/guilds/200000000000000001/roles/500000000000000002"
+        );
+        assert_eq!(
+            body(&requests[0]),
+            json!({ "color": 3447003, "icon": null })
+        );
+    }
+
+    #[test]
+    pub fn modify_role_icon() {
+        let server = MockServer::start(&[(200, ROLE)]);
+
+        let icon = ImageData::from_gif_bytes(b"GIF89a")
+            .and_then(|icon| icon.check_size(ROLE_ICON_SIZE_LIMIT))
+            .unwrap();
+        let role = ModifyRole::new().with_icon(icon);
+
+        server
+            .client()
+            .modify_guild_role(
+                &guild(),
+                &Snowflake::from_u64(500000000000000002),
+                &role,
+                None,
+            )
+            .unwrap();
+
+        let requests = server.requests();
+
+        assert_eq!(
+            body(&requests[0]),
+            json!({ "icon": "data:image/gif;base64,R0lGODlh" })
+        );
+    }
+
+    #[test]
+    pub fn modify_role_positions() {
+        let server = MockServer::start(&[(200, &format!("[{EVERYONE}, {ROLE}]"))]);
+
+        let positions = [
+            RolePosition {
+                id: Snowflake::from_u64(500000000000000002),
+                position: 2,
+            },
+            RolePosition {
+                id: Snowflake::from_u64(500000000000000003),
+                position: 1,
+            },
+        ];
+
+        let roles = server
+            .client()
+            .modify_guild_role_positions(&guild(), &positions, None)
+            .unwrap();
+
+        assert_eq!(roles.len(), 2);
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "PATCH");
+        assert_eq!(requests[0].path, "/guilds/200000000000000001/roles");
+        assert_eq!(
+            body(&requests[0]),
+            json!([
+                { "id": "500000000000000002", "position": 2 },
+                { "id": "500000000000000003", "position": 1 }
+            ])
+        );
+    }
+
+    #[test]
+    pub fn delete_role() {
+        let server = MockServer::start(&[
+            (204, ""),
+            (403, r#"{"message": "Missing Permissions", "code": 50013}"#),
+        ]);
+        let client = server.client();
+        let role = Snowflake::from_u64(500000000000000002);
+
+        client
+            .delete_guild_role(&guild(), &role, Some("cleanup"))
+            .unwrap();
+
+        let res = client.delete_guild_role(&guild(), &role, None);
+        assert!(matches!(res, Err(Error::UnknownResponse(_))));
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "DELETE");
+        assert_eq!(
+            requests[0].path,
+            "/guilds/200000000000000001/roles/500000000000000002"
+        );
+        assert_eq!(requests[0].header("x-audit-log-reason"), Some("cleanup"));
+    }
+}
@@ -0,0 +1,194 @@
+use composure_core::models::{Sku, Snowflake, Subscription};
+use serde::Serialize;
+
+use crate::{DiscordClient, Result};
+
+/// Most subscriptions returned by one list request
+pub const SUBSCRIPTION_LIMIT: u8 = 100;
+
+#[derive(Serialize)]
+struct SubscriptionQuery<'a> {
+    user_id: &'a Snowflake,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<Snowflake>,
+}
+
+impl DiscordClient {
+    /// [List SKUs](https://discord.com/developers/docs/monetization/skus#list-skus) of the
+    /// application, a subscription SKU comes with a system-generated subscription group SKU
+    pub fn list_skus(&self) -> Result<Vec<Sku>> {
+        let url = format!(
+            "{}/applications/{}/skus",
+            self.base_url, self.application_id
+        );
+
+        self.get(url)
+    }
+
+    /// [List SKU Subscriptions](https://discord.com/developers/docs/resources/subscription#list-sku-subscriptions)
+    /// of `user_id`
+    ///
+    /// `limit` is clamped to 1-100, Discord defaults to 50. Pass the last id of a page as `after`
+    /// for the next one.
+    pub fn list_subscriptions(
+        &self,
+        sku_id: &Snowflake,
+        user_id: &Snowflake,
+        limit: Option<u8>,
+        after: Option<Snowflake>,
+    ) -> Result<Vec<Subscription>> {
+        let url = format!("{}/skus/{}/subscriptions", self.base_url, sku_id);
+
+        let query = SubscriptionQuery {
+            user_id,
+            limit: limit.map(|l| l.clamp(1, SUBSCRIPTION_LIMIT)),
+            after,
+        };
+
+        self.get_with_query(url, &query)
+    }
+
+    /// [Get SKU Subscription](https://discord.com/developers/docs/resources/subscription#get-sku-subscription)
+    pub fn get_subscription(
+        &self,
+        sku_id: &Snowflake,
+        subscription_id: &Snowflake,
+    ) -> Result<Subscription> {
+        let url = format!(
+            "{}/skus/{}/subscriptions/{}",
+            self.base_url, sku_id, subscription_id
+        );
+
+        self.get(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use composure_core::models::{SkuFlags, SkuType, SubscriptionStatus};
+
+    use super::*;
+    use crate::{mock::MockServer, Error};
+
+    const SKU: &str = r#"{
+        "id": "1158857122189168803",
+        "type": 5,
+        "dependent_sku_id": null,
+        "application_id": "100000000000000001",
+        "manifest_labels": null,
+        "access_type": 1,
+        "name": "Premium",
+        "features": [],
+        "release_date": null,
+        "premium": false,
+        "slug": "premium",
+        "flags": 260,
+        "show_age_gate": false
+    }"#;
+
+    const SUBSCRIPTION: &str = r#"{
+        "id": "1278078770116427839",
+        "user_id": "400000000000000001",
+        "sku_ids": ["1158857122189168803"],
+        "entitlement_ids": ["1278078770116427900"],
+        "renewal_sku_ids": ["1158857122189168803"],
+        "current_period_start": "2024-08-27T19:48:44.406602+00:00",
+        "current_period_end": "2024-09-27T19:48:44.406602+00:00",
+        "status": 0,
+        "canceled_at": null
+    }"#;
+
+    fn sku() -> Snowflake {
+        Snowflake::from_u64(1158857122189168803)
+    }
+
+    fn user() -> Snowflake {
+        Snowflake::from_u64(400000000000000001)
+    }
+
+    #[test]
+    pub fn list_skus() {
+        let server = MockServer::start(&[(200, &format!("[{SKU}]"))]);
+
+        let skus = server.client().list_skus().unwrap();
+
+        assert_eq!(skus.len(), 1);
+        assert_eq!(skus[0].t, SkuType::Subscription);
+        assert_eq!(
+            skus[0].flags,
+            SkuFlags::Available | SkuFlags::UserSubscription
+        );
+        assert!(skus[0].is_available());
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/applications/100000000000000001/skus");
+    }
+
+    #[test]
+    pub fn list_subscriptions() {
+        let server = MockServer::start(&[(200, &format!("[{SUBSCRIPTION}]")), (200, "[]")]);
+        let client = server.client();
+
+        let subscriptions = client
+            .list_subscriptions(&sku(), &user(), Some(1), None)
+            .unwrap();
+
+        assert_eq!(subscriptions.len(), 1);
+        assert!(subscriptions[0].is_active());
+        assert_eq!(
+            subscriptions[0].entitlement_ids,
+            [Snowflake::from_u64(1278078770116427900)]
+        );
+
+        let last = subscriptions[0].id.clone();
+        let subscriptions = client
+            .list_subscriptions(&sku(), &user(), Some(200), Some(last))
+            .unwrap();
+
+        // no subscription, time for the upsell
+        assert!(subscriptions.is_empty());
+
+        let requests = server.requests();
+
+        assert_eq!(
+            requests[0].path,
+            "/skus/1158857122189168803/subscriptions?user_id=400000000000000001&limit=1"
+        );
+        assert_eq!(
+            requests[1].path,
+            "/skus/1158857122189168803/subscriptions?user_id=400000000000000001&limit=100&after=1278078770116427839"
+        );
+    }
+
+    #[test]
+    pub fn get_subscription() {
+        let server = MockServer::start(&[
+            (200, SUBSCRIPTION),
+            (404, r#"{"message": "Unknown Subscription", "code": 10029}"#),
+        ]);
+        let client = server.client();
+        let id = Snowflake::from_u64(1278078770116427839);
+
+        let subscription = client.get_subscription(&sku(), &id).unwrap();
+
+        assert_eq!(subscription.status, SubscriptionStatus::Active);
+        assert_eq!(subscription.user_id, user());
+
+        let res = client.get_subscription(&sku(), &id);
+        assert!(matches!(res, Err(Error::UnknownResponse(_))));
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(
+            requests[0].path,
+            "/skus/1158857122189168803/subscriptions/1278078770116427839"
+        );
+    }
+}
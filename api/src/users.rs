@@ -0,0 +1,214 @@
+use composure_core::models::{Connection, PartialGuild, Snowflake, User};
+use serde::Serialize;
+
+use crate::{DiscordClient, Result, WithBearer};
+
+/// Most guilds returned by one [DiscordClient::get_current_user_guilds] request
+pub const GUILD_LIMIT: u8 = 200;
+
+/// Where to start listing the current user's guilds from
+#[derive(Debug, Clone)]
+pub enum GuildAnchor {
+    /// guilds before this id
+    Before(Snowflake),
+
+    /// guilds after this id
+    After(Snowflake),
+}
+
+#[derive(Serialize)]
+struct GuildQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<Snowflake>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<Snowflake>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u8>,
+
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    with_counts: bool,
+}
+
+impl DiscordClient {
+    /// [Get Current User](https://discord.com/developers/docs/resources/user#get-current-user),
+    /// the bot's own user
+    pub fn get_current_user(&self) -> Result<User> {
+        let url = format!("{}/users/@me", self.base_url);
+
+        self.get(url)
+    }
+
+    /// [Get Current User Guilds](https://discord.com/developers/docs/resources/user#get-current-user-guilds)
+    /// for the user who authorized `bearer_token` with the `guilds` scope
+    ///
+    /// `limit` is clamped to 1-200, Discord defaults to 200
+    pub fn get_current_user_guilds(
+        &self,
+        bearer_token: &str,
+        anchor: Option<GuildAnchor>,
+        limit: Option<u8>,
+        with_counts: bool,
+    ) -> Result<Vec<PartialGuild>> {
+        let url = format!("{}/users/@me/guilds", self.base_url);
+
+        let mut query = GuildQuery {
+            before: None,
+            after: None,
+            limit: limit.map(|l| l.clamp(1, GUILD_LIMIT)),
+            with_counts,
+        };
+
+        match anchor {
+            Some(GuildAnchor::Before(id)) => query.before = Some(id),
+            Some(GuildAnchor::After(id)) => query.after = Some(id),
+            None => {}
+        }
+
+        self.send_json(self.client.get(url).query(&query).with_bearer(bearer_token))
+    }
+
+    /// [Get Current User Connections](https://discord.com/developers/docs/resources/user#get-current-user-connections)
+    /// for the user who authorized `bearer_token` with the `connections` scope
+    pub fn get_user_connections(&self, bearer_token: &str) -> Result<Vec<Connection>> {
+        let url = format!("{}/users/@me/connections", self.base_url);
+
+        self.send_json(self.client.get(url).with_bearer(bearer_token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use composure_core::models::{ConnectionVisibility, Permissions};
+
+    use super::*;
+    use crate::mock::MockServer;
+
+    const GUILD: &str = r#"{
+        "id": "200000000000000001",
+        "name": "1337 Krew",
+        "icon": "8342729096ea3675442027381ff50dfe",
+        "owner": true,
+        "permissions": "36953089",
+        "features": ["COMMUNITY", "NEWS"],
+        "approximate_member_count": 3268,
+        "approximate_presence_count": 784
+    }"#;
+
+    #[test]
+    pub fn current_user() {
+        let server = MockServer::start(&[(
+            200,
+            r#"{
+                "id": "100000000000000001",
+                "username": "composure",
+                "avatar": null,
+                "discriminator": "0",
+                "public_flags": 0,
+                "flags": 0,
+                "bot": true,
+                "banner": null,
+                "accent_color": null,
+                "global_name": null,
+                "avatar_decoration_data": null,
+                "mfa_enabled": true,
+                "locale": "en-US"
+            }"#,
+        )]);
+
+        let user = server.client().get_current_user().unwrap();
+
+        assert_eq!(user.id, Snowflake::from_u64(100000000000000001));
+        assert_eq!(user.username, "composure");
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/users/@me");
+        assert_eq!(requests[0].header("authorization"), Some("Bot TOKEN"));
+    }
+
+    #[test]
+    pub fn current_user_guilds() {
+        let server = MockServer::start(&[(200, &format!("[{GUILD}]")), (200, "[]")]);
+        let client = server.client();
+
+        let guilds = client
+            .get_current_user_guilds(
+                "USER_TOKEN",
+                Some(GuildAnchor::After(Snowflake::from_u64(100000000000000001))),
+                Some(250),
+                true,
+            )
+            .unwrap();
+
+        assert_eq!(guilds.len(), 1);
+        assert!(guilds[0].owner);
+        assert!(guilds[0]
+            .permissions
+            .contains(Permissions::ViewChannel | Permissions::SendMessages));
+        assert_eq!(guilds[0].approximate_member_count, Some(3268));
+
+        client
+            .get_current_user_guilds("USER_TOKEN", None, None, false)
+            .unwrap();
+
+        let requests = server.requests();
+
+        assert_eq!(
+            requests[0].path,
+            "/users/@me/guilds?after=100000000000000001&limit=200&with_counts=true"
+        );
+        assert_eq!(requests[1].path, "/users/@me/guilds");
+
+        for request in requests {
+            assert_eq!(request.header("authorization"), Some("Bearer USER_TOKEN"));
+        }
+    }
+
+    #[test]
+    pub fn user_connections() {
+        let server = MockServer::start(&[(
+            200,
+            r#"[{
+                "id": "12345",
+                "name": "user",
+                "type": "github",
+                "verified": true,
+                "friend_sync": false,
+                "show_activity": true,
+                "two_way_link": false,
+                "visibility": 1
+            }]"#,
+        )]);
+
+        let connections = server.client().get_user_connections("USER_TOKEN").unwrap();
+
+        assert_eq!(connections[0].t, "github");
+        assert!(connections[0].verified);
+        assert_eq!(connections[0].visibility, ConnectionVisibility::Everyone);
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].path, "/users/@me/connections");
+        assert_eq!(
+            requests[0].header("authorization"),
+            Some("Bearer USER_TOKEN")
+        );
+    }
+
+    #[test]
+    pub fn bot_requests_keep_bot_token() {
+        let server = MockServer::start(&[(200, r#"{"code": null, "uses": 0}"#)]);
+
+        server
+            .client()
+            .get_guild_vanity_url(&Snowflake::from_u64(200000000000000001))
+            .unwrap();
+
+        let requests = server.requests();
+
+        assert_eq!(requests[0].header("authorization"), Some("Bot TOKEN"));
+    }
+}
@@ -0,0 +1,162 @@
+use composure::{
+    auth::validate_request,
+    models::{
+        ActionRow, ApplicationCommandInteractionDataOption, ButtonStyle, Component, Embed,
+        EmbedField, Interaction, InteractionResponse, MessageCallbackData, OptionList,
+    },
+};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+const PING: &str = include_str!("../tests/fixtures/ping.json");
+const REAL_COMMAND: &str = include_str!("../tests/fixtures/real_command.json");
+const LARGE_COMMAND: &str = include_str!("../tests/fixtures/large_command.json");
+const COMPONENT: &str = include_str!("../tests/fixtures/component_string_select.json");
+const MODAL: &str = include_str!("../tests/fixtures/modal_submit.json");
+const MAX_OPTIONS_COMMAND: &str = include_str!("../tests/fixtures/max_options_command.json");
+
+/// Key, signature and timestamp that `real_command.json` was signed with
+const PUBLIC_KEY: &str = "852aec10972ef6dd0431747902c779342cc411ad6d42c2de16ef4c87895c61ad";
+const SIGNATURE: &str = "c91641b5c3d12f9c819d9b5c568ef7d660e7f9abc2c312f296c562f6d7b028dac80c6c8e5c8a11f7a21ee28dbb8c6cf2762118bee45c00b2df78065b3b59f20c";
+const TIMESTAMP: &str = "1682372142";
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+
+    for (name, json) in [
+        ("ping", PING),
+        ("real_command", REAL_COMMAND),
+        ("large_command", LARGE_COMMAND),
+        ("component", COMPONENT),
+        ("modal", MODAL),
+    ] {
+        group.bench_function(name, |b| {
+            b.iter(|| serde_json::from_str::<Interaction>(black_box(json)).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+/// Option names and the raw options of `max_options_command.json`
+fn max_options() -> (Vec<String>, serde_json::Value) {
+    let interaction: serde_json::Value = serde_json::from_str(MAX_OPTIONS_COMMAND).unwrap();
+    let options = interaction["data"]["options"].clone();
+    let names = options
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|o| o["name"].as_str().unwrap().to_string())
+        .collect();
+
+    (names, options)
+}
+
+/// Looks up every option of a 25 option command, scanning the list against the indexed lookups
+fn lookup(c: &mut Criterion) {
+    let (names, options) = max_options();
+    let mut group = c.benchmark_group("lookup");
+
+    let list: Vec<ApplicationCommandInteractionDataOption> =
+        serde_json::from_value(options.clone()).unwrap();
+    group.bench_function("linear_scan", |b| {
+        b.iter(|| {
+            for name in &names {
+                black_box(list.iter().find(|o| o.name() == Some(name)));
+            }
+        })
+    });
+
+    // includes building the index on the first lookup
+    group.bench_function("indexed_cold", |b| {
+        b.iter_batched_ref(
+            || serde_json::from_value::<OptionList>(options.clone()).unwrap(),
+            |list| {
+                for name in &names {
+                    black_box(list.get_option(name));
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    let list: OptionList = serde_json::from_value(options).unwrap();
+    group.bench_function("indexed_warm", |b| {
+        b.iter(|| {
+            for name in &names {
+                black_box(list.get_option(name));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn large_response() -> InteractionResponse {
+    let embeds = (0..10)
+        .map(|i| {
+            Embed::new()
+                .with_title(&format!("Embed {i}"))
+                .with_description("description")
+                .with_color(0xf04747)
+                .with_field(EmbedField::new(
+                    String::from("field"),
+                    String::from("value"),
+                    Some(true),
+                ))
+        })
+        .collect();
+
+    let components = (0..5)
+        .map(|row| {
+            ActionRow::new(
+                (0..5)
+                    .map(|i| {
+                        Component::new_button(
+                            ButtonStyle::Primary,
+                            Some(format!("Button {row}-{i}")),
+                            None,
+                            Some(format!("button_{row}_{i}")),
+                            None,
+                            None,
+                        )
+                    })
+                    .collect(),
+            )
+        })
+        .collect();
+
+    InteractionResponse::ChannelMessageWithSource(MessageCallbackData {
+        tts: None,
+        content: Some(String::from("content")),
+        embeds: Some(embeds),
+        allowed_mentions: None,
+        flags: None,
+        components: Some(components),
+        attachments: None,
+    })
+}
+
+fn serialize(c: &mut Criterion) {
+    let response = large_response();
+
+    c.bench_function("serialize/message_response", |b| {
+        b.iter(|| serde_json::to_vec(black_box(&response)).unwrap())
+    });
+}
+
+fn validate(c: &mut Criterion) {
+    c.bench_function("validate_request", |b| {
+        b.iter(|| {
+            assert!(validate_request(
+                black_box(PUBLIC_KEY),
+                black_box(SIGNATURE),
+                black_box(TIMESTAMP),
+                black_box(REAL_COMMAND.as_bytes()),
+            )
+            .is_ok())
+        })
+    });
+}
+
+criterion_group!(benches, parse, lookup, serialize, validate);
+criterion_main!(benches);
@@ -1,14 +1,18 @@
 mod builder;
+mod hash;
 mod implementation;
+mod manifest;
 mod model;
+mod normalize;
 
 pub use builder::*;
 pub use implementation::*;
+pub use manifest::*;
 pub use model::*;
 
 #[cfg(test)]
 mod tests {
-    use composure::models::TypeField;
+    use composure_core::models::{InteractionContextType, TypeField};
 
     use super::*;
 
@@ -41,6 +45,149 @@ mod tests {
         println!("{}", serde_json::to_string_pretty(&command).unwrap());
     }
 
+    /// Captured from the API after registering each command type
+    const USER_COMMAND_RESPONSE: &str = r#"{
+        "id": "1104000000000000001",
+        "application_id": "1052322265397739523",
+        "version": "1104000000000000002",
+        "default_member_permissions": null,
+        "type": 2,
+        "name": "Report",
+        "name_localizations": null,
+        "description": "",
+        "description_localizations": null,
+        "dm_permission": true,
+        "contexts": null,
+        "integration_types": [0],
+        "nsfw": false
+    }"#;
+
+    const MESSAGE_COMMAND_RESPONSE: &str = r#"{
+        "id": "1104000000000000003",
+        "application_id": "1052322265397739523",
+        "version": "1104000000000000004",
+        "default_member_permissions": "8",
+        "type": 3,
+        "name": "Bookmark",
+        "description": "",
+        "guild_id": "1052322265397739000",
+        "nsfw": false
+    }"#;
+
+    #[test]
+    pub fn context_menu_commands_round_trip() {
+        let cases = [
+            (USER_COMMAND_RESPONSE, 2, "Report"),
+            (MESSAGE_COMMAND_RESPONSE, 3, "Bookmark"),
+        ];
+
+        for (response, t, name) in cases {
+            let command = serde_json::from_str::<ApplicationCommand>(response).unwrap();
+
+            let details = match (&command, t) {
+                (ApplicationCommand::UserCommand(details), 2) => details.name.as_str(),
+                (ApplicationCommand::MessageCommand(details), 3) => details.name.as_str(),
+                (other, _) => panic!("expected type {} but got {:#?}", t, other),
+            };
+            assert_eq!(details, name);
+
+            let json = serde_json::to_value(&command).unwrap();
+            assert_eq!(json["type"], t);
+            assert_eq!(json["name"], name);
+            assert_eq!(json["description"], "");
+            assert!(json.get("options").is_none());
+            assert!(json.get("id").is_none());
+            assert!(json.get("version").is_none());
+
+            let again = serde_json::from_value::<ApplicationCommand>(json).unwrap();
+            assert_eq!(command, again);
+        }
+    }
+
+    #[test]
+    pub fn modernize_fetched_command() {
+        let command = serde_json::from_str::<ApplicationCommand>(USER_COMMAND_RESPONSE).unwrap();
+        let mut details = command.as_user_command().unwrap().clone();
+
+        assert_eq!(details.dm_permission, Some(true));
+        assert!(!details.has_conflicting_dm_permission());
+
+        details.modernize_contexts();
+
+        assert_eq!(details.dm_permission, None);
+        assert_eq!(details.contexts, None);
+        assert_eq!(ApplicationCommand::UserCommand(details), command);
+
+        let mut details = command.as_user_command().unwrap().clone();
+        details.contexts = Some(vec![InteractionContextType::Guild]);
+        assert!(details.has_conflicting_dm_permission());
+        assert_eq!(details.effective_contexts(), details.contexts);
+    }
+
+    #[test]
+    pub fn entry_point_command() {
+        let response = r#"{
+            "id": "1104000000000000005",
+            "application_id": "1052322265397739523",
+            "version": "1104000000000000006",
+            "default_member_permissions": null,
+            "type": 4,
+            "name": "launch",
+            "description": "Launch the activity",
+            "description_localizations": null,
+            "integration_types": [0, 1],
+            "contexts": [0, 1, 2],
+            "nsfw": false,
+            "handler": 2
+        }"#;
+
+        let command = serde_json::from_str::<ApplicationCommand>(response).unwrap();
+
+        let entry_point = command.as_entry_point_command().unwrap();
+        assert_eq!(command.name(), "launch");
+        assert_eq!(entry_point.description, "Launch the activity");
+        assert_eq!(
+            entry_point.handler,
+            Some(EntryPointCommandHandlerType::DiscordLaunchActivity)
+        );
+
+        let json = serde_json::to_value(&command).unwrap();
+        assert_eq!(json["type"], 4);
+        assert_eq!(json["handler"], 2);
+        assert!(json.get("id").is_none());
+
+        let again = serde_json::from_value::<ApplicationCommand>(json).unwrap();
+        assert_eq!(command, again);
+
+        assert_eq!(
+            ApplicationCommand::new_entry_point_command(
+                String::from("launch"),
+                String::from("Launch the activity"),
+                None,
+            ),
+            ApplicationCommand::new_entry_point_command(
+                String::from("launch"),
+                String::from("Launch the activity"),
+                Some(EntryPointCommandHandlerType::AppHandler),
+            )
+        );
+    }
+
+    #[test]
+    pub fn context_menu_commands_serialize_empty_description() {
+        let commands = [
+            ApplicationCommand::new_user_command(String::from("name"), None, None, None),
+            ApplicationCommand::new_message_command(String::from("name"), None, None, None),
+        ];
+
+        for command in commands {
+            let json = serde_json::to_value(&command).unwrap();
+
+            assert_eq!(json["description"], "");
+            assert!(json.get("options").is_none());
+        }
+    }
+
     #[test]
     pub fn serialize_command_with_options() {
         let command = ApplicationCommand::ChatInputCommand(ChatInputCommand {
@@ -54,6 +201,8 @@ mod tests {
                 default_member_permissions: None,
                 dm_permission: None,
                 nsfw: None,
+                integration_types: None,
+                contexts: None,
                 version: None,
             },
             description: String::from("description"),
@@ -68,6 +217,104 @@ mod tests {
         println!("{}", serde_json::to_string_pretty(&command).unwrap());
     }
 
+    #[test]
+    pub fn option_summary() {
+        let command = ApplicationCommand::new_chat_input_command(
+            String::from("name"),
+            String::from("descr"),
+            None,
+            None,
+            None,
+            Some(vec![
+                ApplicationCommandOption::new_string_option(
+                    String::from("text"),
+                    String::from("desc"),
+                    Some(true),
+                    None,
+                    None,
+                    None,
+                    None,
+                ),
+                ApplicationCommandOption::new_user_option(
+                    String::from("user"),
+                    String::from("desc"),
+                    None,
+                ),
+                ApplicationCommandOption::new_subcommand_option(
+                    String::from("sub"),
+                    String::from("desc"),
+                    Some(vec![SubcommandCommandOption::new_boolean_option(
+                        String::from("flag"),
+                        String::from("desc"),
+                        Some(true),
+                    )]),
+                ),
+            ]),
+        );
+
+        let command = command.as_chat_input_command().unwrap();
+
+        assert_eq!(
+            command.option_summary(),
+            vec![
+                (String::from("text"), "STRING", true),
+                (String::from("user"), "USER", false),
+                (String::from("sub"), "SUB_COMMAND", false),
+            ]
+        );
+
+        assert_eq!(
+            command.option_summary_recursive(),
+            vec![
+                (String::from("text"), "STRING", true),
+                (String::from("user"), "USER", false),
+                (String::from("sub"), "SUB_COMMAND", false),
+                (String::from("sub flag"), "BOOLEAN", true),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn commands_by_name() {
+        let commands = vec![
+            ApplicationCommand::new_chat_input_command(
+                String::from("ping"),
+                String::from("descr"),
+                None,
+                None,
+                None,
+                None,
+            ),
+            serde_json::from_str(USER_COMMAND_RESPONSE).unwrap(),
+            serde_json::from_str(MESSAGE_COMMAND_RESPONSE).unwrap(),
+        ];
+
+        let commands = super::commands_by_name(commands);
+
+        assert_eq!(commands.len(), 3);
+        assert!(commands["ping"].as_chat_input_command().is_some());
+        assert!(commands["Report"].as_user_command().is_some());
+        assert!(commands["Bookmark"].as_message_command().is_some());
+        assert!(!commands.contains_key("report"));
+
+        // names only need to be unique per type, the later command wins
+        let commands = super::commands_by_name(vec![
+            ApplicationCommand::new_chat_input_command(
+                String::from("Report"),
+                String::from("descr"),
+                None,
+                None,
+                None,
+                None,
+            ),
+            serde_json::from_str(USER_COMMAND_RESPONSE).unwrap(),
+        ]);
+
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands["Report"].name(), "Report");
+        assert!(commands["Report"].as_user_command().is_some());
+    }
+
     #[test]
     pub fn deserialize_command() {
         let json = r#"{
@@ -1,4 +1,10 @@
-use composure::models::{Permissions, Snowflake, TypeField};
+use composure_core::{
+    models::{
+        ApplicationIntegrationType, ChannelType, InteractionContextType, Permissions, Snowflake,
+        TypeField,
+    },
+    util::BotConfig,
+};
 
 use crate::command::*;
 
@@ -17,6 +23,11 @@ impl CommandsBuilder {
         }
     }
 
+    /// Builder registering commands for the application and guild in `config`
+    pub fn from_config(config: &BotConfig) -> Self {
+        Self::new(config.application_id.clone(), config.guild_id.clone())
+    }
+
     pub fn add_command<F>(mut self, command_builder: F) -> Self
     where
         F: FnOnce(CommandBuilder) -> CommandBuilder,
@@ -26,16 +37,77 @@ impl CommandsBuilder {
         self
     }
 
+    /// Names of the commands whose `dm_permission` disagrees with their `contexts`
+    ///
+    /// Such commands still build, but Discord ignores their `dm_permission`, so warn about these
+    /// before registering.
+    pub fn conflicting_dm_permission(&self) -> Vec<&str> {
+        self.commands
+            .iter()
+            .filter(|command| command.has_conflicting_dm_permission())
+            .map(|command| command.name())
+            .collect()
+    }
+
+    /// Summary of the commands for generating documentation
+    pub fn manifest(&self) -> CommandManifest {
+        CommandManifest::new(&self.commands)
+    }
+
     pub fn build(self) -> Vec<ApplicationCommand> {
         self.commands
     }
 }
 
+/// Why [CommandBuilder::try_build_chat_command] rejected a command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandBuilderError {
+    /// Discord doesn't allow subcommands or subcommand groups next to regular options
+    MixedSubcommandsAndOptions {
+        command: String,
+        subcommand: String,
+        option: String,
+    },
+
+    /// [CommandBuilder::with_default_member_permissions] was given an empty set, which Discord
+    /// reads as "administrators only"
+    EmptyPermissions { command: String },
+}
+
+impl std::fmt::Display for CommandBuilderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandBuilderError::MixedSubcommandsAndOptions {
+                command,
+                subcommand,
+                option,
+            } => write!(
+                f,
+                "command `{}` mixes subcommand `{}` with option `{}`, move the option into the subcommands",
+                command, subcommand, option
+            ),
+            CommandBuilderError::EmptyPermissions { command } => write!(
+                f,
+                "command `{}` was given empty default_member_permissions which hides it from everyone but administrators, use admins_only() or clear_permissions()",
+                command
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CommandBuilderError {}
+
 pub struct CommandBuilder {
     name: String,
     description: String,
     default_member_permissions: Option<Permissions>,
+
+    /// `default_member_permissions` came from an empty [with_default_member_permissions](Self::with_default_member_permissions)
+    empty_permissions: bool,
     dm_permission: Option<bool>,
+    integration_types: Option<Vec<ApplicationIntegrationType>>,
+    contexts: Option<Vec<InteractionContextType>>,
+    modernize_contexts: bool,
     options: Option<Vec<ApplicationCommandOption>>,
 }
 
@@ -46,7 +118,11 @@ impl CommandBuilder {
             description: String::new(),
             options: None,
             default_member_permissions: None,
+            empty_permissions: false,
             dm_permission: None,
+            integration_types: None,
+            contexts: None,
+            modernize_contexts: false,
         }
     }
 
@@ -86,25 +162,171 @@ impl CommandBuilder {
         self.add_option(option.build())
     }
 
+    pub fn add_string_option<F>(self, option_builder: F) -> Self
+    where
+        F: FnOnce(StringOptionBuilder) -> StringOptionBuilder,
+    {
+        let option = option_builder(StringOptionBuilder::new()).build();
+        self.add_option(ApplicationCommandOption::String(option))
+    }
+
+    pub fn add_integer_option<F>(self, option_builder: F) -> Self
+    where
+        F: FnOnce(IntegerOptionBuilder) -> IntegerOptionBuilder,
+    {
+        let option = option_builder(IntegerOptionBuilder::new()).build();
+        self.add_option(ApplicationCommandOption::Integer(option))
+    }
+
+    pub fn add_number_option<F>(self, option_builder: F) -> Self
+    where
+        F: FnOnce(NumberOptionBuilder) -> NumberOptionBuilder,
+    {
+        let option = option_builder(NumberOptionBuilder::new()).build();
+        self.add_option(ApplicationCommandOption::Number(option))
+    }
+
+    pub fn add_channel_option<F>(self, option_builder: F) -> Self
+    where
+        F: FnOnce(ChannelOptionBuilder) -> ChannelOptionBuilder,
+    {
+        let option = option_builder(ChannelOptionBuilder::new()).build();
+        self.add_option(ApplicationCommandOption::Channel(option))
+    }
+
+    /// Only members with all of `permissions` can use the command by default
+    ///
+    /// Discord reads an empty set as "administrators only", not "everyone", so building fails
+    /// with [CommandBuilderError::EmptyPermissions]. Use [CommandBuilder::admins_only] if that is
+    /// intended or [CommandBuilder::clear_permissions] to let everyone use the command.
     pub fn with_default_member_permissions(mut self, permissions: Permissions) -> Self {
+        self.empty_permissions = permissions.is_empty();
         self.default_member_permissions = Some(permissions);
         self
     }
 
+    /// Hides the command from everyone except administrators until permissions are changed in
+    /// the server's settings, sent as `"0"`
+    pub fn admins_only(mut self) -> Self {
+        self.default_member_permissions = Some(Permissions::empty());
+        self.empty_permissions = false;
+        self
+    }
+
+    /// Removes any default permissions so everyone can use the command
+    pub fn clear_permissions(mut self) -> Self {
+        self.default_member_permissions = None;
+        self.empty_permissions = false;
+        self
+    }
+
     pub fn with_dm_permission(mut self, dm_permission: bool) -> Self {
         self.dm_permission = Some(dm_permission);
         self
     }
 
+    /// Where the app must be installed for the command to show up
+    pub fn with_integration_types(
+        mut self,
+        integration_types: Vec<ApplicationIntegrationType>,
+    ) -> Self {
+        self.integration_types = Some(integration_types);
+        self
+    }
+
+    /// Where the command can be used
+    pub fn with_contexts(mut self, contexts: Vec<InteractionContextType>) -> Self {
+        self.contexts = Some(contexts);
+        self
+    }
+
+    /// Sends the deprecated `dm_permission` as the equivalent `contexts`, see
+    /// [CommandDetails::modernize_contexts]
+    pub fn modernize_contexts(mut self) -> Self {
+        self.modernize_contexts = true;
+        self
+    }
+
+    /// Makes the command usable from guild and user installs, in guilds, the bot's DMs and
+    /// other DMs and group DMs
+    pub fn available_everywhere(self) -> Self {
+        self.with_integration_types(vec![
+            ApplicationIntegrationType::GuildInstall,
+            ApplicationIntegrationType::UserInstall,
+        ])
+        .with_contexts(vec![
+            InteractionContextType::Guild,
+            InteractionContextType::BotDm,
+            InteractionContextType::PrivateChannel,
+        ])
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the command is invalid, see [CommandBuilder::try_build_chat_command]
     pub fn build_chat_command(self) -> ApplicationCommand {
-        ApplicationCommand::new_chat_input_command(
+        self.try_build_chat_command()
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Builds the command, failing if it has both subcommands (or groups) and regular options, or
+    /// empty [default member permissions](Self::with_default_member_permissions)
+    ///
+    /// A `dm_permission` that disagrees with `contexts` doesn't fail the build, Discord ignores it
+    /// then. See [CommandsBuilder::conflicting_dm_permission] to warn about it.
+    pub fn try_build_chat_command(self) -> Result<ApplicationCommand, CommandBuilderError> {
+        self.validate()?;
+
+        let mut command = ApplicationCommand::new_chat_input_command(
             self.name,
             self.description,
             self.default_member_permissions,
             self.dm_permission,
             None,
             self.options,
-        )
+        );
+
+        if let ApplicationCommand::ChatInputCommand(ref mut command) = command {
+            command.details.integration_types = self.integration_types;
+            command.details.contexts = self.contexts;
+
+            if self.modernize_contexts {
+                command.details.modernize_contexts();
+            }
+        }
+
+        Ok(command)
+    }
+
+    fn validate(&self) -> Result<(), CommandBuilderError> {
+        if self.empty_permissions {
+            return Err(CommandBuilderError::EmptyPermissions {
+                command: self.name.clone(),
+            });
+        }
+
+        let options = self.options.as_deref().unwrap_or(&[]);
+
+        let is_subcommand = |o: &&ApplicationCommandOption| {
+            matches!(
+                o,
+                ApplicationCommandOption::Subcommand(_)
+                    | ApplicationCommandOption::SubcommandGroup(_)
+            )
+        };
+
+        let subcommand = options.iter().find(is_subcommand);
+        let option = options.iter().find(|o| !is_subcommand(o));
+
+        if let (Some(subcommand), Some(option)) = (subcommand, option) {
+            return Err(CommandBuilderError::MixedSubcommandsAndOptions {
+                command: self.name.clone(),
+                subcommand: subcommand.name().to_string(),
+                option: option.name().to_string(),
+            });
+        }
+
+        Ok(())
     }
 }
 
@@ -143,6 +365,38 @@ impl SubcommandBuilder {
         self
     }
 
+    pub fn add_string_option<F>(self, option_builder: F) -> Self
+    where
+        F: FnOnce(StringOptionBuilder) -> StringOptionBuilder,
+    {
+        let option = option_builder(StringOptionBuilder::new()).build();
+        self.add_option(SubcommandCommandOption::String(option))
+    }
+
+    pub fn add_integer_option<F>(self, option_builder: F) -> Self
+    where
+        F: FnOnce(IntegerOptionBuilder) -> IntegerOptionBuilder,
+    {
+        let option = option_builder(IntegerOptionBuilder::new()).build();
+        self.add_option(SubcommandCommandOption::Integer(option))
+    }
+
+    pub fn add_number_option<F>(self, option_builder: F) -> Self
+    where
+        F: FnOnce(NumberOptionBuilder) -> NumberOptionBuilder,
+    {
+        let option = option_builder(NumberOptionBuilder::new()).build();
+        self.add_option(SubcommandCommandOption::Number(option))
+    }
+
+    pub fn add_channel_option<F>(self, option_builder: F) -> Self
+    where
+        F: FnOnce(ChannelOptionBuilder) -> ChannelOptionBuilder,
+    {
+        let option = option_builder(ChannelOptionBuilder::new()).build();
+        self.add_option(SubcommandCommandOption::Channel(option))
+    }
+
     fn build(self) -> ApplicationCommandOption {
         ApplicationCommandOption::new_subcommand_option(self.name, self.description, self.options)
     }
@@ -207,11 +461,553 @@ impl SubcommandGroupBuilder {
     }
 }
 
+pub struct StringOptionBuilder {
+    name: String,
+    description: String,
+    required: Option<bool>,
+    choices: Option<Vec<ApplicationCommandOptionChoice<String>>>,
+    min_length: Option<i32>,
+    max_length: Option<i32>,
+    autocomplete: Option<bool>,
+}
+
+impl StringOptionBuilder {
+    pub fn new() -> Self {
+        Self {
+            name: String::new(),
+            description: String::new(),
+            required: None,
+            choices: None,
+            min_length: None,
+            max_length: None,
+            autocomplete: None,
+        }
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name.clear();
+        self.name.push_str(name);
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description.clear();
+        self.description.push_str(description);
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    pub fn add_choice(mut self, name: &str, value: String) -> Self {
+        let choice = ApplicationCommandOptionChoice {
+            name: name.to_string(),
+            name_localizations: None,
+            value,
+        };
+        match self.choices {
+            None => self.choices = Some(vec![choice]),
+            Some(ref mut choices) => choices.push(choice),
+        }
+        self
+    }
+
+    pub fn min_length(mut self, min_length: i32) -> Self {
+        self.min_length = Some(min_length);
+        self
+    }
+
+    pub fn max_length(mut self, max_length: i32) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Enables autocomplete interactions for this option
+    pub fn autocomplete(mut self, autocomplete: bool) -> Self {
+        self.autocomplete = Some(autocomplete);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if autocomplete is enabled while choices are present, Discord does not allow both
+    fn build(self) -> StringOption {
+        assert!(
+            !(self.autocomplete == Some(true) && self.choices.is_some()),
+            "option `{}` cannot have both autocomplete and choices",
+            self.name
+        );
+
+        StringOption {
+            t: TypeField,
+            name: self.name,
+            name_localizations: None,
+            description: self.description,
+            description_localizations: None,
+            required: self.required,
+            choices: self.choices,
+            min_length: self.min_length,
+            max_length: self.max_length,
+            autocomplete: self.autocomplete,
+        }
+    }
+}
+
+impl Default for StringOptionBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct IntegerOptionBuilder {
+    name: String,
+    description: String,
+    required: Option<bool>,
+    choices: Option<Vec<ApplicationCommandOptionChoice<i64>>>,
+    min_value: Option<i64>,
+    max_value: Option<i64>,
+    autocomplete: Option<bool>,
+}
+
+impl IntegerOptionBuilder {
+    pub fn new() -> Self {
+        Self {
+            name: String::new(),
+            description: String::new(),
+            required: None,
+            choices: None,
+            min_value: None,
+            max_value: None,
+            autocomplete: None,
+        }
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name.clear();
+        self.name.push_str(name);
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description.clear();
+        self.description.push_str(description);
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    pub fn add_choice(mut self, name: &str, value: i64) -> Self {
+        let choice = ApplicationCommandOptionChoice {
+            name: name.to_string(),
+            name_localizations: None,
+            value,
+        };
+        match self.choices {
+            None => self.choices = Some(vec![choice]),
+            Some(ref mut choices) => choices.push(choice),
+        }
+        self
+    }
+
+    pub fn min_value(mut self, min_value: i64) -> Self {
+        self.min_value = Some(min_value);
+        self
+    }
+
+    pub fn max_value(mut self, max_value: i64) -> Self {
+        self.max_value = Some(max_value);
+        self
+    }
+
+    /// Enables autocomplete interactions for this option
+    pub fn autocomplete(mut self, autocomplete: bool) -> Self {
+        self.autocomplete = Some(autocomplete);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if autocomplete is enabled while choices are present, Discord does not allow both
+    fn build(self) -> IntegerOption {
+        assert!(
+            !(self.autocomplete == Some(true) && self.choices.is_some()),
+            "option `{}` cannot have both autocomplete and choices",
+            self.name
+        );
+
+        IntegerOption {
+            t: TypeField,
+            name: self.name,
+            name_localizations: None,
+            description: self.description,
+            description_localizations: None,
+            required: self.required,
+            choices: self.choices,
+            min_value: self.min_value,
+            max_value: self.max_value,
+            autocomplete: self.autocomplete,
+        }
+    }
+}
+
+impl Default for IntegerOptionBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct NumberOptionBuilder {
+    name: String,
+    description: String,
+    required: Option<bool>,
+    choices: Option<Vec<ApplicationCommandOptionChoice<f64>>>,
+    min_value: Option<f64>,
+    max_value: Option<f64>,
+    autocomplete: Option<bool>,
+}
+
+impl NumberOptionBuilder {
+    pub fn new() -> Self {
+        Self {
+            name: String::new(),
+            description: String::new(),
+            required: None,
+            choices: None,
+            min_value: None,
+            max_value: None,
+            autocomplete: None,
+        }
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name.clear();
+        self.name.push_str(name);
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description.clear();
+        self.description.push_str(description);
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    pub fn add_choice(mut self, name: &str, value: f64) -> Self {
+        let choice = ApplicationCommandOptionChoice {
+            name: name.to_string(),
+            name_localizations: None,
+            value,
+        };
+        match self.choices {
+            None => self.choices = Some(vec![choice]),
+            Some(ref mut choices) => choices.push(choice),
+        }
+        self
+    }
+
+    pub fn min_value(mut self, min_value: f64) -> Self {
+        self.min_value = Some(min_value);
+        self
+    }
+
+    pub fn max_value(mut self, max_value: f64) -> Self {
+        self.max_value = Some(max_value);
+        self
+    }
+
+    /// Enables autocomplete interactions for this option
+    pub fn autocomplete(mut self, autocomplete: bool) -> Self {
+        self.autocomplete = Some(autocomplete);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if autocomplete is enabled while choices are present, Discord does not allow both
+    fn build(self) -> NumberOption {
+        assert!(
+            !(self.autocomplete == Some(true) && self.choices.is_some()),
+            "option `{}` cannot have both autocomplete and choices",
+            self.name
+        );
+
+        NumberOption {
+            t: TypeField,
+            name: self.name,
+            name_localizations: None,
+            description: self.description,
+            description_localizations: None,
+            required: self.required,
+            choices: self.choices,
+            min_value: self.min_value,
+            max_value: self.max_value,
+            autocomplete: self.autocomplete,
+        }
+    }
+}
+
+impl Default for NumberOptionBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct ChannelOptionBuilder {
+    name: String,
+    description: String,
+    required: Option<bool>,
+    channel_types: Option<Vec<ChannelType>>,
+}
+
+impl ChannelOptionBuilder {
+    pub fn new() -> Self {
+        Self {
+            name: String::new(),
+            description: String::new(),
+            required: None,
+            channel_types: None,
+        }
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name.clear();
+        self.name.push_str(name);
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description.clear();
+        self.description.push_str(description);
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    /// Restricts the channels shown to the user, can be called multiple times to allow several types
+    pub fn add_channel_type(mut self, channel_type: ChannelType) -> Self {
+        match self.channel_types {
+            None => self.channel_types = Some(vec![channel_type]),
+            Some(ref mut channel_types) => channel_types.push(channel_type),
+        }
+        self
+    }
+
+    fn build(self) -> ChannelOption {
+        ChannelOption {
+            t: TypeField,
+            name: self.name,
+            name_localizations: None,
+            description: self.description,
+            description_localizations: None,
+            required: self.required,
+            channel_types: self.channel_types,
+        }
+    }
+}
+
+impl Default for ChannelOptionBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::*;
     use crate::command::ApplicationCommandOption;
 
+    #[test]
+    pub fn available_everywhere() {
+        let command = CommandBuilder::new()
+            .name("roll")
+            .description("description")
+            .available_everywhere()
+            .build_chat_command();
+
+        let json = serde_json::to_value(command).unwrap();
+
+        assert_eq!(json["integration_types"], serde_json::json!([0, 1]));
+        assert_eq!(json["contexts"], serde_json::json!([0, 1, 2]));
+
+        let command = CommandBuilder::new()
+            .name("roll")
+            .description("description")
+            .build_chat_command();
+
+        let json = serde_json::to_value(command).unwrap();
+
+        assert!(json.get("integration_types").is_none());
+        assert!(json.get("contexts").is_none());
+    }
+
+    #[test]
+    pub fn dm_permission_and_contexts() {
+        use InteractionContextType::*;
+
+        type Configure = fn(CommandBuilder) -> CommandBuilder;
+
+        let build = |f: Configure| {
+            let command = f(CommandBuilder::new()
+                .name("roll")
+                .description("description"))
+            .try_build_chat_command()
+            .unwrap();
+            let json = serde_json::to_value(command).unwrap();
+
+            (
+                json.get("dm_permission").cloned(),
+                json.get("contexts").cloned(),
+            )
+        };
+
+        let cases: [(&str, Configure, _, _); 6] = [
+            ("neither", |b| b, None, None),
+            (
+                "dm permission only",
+                |b| b.with_dm_permission(false),
+                Some(json!(false)),
+                None,
+            ),
+            (
+                "dm permission dropped next to contexts",
+                |b| b.with_dm_permission(true).with_contexts(vec![Guild, BotDm]),
+                None,
+                Some(json!([0, 1])),
+            ),
+            (
+                "dm permission disabled, modernized",
+                |b| b.with_dm_permission(false).modernize_contexts(),
+                None,
+                Some(json!([0, 2])),
+            ),
+            (
+                "dm permission allowed, modernized to the default",
+                |b| b.with_dm_permission(true).modernize_contexts(),
+                None,
+                None,
+            ),
+            (
+                "modernizing keeps contexts",
+                |b| {
+                    b.with_dm_permission(false)
+                        .with_contexts(vec![Guild])
+                        .modernize_contexts()
+                },
+                None,
+                Some(json!([0])),
+            ),
+        ];
+
+        for (name, f, dm_permission, contexts) in cases {
+            assert_eq!(build(f), (dm_permission, contexts), "{name}");
+        }
+    }
+
+    #[test]
+    pub fn conflicting_dm_permission() {
+        use InteractionContextType::*;
+
+        let builder = CommandsBuilder::new(Snowflake::default(), None)
+            .add_command(|builder| {
+                builder
+                    .name("roll")
+                    .description("description")
+                    .with_dm_permission(false)
+                    .available_everywhere()
+            })
+            .add_command(|builder| {
+                builder
+                    .name("flip")
+                    .description("description")
+                    .with_dm_permission(true)
+                    .with_contexts(vec![Guild])
+            })
+            .add_command(|builder| {
+                builder
+                    .name("ping")
+                    .description("description")
+                    .with_dm_permission(false)
+                    .with_contexts(vec![Guild, PrivateChannel])
+            })
+            .add_command(|builder| {
+                builder
+                    .name("help")
+                    .description("description")
+                    .with_dm_permission(false)
+            });
+
+        assert_eq!(builder.conflicting_dm_permission(), vec!["roll", "flip"]);
+        assert_eq!(builder.build().len(), 4);
+    }
+
+    #[test]
+    pub fn subcommands_and_options_are_not_mixed() {
+        let builder = CommandBuilder::new()
+            .name("config")
+            .description("description")
+            .add_subcommand(|sub| sub.name("show").description("description"))
+            .add_string_option(|option| option.name("key").description("description"));
+
+        assert_eq!(
+            builder.try_build_chat_command().unwrap_err(),
+            CommandBuilderError::MixedSubcommandsAndOptions {
+                command: "config".to_string(),
+                subcommand: "show".to_string(),
+                option: "key".to_string(),
+            }
+        );
+
+        let builder = CommandBuilder::new()
+            .name("config")
+            .description("description")
+            .add_integer_option(|option| option.name("limit").description("description"))
+            .add_subcommand_group(|group| {
+                group
+                    .name("roles")
+                    .description("description")
+                    .add_subcommand(|sub| sub.name("list").description("description"))
+            });
+
+        assert!(matches!(
+            builder.try_build_chat_command(),
+            Err(CommandBuilderError::MixedSubcommandsAndOptions { subcommand, .. }) if subcommand == "roles"
+        ));
+
+        let builder = CommandBuilder::new()
+            .name("config")
+            .description("description")
+            .add_subcommand(|sub| sub.name("show").description("description"))
+            .add_subcommand_group(|group| group.name("roles").description("description"));
+
+        assert!(builder.try_build_chat_command().is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "command `config` mixes subcommand `show` with option `key`")]
+    pub fn build_panics_on_mixed_options() {
+        CommandsBuilder::new(Snowflake::default(), None).add_command(|builder| {
+            builder
+                .name("config")
+                .description("description")
+                .add_string_option(|option| option.name("key").description("description"))
+                .add_subcommand(|sub| sub.name("show").description("description"))
+        });
+    }
+
     #[test]
     pub fn build_commands_test() {
         // arrange
@@ -290,4 +1086,164 @@ mod tests {
             ApplicationCommandOption::SubcommandGroup(_)
         ));
     }
+    #[test]
+    pub fn build_autocomplete_option_test() {
+        // arrange
+        let builder = CommandsBuilder::new(Snowflake::default(), None).add_command(|builder| {
+            builder
+                .name("name")
+                .description("description")
+                .add_string_option(|option| {
+                    option
+                        .name("query")
+                        .description("query description")
+                        .autocomplete(true)
+                })
+        });
+
+        // act
+        let commands = builder.build();
+        let json = serde_json::to_value(&commands[0]).unwrap();
+
+        // assert
+        assert_eq!(json["options"][0]["type"], 3);
+        assert_eq!(json["options"][0]["name"], "query");
+        assert_eq!(json["options"][0]["autocomplete"], true);
+    }
+
+    #[test]
+    pub fn build_option_choices_test() {
+        // arrange
+        let builder = CommandsBuilder::new(Snowflake::default(), None).add_command(|builder| {
+            builder
+                .name("name")
+                .description("description")
+                .add_integer_option(|option| {
+                    option
+                        .name("count")
+                        .description("count description")
+                        .required(true)
+                        .add_choice("one", 1)
+                        .add_choice("two", 2)
+                })
+        });
+
+        // act
+        let commands = builder.build();
+        let json = serde_json::to_value(&commands[0]).unwrap();
+
+        // assert
+        assert_eq!(json["options"][0]["required"], true);
+        assert_eq!(json["options"][0]["choices"][1]["name"], "two");
+        assert_eq!(json["options"][0]["choices"][1]["value"], 2);
+    }
+
+    #[test]
+    pub fn build_channel_option_test() {
+        // arrange
+        let builder = CommandsBuilder::new(Snowflake::default(), None).add_command(|builder| {
+            builder
+                .name("name")
+                .description("description")
+                .add_channel_option(|option| {
+                    option
+                        .name("channel")
+                        .description("channel description")
+                        .add_channel_type(ChannelType::GuildText)
+                })
+        });
+
+        // act
+        let commands = builder.build();
+        let json = serde_json::to_value(&commands[0]).unwrap();
+
+        // assert
+        assert_eq!(json["options"][0]["type"], 7);
+        assert_eq!(json["options"][0]["name"], "channel");
+        assert_eq!(json["options"][0]["channel_types"], serde_json::json!([0]));
+    }
+
+    #[test]
+    pub fn build_permissions_test() {
+        // arrange
+        let builder = CommandsBuilder::new(Snowflake::default(), None)
+            .add_command(|builder| builder.name("admin").description("d").admins_only())
+            .add_command(|builder| {
+                builder
+                    .name("everyone")
+                    .description("d")
+                    .with_default_member_permissions(Permissions::BanMembers)
+                    .clear_permissions()
+            })
+            .add_command(|builder| {
+                builder
+                    .name("moderators")
+                    .description("d")
+                    .with_default_member_permissions(Permissions::BanMembers)
+            });
+
+        // act
+        let json = serde_json::to_value(builder.build()).unwrap();
+
+        // assert
+        assert_eq!(json[0]["default_member_permissions"], "0");
+        assert!(json[1].get("default_member_permissions").is_none());
+        assert_eq!(json[2]["default_member_permissions"], "4");
+    }
+
+    #[test]
+    pub fn try_build_empty_permissions_test() {
+        let builder = CommandBuilder::new()
+            .name("name")
+            .description("description")
+            .with_default_member_permissions(Permissions::empty());
+
+        assert_eq!(
+            builder.try_build_chat_command().unwrap_err(),
+            CommandBuilderError::EmptyPermissions {
+                command: "name".to_string()
+            }
+        );
+
+        // admins_only is the intended way to get an empty set
+        let command = CommandBuilder::new()
+            .name("name")
+            .description("description")
+            .with_default_member_permissions(Permissions::empty())
+            .admins_only()
+            .try_build_chat_command()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(command).unwrap()["default_member_permissions"],
+            "0"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "command `name` was given empty default_member_permissions")]
+    pub fn build_empty_permissions_test() {
+        CommandsBuilder::new(Snowflake::default(), None).add_command(|builder| {
+            builder
+                .name("name")
+                .description("description")
+                .with_default_member_permissions(Permissions::empty())
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn build_autocomplete_with_choices_test() {
+        CommandsBuilder::new(Snowflake::default(), None).add_command(|builder| {
+            builder
+                .name("name")
+                .description("description")
+                .add_number_option(|option| {
+                    option
+                        .name("amount")
+                        .description("amount description")
+                        .add_choice("half", 0.5)
+                        .autocomplete(true)
+                })
+        });
+    }
 }
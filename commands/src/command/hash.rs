@@ -0,0 +1,187 @@
+use serde_json::Value;
+
+use crate::command::*;
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// 64 bit [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function),
+/// unlike `DefaultHasher` its output is the same across Rust versions and platforms
+struct Fnv(u64);
+
+impl Fnv {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    /// Writes a length prefix first so adjacent values can't run into each other
+    fn write_str(&mut self, s: &str) {
+        self.write(&(s.len() as u64).to_le_bytes());
+        self.write(s.as_bytes());
+    }
+
+    /// Hashes a JSON value with object keys in sorted order, whatever order the map keeps them in
+    fn write_value(&mut self, value: &Value) {
+        match value {
+            Value::Null => self.write(b"n"),
+            Value::Bool(b) => self.write(if *b { b"t" } else { b"f" }),
+            Value::Number(n) => {
+                self.write(b"#");
+                self.write_str(&n.to_string());
+            }
+            Value::String(s) => {
+                self.write(b"s");
+                self.write_str(s);
+            }
+            Value::Array(values) => {
+                self.write(b"[");
+                self.write(&(values.len() as u64).to_le_bytes());
+                for value in values {
+                    self.write_value(value);
+                }
+            }
+            Value::Object(map) => {
+                let mut entries: Vec<_> = map.iter().collect();
+                entries.sort_by_key(|(key, _)| *key);
+
+                self.write(b"{");
+                self.write(&(entries.len() as u64).to_le_bytes());
+                for (key, value) in entries {
+                    self.write_str(key);
+                    self.write_value(value);
+                }
+            }
+        }
+    }
+}
+
+impl CommandsBuilder {
+    /// Stable hash of the commands, for skipping `overwrite_*` calls when nothing changed
+    ///
+    /// Commands are [normalized](ApplicationCommand::normalize) and sorted by type and name first
+    /// (names are only unique per type), so the order they were added in and values left at
+    /// Discord's defaults don't change the hash. The hash is the same across runs and builds,
+    /// store it alongside a deploy to compare later.
+    pub fn content_hash(&self) -> u64 {
+        let mut commands: Vec<Value> = self
+            .commands
+            .iter()
+            .map(|command| {
+                serde_json::to_value(command.clone().normalize())
+                    .expect("commands serialize to JSON")
+            })
+            .collect();
+
+        fn key(command: &Value) -> (Option<u64>, Option<&str>) {
+            (command["type"].as_u64(), command["name"].as_str())
+        }
+        commands.sort_by(|a, b| key(a).cmp(&key(b)));
+
+        let mut hasher = Fnv(FNV_OFFSET);
+        hasher.write_value(&Value::Array(commands));
+        hasher.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use composure_core::models::Snowflake;
+
+    use super::*;
+
+    fn ping(builder: CommandBuilder) -> CommandBuilder {
+        builder.name("ping").description("Replies with pong")
+    }
+
+    fn echo(builder: CommandBuilder) -> CommandBuilder {
+        builder
+            .name("echo")
+            .description("Repeats a message")
+            .add_string_option(|option| {
+                option
+                    .name("message")
+                    .description("What to say")
+                    .required(true)
+            })
+    }
+
+    fn builder() -> CommandsBuilder {
+        CommandsBuilder::new(Snowflake::default(), None)
+    }
+
+    #[test]
+    pub fn order_independent() {
+        let a = builder().add_command(ping).add_command(echo);
+        let b = builder().add_command(echo).add_command(ping);
+
+        assert_eq!(a.content_hash(), b.content_hash());
+    }
+
+    #[test]
+    pub fn same_name_different_type() {
+        let user_command =
+            || ApplicationCommand::new_user_command("ping".to_string(), None, None, None);
+
+        let mut a = builder().add_command(ping);
+        a.commands.push(user_command());
+
+        let mut b = builder();
+        b.commands.push(user_command());
+        let b = b.add_command(ping);
+
+        assert_eq!(a.content_hash(), b.content_hash());
+    }
+
+    #[test]
+    pub fn equal_commands_hash_equal() {
+        use composure_core::models::InteractionContextType::*;
+
+        let a = builder().add_command(|b| ping(b).with_dm_permission(false));
+        let b = builder().add_command(|b| ping(b).with_contexts(vec![Guild, PrivateChannel]));
+
+        assert_eq!(a.commands, b.commands);
+        assert_eq!(a.content_hash(), b.content_hash());
+    }
+
+    #[test]
+    pub fn deterministic() {
+        let builder = builder().add_command(ping).add_command(echo);
+
+        assert_eq!(builder.content_hash(), builder.content_hash());
+
+        // pinned so a change to the hash or the serialized form is noticed, it invalidates
+        // every stored hash
+        assert_eq!(builder.content_hash(), 2917406717026631677);
+    }
+
+    #[test]
+    pub fn changes_with_content() {
+        let base = builder().add_command(ping).content_hash();
+
+        let cases = [
+            builder().add_command(|b| ping(b).description("Replies")),
+            builder().add_command(|b| ping(b).name("pong")),
+            builder().add_command(|b| ping(b).with_dm_permission(false)),
+            builder().add_command(ping).add_command(echo),
+            builder(),
+        ];
+
+        for case in cases {
+            assert_ne!(case.content_hash(), base);
+        }
+    }
+
+    #[test]
+    pub fn ignores_defaults_and_target() {
+        let base = builder().add_command(ping).content_hash();
+
+        // dm_permission defaults to true, the application and guild aren't part of the content
+        let same = CommandsBuilder::new(Snowflake::from_u64(1), Some(Snowflake::from_u64(2)))
+            .add_command(|b| ping(b).with_dm_permission(true));
+
+        assert_eq!(same.content_hash(), base);
+    }
+}
@@ -1,5 +1,9 @@
-use composure::models::{Permissions, Snowflake, TypeField};
-use serde::Deserialize;
+use std::collections::HashMap;
+
+use composure_core::models::{
+    ChannelType, InteractionContextType, Permissions, Snowflake, TypeField,
+};
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::command::*;
@@ -24,6 +28,8 @@ impl ApplicationCommand {
                 default_member_permissions,
                 dm_permission,
                 nsfw,
+                integration_types: None,
+                contexts: None,
                 version: None,
             },
             options,
@@ -48,6 +54,8 @@ impl ApplicationCommand {
             default_member_permissions,
             dm_permission,
             nsfw,
+            integration_types: None,
+            contexts: None,
             version: None,
         })
     }
@@ -68,19 +76,231 @@ impl ApplicationCommand {
             default_member_permissions,
             dm_permission,
             nsfw,
+            integration_types: None,
+            contexts: None,
             version: None,
         })
     }
 
+    pub fn new_entry_point_command(
+        name: String,
+        description: String,
+        handler: Option<EntryPointCommandHandlerType>,
+    ) -> ApplicationCommand {
+        ApplicationCommand::EntryPointCommand(EntryPointCommand {
+            details: CommandDetails {
+                t: TypeField,
+                id: None,
+                application_id: None,
+                guild_id: None,
+                name,
+                name_localizations: None,
+                default_member_permissions: None,
+                dm_permission: None,
+                nsfw: None,
+                integration_types: None,
+                contexts: None,
+                version: None,
+            },
+            description,
+            description_localizations: None,
+            handler,
+        })
+    }
+
     pub fn get_guild_id(&self) -> &Option<Snowflake> {
         match self {
             ApplicationCommand::ChatInputCommand(value) => &value.details.guild_id,
             ApplicationCommand::UserCommand(value) => &value.guild_id,
             ApplicationCommand::MessageCommand(value) => &value.guild_id,
+            ApplicationCommand::EntryPointCommand(value) => &value.details.guild_id,
+        }
+    }
+
+    /// Name of the command, whichever type it is
+    pub fn name(&self) -> &str {
+        match self {
+            ApplicationCommand::ChatInputCommand(value) => &value.details.name,
+            ApplicationCommand::UserCommand(value) => &value.name,
+            ApplicationCommand::MessageCommand(value) => &value.name,
+            ApplicationCommand::EntryPointCommand(value) => &value.details.name,
+        }
+    }
+
+    /// See [CommandDetails::has_conflicting_dm_permission]
+    pub fn has_conflicting_dm_permission(&self) -> bool {
+        match self {
+            ApplicationCommand::ChatInputCommand(value) => {
+                value.details.has_conflicting_dm_permission()
+            }
+            ApplicationCommand::UserCommand(value) => value.has_conflicting_dm_permission(),
+            ApplicationCommand::MessageCommand(value) => value.has_conflicting_dm_permission(),
+            ApplicationCommand::EntryPointCommand(value) => {
+                value.details.has_conflicting_dm_permission()
+            }
         }
     }
 }
 
+/// Commands keyed on their [name](ApplicationCommand::name), for looking up fetched commands
+///
+/// Names are only unique per command type, if a chat input and a context menu command share a
+/// name the one later in `commands` is kept.
+pub fn commands_by_name(commands: Vec<ApplicationCommand>) -> HashMap<String, ApplicationCommand> {
+    commands
+        .into_iter()
+        .map(|command| (command.name().to_string(), command))
+        .collect()
+}
+
+/// [Interaction contexts](InteractionContextType) equivalent to `dm_permission`
+///
+/// `true` is Discord's default so it maps to `None`, which allows every context. `false` only
+/// ever blocked DMs with the bot, so it keeps guilds and private channels.
+fn dm_permission_contexts(dm_permission: bool) -> Option<Vec<InteractionContextType>> {
+    if dm_permission {
+        None
+    } else {
+        Some(vec![
+            InteractionContextType::Guild,
+            InteractionContextType::PrivateChannel,
+        ])
+    }
+}
+
+/// Whether `dm_permission` disagrees with `contexts` about DMs with the bot
+fn dm_permission_conflicts(contexts: &[InteractionContextType], dm_permission: bool) -> bool {
+    contexts.contains(&InteractionContextType::BotDm) != dm_permission
+}
+
+impl<const T: u8> CommandDetails<T> {
+    /// Contexts the command can be used in, `contexts` when set and not empty, otherwise
+    /// translated from the deprecated `dm_permission`
+    pub fn effective_contexts(&self) -> Option<Vec<InteractionContextType>> {
+        match (&self.contexts, self.dm_permission) {
+            (Some(contexts), _) if !contexts.is_empty() => Some(contexts.clone()),
+            (_, Some(dm_permission)) => dm_permission_contexts(dm_permission),
+            (_, None) => None,
+        }
+    }
+
+    /// Whether `dm_permission` and `contexts` are both set and disagree about DMs with the bot
+    ///
+    /// Discord only reads `contexts` then, so the `dm_permission` value is silently ignored.
+    pub fn has_conflicting_dm_permission(&self) -> bool {
+        match (&self.contexts, self.dm_permission) {
+            (Some(contexts), Some(dm_permission)) => {
+                dm_permission_conflicts(contexts, dm_permission)
+            }
+            _ => false,
+        }
+    }
+
+    /// Replaces the deprecated `dm_permission` with the equivalent `contexts`
+    ///
+    /// `contexts` is kept when already set, `dm_permission` is cleared either way.
+    pub fn modernize_contexts(&mut self) {
+        if let Some(dm_permission) = self.dm_permission.take() {
+            if self.contexts.is_none() {
+                self.contexts = dm_permission_contexts(dm_permission);
+            }
+        }
+    }
+}
+
+/// Skips server-assigned fields and missing values, and leaves out `dm_permission` when
+/// `contexts` is set since Discord ignores it then
+impl<const T: u8> Serialize for CommandDetails<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("CommandDetails", 8)?;
+
+        state.serialize_field("type", &self.t)?;
+        if let Some(guild_id) = &self.guild_id {
+            state.serialize_field("guild_id", guild_id)?;
+        }
+        state.serialize_field("name", &self.name)?;
+        if let Some(name_localizations) = &self.name_localizations {
+            state.serialize_field("name_localizations", name_localizations)?;
+        }
+        if let Some(permissions) = &self.default_member_permissions {
+            state.serialize_field("default_member_permissions", permissions)?;
+        }
+        if let (Some(dm_permission), None) = (self.dm_permission, &self.contexts) {
+            state.serialize_field("dm_permission", &dm_permission)?;
+        }
+        if let Some(nsfw) = self.nsfw {
+            state.serialize_field("nsfw", &nsfw)?;
+        }
+        if let Some(integration_types) = &self.integration_types {
+            state.serialize_field("integration_types", integration_types)?;
+        }
+        if let Some(contexts) = &self.contexts {
+            state.serialize_field("contexts", contexts)?;
+        }
+
+        state.end()
+    }
+}
+
+impl<const T: u8> ChatInputCommand<T> {
+    /// Summarizes the top-level options as `(name, type name, required)`
+    pub fn option_summary(&self) -> Vec<(String, &'static str, bool)> {
+        self.options
+            .iter()
+            .flatten()
+            .map(|o| (o.name().to_string(), o.type_name(), o.is_required()))
+            .collect()
+    }
+
+    /// Summarizes every option, recursing into subcommands and subcommand groups.
+    ///
+    /// Nested option names are prefixed with their parents, separated by spaces (e.g. `group sub option`)
+    pub fn option_summary_recursive(&self) -> Vec<(String, &'static str, bool)> {
+        let mut summary = Vec::new();
+
+        for option in self.options.iter().flatten() {
+            summary.push((
+                option.name().to_string(),
+                option.type_name(),
+                option.is_required(),
+            ));
+
+            match option {
+                ApplicationCommandOption::Subcommand(subcommand) => {
+                    summarize_subcommand(&subcommand.name, subcommand, &mut summary)
+                }
+                ApplicationCommandOption::SubcommandGroup(group) => {
+                    for subcommand in group.options.iter().flatten() {
+                        let path = format!("{} {}", group.name, subcommand.name);
+                        summary.push((path.clone(), "SUB_COMMAND", false));
+                        summarize_subcommand(&path, subcommand, &mut summary);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        summary
+    }
+}
+
+fn summarize_subcommand(
+    path: &str,
+    subcommand: &SubcommandOption,
+    summary: &mut Vec<(String, &'static str, bool)>,
+) {
+    for option in subcommand.options.iter().flatten() {
+        summary.push((
+            format!("{} {}", path, option.name()),
+            option.type_name(),
+            option.is_required(),
+        ));
+    }
+}
+
 impl<'de> Deserialize<'de> for ApplicationCommand {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -103,11 +323,45 @@ impl<'de> Deserialize<'de> for ApplicationCommand {
             3 => Ok(ApplicationCommand::MessageCommand(
                 CommandDetails::deserialize(value).map_err(|e| serde::de::Error::custom(e))?,
             )),
+            4 => Ok(ApplicationCommand::EntryPointCommand(
+                EntryPointCommand::deserialize(value).map_err(serde::de::Error::custom)?,
+            )),
             _ => Err(serde::de::Error::custom("Unknown command")),
         }
     }
 }
 
+/// User and message commands as sent to Discord, which expects an empty description on them
+#[derive(Serialize)]
+struct ContextMenuCommand<'a, const T: u8> {
+    #[serde(flatten)]
+    details: &'a CommandDetails<T>,
+
+    description: &'static str,
+}
+
+impl Serialize for ApplicationCommand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            ApplicationCommand::ChatInputCommand(command) => command.serialize(serializer),
+            ApplicationCommand::UserCommand(details) => ContextMenuCommand {
+                details,
+                description: "",
+            }
+            .serialize(serializer),
+            ApplicationCommand::MessageCommand(details) => ContextMenuCommand {
+                details,
+                description: "",
+            }
+            .serialize(serializer),
+            ApplicationCommand::EntryPointCommand(command) => command.serialize(serializer),
+        }
+    }
+}
+
 impl ApplicationCommandOption {
     pub fn new_subcommand_option(
         name: String,
@@ -205,8 +459,17 @@ impl ApplicationCommandOption {
         name: String,
         description: String,
         required: Option<bool>,
+        channel_types: Option<Vec<ChannelType>>,
     ) -> ApplicationCommandOption {
-        ApplicationCommandOption::Channel(Self::new_base_option::<7>(name, description, required))
+        ApplicationCommandOption::Channel(ChannelOption {
+            t: TypeField::<7>,
+            name,
+            name_localizations: None,
+            description,
+            description_localizations: None,
+            required,
+            channel_types,
+        })
     }
 
     pub fn new_role_option(
@@ -278,6 +541,58 @@ impl ApplicationCommandOption {
             required,
         }
     }
+
+    /// Name of the option
+    pub fn name(&self) -> &str {
+        match self {
+            ApplicationCommandOption::Subcommand(o) => &o.name,
+            ApplicationCommandOption::SubcommandGroup(o) => &o.name,
+            ApplicationCommandOption::String(o) => &o.name,
+            ApplicationCommandOption::Integer(o) => &o.name,
+            ApplicationCommandOption::Boolean(o) => &o.name,
+            ApplicationCommandOption::User(o) => &o.name,
+            ApplicationCommandOption::Channel(o) => &o.name,
+            ApplicationCommandOption::Role(o) => &o.name,
+            ApplicationCommandOption::Mentionable(o) => &o.name,
+            ApplicationCommandOption::Number(o) => &o.name,
+            ApplicationCommandOption::Attachment(o) => &o.name,
+        }
+    }
+
+    /// [Option type](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-type) name as written in the Discord documentation
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            ApplicationCommandOption::Subcommand(_) => "SUB_COMMAND",
+            ApplicationCommandOption::SubcommandGroup(_) => "SUB_COMMAND_GROUP",
+            ApplicationCommandOption::String(_) => "STRING",
+            ApplicationCommandOption::Integer(_) => "INTEGER",
+            ApplicationCommandOption::Boolean(_) => "BOOLEAN",
+            ApplicationCommandOption::User(_) => "USER",
+            ApplicationCommandOption::Channel(_) => "CHANNEL",
+            ApplicationCommandOption::Role(_) => "ROLE",
+            ApplicationCommandOption::Mentionable(_) => "MENTIONABLE",
+            ApplicationCommandOption::Number(_) => "NUMBER",
+            ApplicationCommandOption::Attachment(_) => "ATTACHMENT",
+        }
+    }
+
+    /// Whether the option is required. Subcommands and groups are never required
+    pub fn is_required(&self) -> bool {
+        let required = match self {
+            ApplicationCommandOption::Subcommand(_) => None,
+            ApplicationCommandOption::SubcommandGroup(_) => None,
+            ApplicationCommandOption::String(o) => o.required,
+            ApplicationCommandOption::Integer(o) => o.required,
+            ApplicationCommandOption::Boolean(o) => o.required,
+            ApplicationCommandOption::User(o) => o.required,
+            ApplicationCommandOption::Channel(o) => o.required,
+            ApplicationCommandOption::Role(o) => o.required,
+            ApplicationCommandOption::Mentionable(o) => o.required,
+            ApplicationCommandOption::Number(o) => o.required,
+            ApplicationCommandOption::Attachment(o) => o.required,
+        };
+        required.unwrap_or(false)
+    }
 }
 
 impl<'de> Deserialize<'de> for ApplicationCommandOption {
@@ -313,7 +628,7 @@ impl<'de> Deserialize<'de> for ApplicationCommandOption {
                 BaseOption::deserialize(value).map_err(|e| serde::de::Error::custom(e))?,
             )),
             7 => Ok(ApplicationCommandOption::Channel(
-                BaseOption::deserialize(value).map_err(|e| serde::de::Error::custom(e))?,
+                ChannelOption::deserialize(value).map_err(serde::de::Error::custom)?,
             )),
             8 => Ok(ApplicationCommandOption::Role(
                 BaseOption::deserialize(value).map_err(|e| serde::de::Error::custom(e))?,
@@ -399,8 +714,17 @@ impl SubcommandCommandOption {
         name: String,
         description: String,
         required: Option<bool>,
+        channel_types: Option<Vec<ChannelType>>,
     ) -> SubcommandCommandOption {
-        SubcommandCommandOption::Channel(Self::new_base_option::<7>(name, description, required))
+        SubcommandCommandOption::Channel(ChannelOption {
+            t: TypeField::<7>,
+            name,
+            name_localizations: None,
+            description,
+            description_localizations: None,
+            required,
+            channel_types,
+        })
     }
 
     pub fn new_role_option(
@@ -472,6 +796,52 @@ impl SubcommandCommandOption {
             required,
         }
     }
+
+    /// Name of the option
+    pub fn name(&self) -> &str {
+        match self {
+            SubcommandCommandOption::String(o) => &o.name,
+            SubcommandCommandOption::Integer(o) => &o.name,
+            SubcommandCommandOption::Boolean(o) => &o.name,
+            SubcommandCommandOption::User(o) => &o.name,
+            SubcommandCommandOption::Channel(o) => &o.name,
+            SubcommandCommandOption::Role(o) => &o.name,
+            SubcommandCommandOption::Mentionable(o) => &o.name,
+            SubcommandCommandOption::Number(o) => &o.name,
+            SubcommandCommandOption::Attachment(o) => &o.name,
+        }
+    }
+
+    /// [Option type](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-type) name as written in the Discord documentation
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            SubcommandCommandOption::String(_) => "STRING",
+            SubcommandCommandOption::Integer(_) => "INTEGER",
+            SubcommandCommandOption::Boolean(_) => "BOOLEAN",
+            SubcommandCommandOption::User(_) => "USER",
+            SubcommandCommandOption::Channel(_) => "CHANNEL",
+            SubcommandCommandOption::Role(_) => "ROLE",
+            SubcommandCommandOption::Mentionable(_) => "MENTIONABLE",
+            SubcommandCommandOption::Number(_) => "NUMBER",
+            SubcommandCommandOption::Attachment(_) => "ATTACHMENT",
+        }
+    }
+
+    /// Whether the option is required
+    pub fn is_required(&self) -> bool {
+        let required = match self {
+            SubcommandCommandOption::String(o) => o.required,
+            SubcommandCommandOption::Integer(o) => o.required,
+            SubcommandCommandOption::Boolean(o) => o.required,
+            SubcommandCommandOption::User(o) => o.required,
+            SubcommandCommandOption::Channel(o) => o.required,
+            SubcommandCommandOption::Role(o) => o.required,
+            SubcommandCommandOption::Mentionable(o) => o.required,
+            SubcommandCommandOption::Number(o) => o.required,
+            SubcommandCommandOption::Attachment(o) => o.required,
+        };
+        required.unwrap_or(false)
+    }
 }
 
 impl TryFrom<ApplicationCommandOption> for SubcommandCommandOption {
@@ -494,6 +864,22 @@ impl TryFrom<ApplicationCommandOption> for SubcommandCommandOption {
     }
 }
 
+impl From<SubcommandCommandOption> for ApplicationCommandOption {
+    fn from(value: SubcommandCommandOption) -> Self {
+        match value {
+            SubcommandCommandOption::String(x) => ApplicationCommandOption::String(x),
+            SubcommandCommandOption::Integer(x) => ApplicationCommandOption::Integer(x),
+            SubcommandCommandOption::Boolean(x) => ApplicationCommandOption::Boolean(x),
+            SubcommandCommandOption::User(x) => ApplicationCommandOption::User(x),
+            SubcommandCommandOption::Channel(x) => ApplicationCommandOption::Channel(x),
+            SubcommandCommandOption::Role(x) => ApplicationCommandOption::Role(x),
+            SubcommandCommandOption::Mentionable(x) => ApplicationCommandOption::Mentionable(x),
+            SubcommandCommandOption::Number(x) => ApplicationCommandOption::Number(x),
+            SubcommandCommandOption::Attachment(x) => ApplicationCommandOption::Attachment(x),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for SubcommandCommandOption {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -520,7 +906,7 @@ impl<'de> Deserialize<'de> for SubcommandCommandOption {
                 BaseOption::deserialize(value).map_err(|e| serde::de::Error::custom(e))?,
             )),
             7 => Ok(SubcommandCommandOption::Channel(
-                BaseOption::deserialize(value).map_err(|e| serde::de::Error::custom(e))?,
+                ChannelOption::deserialize(value).map_err(serde::de::Error::custom)?,
             )),
             8 => Ok(SubcommandCommandOption::Role(
                 BaseOption::deserialize(value).map_err(|e| serde::de::Error::custom(e))?,
@@ -0,0 +1,748 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+use composure_core::models::{
+    ApplicationIntegrationType, ChannelType, InteractionContextType, Permissions,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::command::*;
+
+/// Version of the [CommandManifest] schema, bumped whenever a field changes meaning or is removed
+pub const COMMAND_MANIFEST_VERSION: u32 = 1;
+
+/// Summary of a bot's commands for documentation, see [CommandsBuilder::manifest]
+///
+/// The schema only holds what a reader of the docs needs, in plain types: option types are
+/// named as in the Discord documentation, permissions and contexts by name. Tools should check
+/// [version](Self::version) against the [COMMAND_MANIFEST_VERSION] they were written for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandManifest {
+    pub version: u32,
+    pub commands: Vec<CommandSummary>,
+}
+
+impl CommandManifest {
+    pub fn new(commands: &[ApplicationCommand]) -> Self {
+        CommandManifest {
+            version: COMMAND_MANIFEST_VERSION,
+            commands: commands.iter().map(CommandSummary::from).collect(),
+        }
+    }
+
+    /// A heading, the details and a table of options per command
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::from("# Commands\n");
+
+        for command in &self.commands {
+            command.write_markdown(&mut markdown);
+        }
+
+        markdown
+    }
+}
+
+/// Command type, as the manifest names it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommandKind {
+    ChatInput,
+    User,
+    Message,
+    PrimaryEntryPoint,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandSummary {
+    pub name: String,
+
+    pub kind: CommandKind,
+
+    /// empty for user and message commands
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<OptionSummary>,
+
+    /// names of the permissions needed to use the command, `None` lets everyone use it and an
+    /// empty list limits it to administrators
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_member_permissions: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dm_permission: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub nsfw: bool,
+
+    /// `guild_install` or `user_install`, `None` for the app's defaults
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_types: Option<Vec<String>>,
+
+    /// `guild`, `bot_dm` or `private_channel`, `None` allows all of them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contexts: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub name_localizations: BTreeMap<String, String>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub description_localizations: BTreeMap<String, String>,
+}
+
+impl CommandSummary {
+    fn new<const T: u8>(
+        details: &CommandDetails<T>,
+        kind: CommandKind,
+        description: &str,
+        description_localizations: &Option<HashMap<String, String>>,
+    ) -> Self {
+        CommandSummary {
+            name: details.name.clone(),
+            kind,
+            description: description.to_string(),
+            options: vec![],
+            default_member_permissions: details
+                .default_member_permissions
+                .as_ref()
+                .map(permission_names),
+            // not sent alongside contexts
+            dm_permission: match details.contexts {
+                Some(_) => None,
+                None => details.dm_permission,
+            },
+            nsfw: details.nsfw.unwrap_or(false),
+            integration_types: details
+                .integration_types
+                .as_ref()
+                .map(|types| types.iter().map(|t| integration_type_name(*t)).collect()),
+            contexts: details
+                .effective_contexts()
+                .map(|contexts| contexts.iter().map(|c| context_name(*c)).collect()),
+            name_localizations: sorted(&details.name_localizations),
+            description_localizations: sorted(description_localizations),
+        }
+    }
+
+    fn write_markdown(&self, markdown: &mut String) {
+        let heading = match self.kind {
+            CommandKind::ChatInput => code_span(&format!("/{}", self.name)),
+            CommandKind::User => format!("{} (user command)", code_span(&self.name)),
+            CommandKind::Message => format!("{} (message command)", code_span(&self.name)),
+            CommandKind::PrimaryEntryPoint => format!("{} (entry point)", code_span(&self.name)),
+        };
+        markdown.push_str(&format!("\n## {}\n", heading));
+
+        if !self.description.is_empty() {
+            markdown.push_str(&format!("\n{}\n", escape_cell(&self.description)));
+        }
+
+        let mut details = vec![];
+        if let Some(permissions) = &self.default_member_permissions {
+            details.push(match permissions.is_empty() {
+                true => "- Permissions: administrators only".to_string(),
+                false => format!("- Permissions: {}", permissions.join(", ")),
+            });
+        }
+        if let Some(contexts) = &self.contexts {
+            details.push(format!("- Contexts: {}", contexts.join(", ")));
+        }
+        if let Some(types) = &self.integration_types {
+            details.push(format!("- Installs: {}", types.join(", ")));
+        }
+        if self.dm_permission == Some(false) {
+            details.push("- Not available in DMs".to_string());
+        }
+        if self.nsfw {
+            details.push("- Age-restricted".to_string());
+        }
+        if !details.is_empty() {
+            markdown.push_str(&format!("\n{}\n", details.join("\n")));
+        }
+
+        if self.options.is_empty() {
+            if self.kind == CommandKind::ChatInput {
+                markdown.push_str("\nNo options.\n");
+            }
+            return;
+        }
+
+        markdown.push_str("\n| Option | Type | Required | Description | Constraints |\n");
+        markdown.push_str("|---|---|---|---|---|\n");
+
+        for option in &self.options {
+            option.write_rows("", markdown);
+        }
+    }
+}
+
+impl From<&ApplicationCommand> for CommandSummary {
+    fn from(command: &ApplicationCommand) -> Self {
+        match command {
+            ApplicationCommand::ChatInputCommand(command) => CommandSummary {
+                options: command
+                    .options
+                    .iter()
+                    .flatten()
+                    .map(OptionSummary::from)
+                    .collect(),
+                ..CommandSummary::new(
+                    &command.details,
+                    CommandKind::ChatInput,
+                    &command.description,
+                    &command.description_localizations,
+                )
+            },
+            ApplicationCommand::UserCommand(details) => {
+                CommandSummary::new(details, CommandKind::User, "", &None)
+            }
+            ApplicationCommand::MessageCommand(details) => {
+                CommandSummary::new(details, CommandKind::Message, "", &None)
+            }
+            ApplicationCommand::EntryPointCommand(command) => CommandSummary::new(
+                &command.details,
+                CommandKind::PrimaryEntryPoint,
+                &command.description,
+                &command.description_localizations,
+            ),
+        }
+    }
+}
+
+/// Choice value or bound of an option
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ManifestValue {
+    Integer(i64),
+    Number(f64),
+    String(String),
+}
+
+impl fmt::Display for ManifestValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestValue::Integer(value) => write!(f, "{}", value),
+            ManifestValue::Number(value) => write!(f, "{}", value),
+            ManifestValue::String(value) => f.write_str(value),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChoiceSummary {
+    pub name: String,
+
+    pub value: ManifestValue,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub name_localizations: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OptionSummary {
+    pub name: String,
+
+    /// [type name](ApplicationCommandOption::type_name) as written in the Discord documentation,
+    /// like `STRING` or `SUB_COMMAND`
+    #[serde(rename = "type")]
+    pub option_type: String,
+
+    pub description: String,
+
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub required: bool,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<ChoiceSummary>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<ManifestValue>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<ManifestValue>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<i32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<i32>,
+
+    /// names of the channel types a channel option is limited to
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_types: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub autocomplete: bool,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub name_localizations: BTreeMap<String, String>,
+
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub description_localizations: BTreeMap<String, String>,
+
+    /// options of a subcommand, or subcommands of a group
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<OptionSummary>,
+}
+
+impl OptionSummary {
+    fn new(
+        option: &ApplicationCommandOption,
+        name_localizations: &Option<HashMap<String, String>>,
+        description: &str,
+        description_localizations: &Option<HashMap<String, String>>,
+    ) -> Self {
+        OptionSummary {
+            name: option.name().to_string(),
+            option_type: option.type_name().to_string(),
+            description: description.to_string(),
+            required: option.is_required(),
+            choices: vec![],
+            min_value: None,
+            max_value: None,
+            min_length: None,
+            max_length: None,
+            channel_types: vec![],
+            autocomplete: false,
+            name_localizations: sorted(name_localizations),
+            description_localizations: sorted(description_localizations),
+            options: vec![],
+        }
+    }
+
+    /// Row for this option and its nested options, named by their path below the command
+    fn write_rows(&self, parent: &str, markdown: &mut String) {
+        let path = match parent {
+            "" => self.name.clone(),
+            parent => format!("{} {}", parent, self.name),
+        };
+
+        markdown.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            escape_cell(&code_span(&path)),
+            self.option_type,
+            if self.required { "yes" } else { "" },
+            escape_cell(&self.description),
+            escape_cell(&self.constraints().join("; ")),
+        ));
+
+        for option in &self.options {
+            option.write_rows(&path, markdown);
+        }
+    }
+
+    fn constraints(&self) -> Vec<String> {
+        let mut constraints = vec![];
+
+        if !self.choices.is_empty() {
+            let choices = self
+                .choices
+                .iter()
+                .map(|choice| {
+                    format!(
+                        "{} ({})",
+                        choice.name.replace('`', "\\`"),
+                        code_span(&choice.value.to_string())
+                    )
+                })
+                .collect::<Vec<_>>();
+            constraints.push(format!("choices: {}", choices.join(", ")));
+        }
+        if let Some(min) = &self.min_value {
+            constraints.push(format!("min {}", min));
+        }
+        if let Some(max) = &self.max_value {
+            constraints.push(format!("max {}", max));
+        }
+        if let Some(min) = self.min_length {
+            constraints.push(format!("min length {}", min));
+        }
+        if let Some(max) = self.max_length {
+            constraints.push(format!("max length {}", max));
+        }
+        if !self.channel_types.is_empty() {
+            constraints.push(format!("channels: {}", self.channel_types.join(", ")));
+        }
+        if self.autocomplete {
+            constraints.push("autocomplete".to_string());
+        }
+
+        constraints
+    }
+}
+
+impl From<&ApplicationCommandOption> for OptionSummary {
+    fn from(option: &ApplicationCommandOption) -> Self {
+        let summary = |name_localizations, description, description_localizations| {
+            OptionSummary::new(
+                option,
+                name_localizations,
+                description,
+                description_localizations,
+            )
+        };
+
+        match option {
+            ApplicationCommandOption::Subcommand(o) => OptionSummary {
+                options: o
+                    .options
+                    .iter()
+                    .flatten()
+                    .map(|o| OptionSummary::from(&ApplicationCommandOption::from(o.clone())))
+                    .collect(),
+                ..summary(
+                    &o.name_localizations,
+                    &o.description,
+                    &o.description_localizations,
+                )
+            },
+            ApplicationCommandOption::SubcommandGroup(o) => OptionSummary {
+                options: o
+                    .options
+                    .iter()
+                    .flatten()
+                    .map(|o| OptionSummary::from(&ApplicationCommandOption::Subcommand(o.clone())))
+                    .collect(),
+                ..summary(
+                    &o.name_localizations,
+                    &o.description,
+                    &o.description_localizations,
+                )
+            },
+            ApplicationCommandOption::String(o) => OptionSummary {
+                choices: choices(&o.choices, |v| ManifestValue::String(v.clone())),
+                min_length: o.min_length,
+                max_length: o.max_length,
+                autocomplete: o.autocomplete.unwrap_or(false),
+                ..summary(
+                    &o.name_localizations,
+                    &o.description,
+                    &o.description_localizations,
+                )
+            },
+            ApplicationCommandOption::Integer(o) => OptionSummary {
+                choices: choices(&o.choices, |v| ManifestValue::Integer(*v)),
+                min_value: o.min_value.map(ManifestValue::Integer),
+                max_value: o.max_value.map(ManifestValue::Integer),
+                autocomplete: o.autocomplete.unwrap_or(false),
+                ..summary(
+                    &o.name_localizations,
+                    &o.description,
+                    &o.description_localizations,
+                )
+            },
+            ApplicationCommandOption::Number(o) => OptionSummary {
+                choices: choices(&o.choices, |v| ManifestValue::Number(*v)),
+                min_value: o.min_value.map(ManifestValue::Number),
+                max_value: o.max_value.map(ManifestValue::Number),
+                autocomplete: o.autocomplete.unwrap_or(false),
+                ..summary(
+                    &o.name_localizations,
+                    &o.description,
+                    &o.description_localizations,
+                )
+            },
+            ApplicationCommandOption::Channel(o) => OptionSummary {
+                channel_types: o
+                    .channel_types
+                    .iter()
+                    .flatten()
+                    .map(channel_type_name)
+                    .collect(),
+                ..summary(
+                    &o.name_localizations,
+                    &o.description,
+                    &o.description_localizations,
+                )
+            },
+            ApplicationCommandOption::Boolean(o) => summary(
+                &o.name_localizations,
+                &o.description,
+                &o.description_localizations,
+            ),
+            ApplicationCommandOption::User(o) => summary(
+                &o.name_localizations,
+                &o.description,
+                &o.description_localizations,
+            ),
+            ApplicationCommandOption::Role(o) => summary(
+                &o.name_localizations,
+                &o.description,
+                &o.description_localizations,
+            ),
+            ApplicationCommandOption::Mentionable(o) => summary(
+                &o.name_localizations,
+                &o.description,
+                &o.description_localizations,
+            ),
+            ApplicationCommandOption::Attachment(o) => summary(
+                &o.name_localizations,
+                &o.description,
+                &o.description_localizations,
+            ),
+        }
+    }
+}
+
+fn choices<T>(
+    choices: &Option<Vec<ApplicationCommandOptionChoice<T>>>,
+    value: impl Fn(&T) -> ManifestValue,
+) -> Vec<ChoiceSummary> {
+    choices
+        .iter()
+        .flatten()
+        .map(|choice| ChoiceSummary {
+            name: choice.name.clone(),
+            value: value(&choice.value),
+            name_localizations: sorted(&choice.name_localizations),
+        })
+        .collect()
+}
+
+fn sorted(localizations: &Option<HashMap<String, String>>) -> BTreeMap<String, String> {
+    localizations
+        .iter()
+        .flatten()
+        .map(|(locale, text)| (locale.clone(), text.clone()))
+        .collect()
+}
+
+fn permission_names(permissions: &Permissions) -> Vec<String> {
+    permissions
+        .iter_names()
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+fn integration_type_name(integration_type: ApplicationIntegrationType) -> String {
+    match integration_type {
+        ApplicationIntegrationType::GuildInstall => "guild_install",
+        ApplicationIntegrationType::UserInstall => "user_install",
+    }
+    .to_string()
+}
+
+fn context_name(context: InteractionContextType) -> String {
+    match context {
+        InteractionContextType::Guild => "guild",
+        InteractionContextType::BotDm => "bot_dm",
+        InteractionContextType::PrivateChannel => "private_channel",
+    }
+    .to_string()
+}
+
+fn channel_type_name(channel_type: &ChannelType) -> String {
+    format!("{:?}", channel_type)
+}
+
+/// Inline code showing `text` as is, fenced with more backticks than any run inside it
+fn code_span(text: &str) -> String {
+    let longest = text
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    let fence = "`".repeat(longest + 1);
+    let pad = if text.starts_with('`') || text.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+
+    format!("{fence}{pad}{text}{pad}{fence}")
+}
+
+/// Keeps text from ending a table cell or starting a new row
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+#[cfg(test)]
+mod tests {
+    use composure_core::models::Snowflake;
+    use serde_json::json;
+
+    use super::*;
+
+    fn commands() -> CommandsBuilder {
+        let mut builder = CommandsBuilder::new(Snowflake::from_u64(100000000000000001), None)
+            .add_command(|command| {
+                command
+                    .name("poll")
+                    .description("Create and manage polls")
+                    .with_default_member_permissions(
+                        Permissions::ManageMessages | Permissions::SendMessages,
+                    )
+                    .with_contexts(vec![InteractionContextType::Guild])
+                    .add_subcommand(|subcommand| {
+                        subcommand
+                            .name("create")
+                            .description("Start a poll | one question")
+                            .add_string_option(|option| {
+                                option
+                                    .name("question")
+                                    .description("What to ask")
+                                    .required(true)
+                                    .min_length(1)
+                                    .max_length(300)
+                            })
+                            .add_integer_option(|option| {
+                                option
+                                    .name("duration")
+                                    .description("How long the poll runs")
+                                    .add_choice("One hour", 1)
+                                    .add_choice("One day", 24)
+                            })
+                    })
+                    .add_subcommand_group(|group| {
+                        group
+                            .name("settings")
+                            .description("Poll settings")
+                            .add_subcommand(|subcommand| {
+                                subcommand
+                                    .name("channel")
+                                    .description("Where polls are posted")
+                                    .add_channel_option(|option| {
+                                        option
+                                            .name("channel")
+                                            .description("Channel for polls")
+                                            .required(true)
+                                            .add_channel_type(ChannelType::GuildText)
+                                    })
+                            })
+                            .add_subcommand(|subcommand| {
+                                subcommand
+                                    .name("weight")
+                                    .description("How much a vote counts")
+                                    .add_number_option(|option| {
+                                        option
+                                            .name("weight")
+                                            .description("Vote weight")
+                                            .min_value(0.5)
+                                            .max_value(2.0)
+                                    })
+                            })
+                    })
+            })
+            .add_command(|command| {
+                command
+                    .name("ping")
+                    .description("Check the bot is up")
+                    .admins_only()
+                    .with_dm_permission(false)
+            });
+
+        builder.commands.push(ApplicationCommand::new_user_command(
+            "Report".to_string(),
+            None,
+            None,
+            None,
+        ));
+
+        builder
+    }
+
+    #[test]
+    pub fn markdown() {
+        let markdown = commands().manifest().to_markdown();
+
+        assert_eq!(
+            markdown,
+            include_str!("../../../tests/fixtures/command_manifest.md")
+        );
+    }
+
+    #[test]
+    pub fn schema() {
+        let manifest = commands().manifest();
+        let json = serde_json::to_value(&manifest).unwrap();
+
+        assert_eq!(json["version"], COMMAND_MANIFEST_VERSION);
+        assert_eq!(
+            json["commands"][0]["default_member_permissions"],
+            json!(["SendMessages", "ManageMessages"])
+        );
+        assert_eq!(json["commands"][0]["contexts"], json!(["guild"]));
+        assert_eq!(json["commands"][1]["default_member_permissions"], json!([]));
+        assert_eq!(
+            json["commands"][1]["contexts"],
+            json!(["guild", "private_channel"])
+        );
+        assert_eq!(
+            json["commands"][2],
+            json!({"name": "Report", "kind": "user"})
+        );
+
+        let create = &json["commands"][0]["options"][0];
+        assert_eq!(create["type"], "SUB_COMMAND");
+        assert_eq!(
+            create["options"][1],
+            json!({
+                "name": "duration",
+                "type": "INTEGER",
+                "description": "How long the poll runs",
+                "choices": [
+                    {"name": "One hour", "value": 1},
+                    {"name": "One day", "value": 24}
+                ]
+            })
+        );
+
+        assert_eq!(
+            serde_json::from_value::<CommandManifest>(json).unwrap(),
+            manifest
+        );
+    }
+
+    #[test]
+    pub fn dm_permission_next_to_contexts() {
+        let builder = CommandsBuilder::new(Snowflake::default(), None).add_command(|command| {
+            command
+                .name("roll")
+                .description("description")
+                .with_dm_permission(false)
+                .with_contexts(vec![InteractionContextType::Guild])
+        });
+
+        let summary = &builder.manifest().commands[0];
+
+        assert_eq!(summary.dm_permission, None);
+        assert_eq!(summary.contexts, Some(vec!["guild".to_string()]));
+        assert!(!builder
+            .manifest()
+            .to_markdown()
+            .contains("Not available in DMs"));
+    }
+
+    #[test]
+    pub fn backticks() {
+        let builder = CommandsBuilder::new(Snowflake::default(), None).add_command(|command| {
+            command
+                .name("format")
+                .description("description")
+                .add_string_option(|option| {
+                    option
+                        .name("style")
+                        .description("description")
+                        .add_choice("Inline `code`", "`".to_string())
+                        .add_choice("Block", "```|".to_string())
+                })
+        });
+
+        let markdown = builder.manifest().to_markdown();
+
+        assert!(
+            markdown.contains(
+                "| `style` | STRING |  | description | choices: Inline \\`code\\` (`` ` ``), Block (```` ```\\| ````) |"
+            ),
+            "{markdown}"
+        );
+    }
+}
@@ -1,15 +1,19 @@
 use std::collections::HashMap;
 
-use composure::models::{Permissions, Snowflake, TypeField};
+use composure_core::models::{
+    ApplicationIntegrationType, ChannelType, InteractionContextType, Permissions, Snowflake,
+    TypeField,
+};
 use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// [Application Command Structure](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-structure)
-#[derive(Debug, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone)]
 pub enum ApplicationCommand {
     ChatInputCommand(ChatInputCommand<1>),
     UserCommand(CommandDetails<2>),
     MessageCommand(CommandDetails<3>),
+    EntryPointCommand(EntryPointCommand),
 }
 
 impl ApplicationCommand {
@@ -36,38 +40,48 @@ impl ApplicationCommand {
             None
         }
     }
+
+    pub fn as_entry_point_command(&self) -> Option<&EntryPointCommand> {
+        if let Self::EntryPointCommand(v) = self {
+            Some(v)
+        } else {
+            None
+        }
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Fields shared by every command type
+///
+/// Serialized by hand so `dm_permission` is left out when `contexts` is set, see
+/// [modernize_contexts](Self::modernize_contexts).
+#[derive(Debug, Clone, Deserialize)]
 pub struct CommandDetails<const T: u8> {
     #[serde(rename = "type")]
     pub t: TypeField<T>,
 
     /// Unique ID of command
-    #[serde(skip_serializing)]
     pub id: Option<Snowflake>,
 
     /// ID of the parent application
-    #[serde(skip_serializing)]
     pub application_id: Option<Snowflake>,
 
     /// Guild ID of the command, if not global
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<Snowflake>,
 
     /// [Name of command](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-naming), 1-32 characters
     pub name: String,
 
     /// Localization dictionary for name field. Values follow the same restrictions as name
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub name_localizations: Option<HashMap<String, String>>,
 
     /// Set of [permissions](https://discord.com/developers/docs/topics/permissions) represented as a bit set
-    #[serde(skip_serializing_if = "Option::is_none")]
+    ///
+    /// `None` lets everyone use the command while an empty set (`"0"`) limits it to administrators
     pub default_member_permissions: Option<Permissions>,
 
     /// Indicates whether the command is available in DMs with the app, only for globally-scoped commands. By default, commands are visible.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    ///
+    /// Deprecated by Discord in favor of `contexts`, not sent when `contexts` is set
     pub dm_permission: Option<bool>,
 
     /// Not recommended for use as field will soon be deprecated. Indicates whether the command is enabled by default when the app is added to a guild, defaults to true
@@ -75,15 +89,19 @@ pub struct CommandDetails<const T: u8> {
     // pub default_permission: Option<bool>,
 
     /// Indicates whether the command is [age-restricted](https://discord.com/developers/docs/interactions/application-commands#agerestricted-commands), defaults to false
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub nsfw: Option<bool>,
 
+    /// [Installation contexts](https://discord.com/developers/docs/resources/application#installation-context) where the command is available, only for globally-scoped commands. Defaults to the app's configured contexts
+    pub integration_types: Option<Vec<ApplicationIntegrationType>>,
+
+    /// [Interaction contexts](https://discord.com/developers/docs/interactions/application-commands#interaction-contexts) where the command can be used, only for globally-scoped commands. `None` allows all of them
+    pub contexts: Option<Vec<InteractionContextType>>,
+
     /// Autoincrementing version identifier updated during substantial record changes
-    #[serde(skip_serializing)]
     pub version: Option<Snowflake>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatInputCommand<const T: u8> {
     #[serde(flatten)]
     pub details: CommandDetails<T>,
@@ -100,15 +118,44 @@ pub struct ChatInputCommand<const T: u8> {
     pub options: Option<Vec<ApplicationCommandOption>>,
 }
 
+/// [Primary entry point](https://discord.com/developers/docs/interactions/application-commands#entry-point-commands) command, the app's
+/// entry in the App Launcher. An app has at most one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntryPointCommand {
+    #[serde(flatten)]
+    pub details: CommandDetails<4>,
+
+    /// 1-100 character description
+    pub description: String,
+
+    /// Localization dictionary for description field. Values follow the same restrictions as description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<HashMap<String, String>>,
+
+    /// Whether the app or Discord handles the command, Discord defaults to the app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handler: Option<EntryPointCommandHandlerType>,
+}
+
+/// [Entry Point Command Handler Types](https://discord.com/developers/docs/interactions/application-commands#application-command-object-entry-point-command-handler-types)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum EntryPointCommandHandlerType {
+    /// The app handles the interaction using an interaction token
+    AppHandler = 1,
+
+    /// Discord handles the interaction by launching an Activity and sending a follow-up message without coordinating with the app
+    DiscordLaunchActivity = 2,
+}
+
 pub type BooleanOption = BaseOption<5>;
 pub type UserOption = BaseOption<6>;
-pub type ChannelOption = BaseOption<7>;
 pub type RoleOption = BaseOption<8>;
 pub type MentionableOption = BaseOption<9>;
 pub type AttachmentOption = BaseOption<11>;
 
 /// [Application Command Option Structure](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-structure)
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
 pub enum ApplicationCommandOption {
     Subcommand(SubcommandOption),
@@ -125,7 +172,7 @@ pub enum ApplicationCommandOption {
 }
 
 /// Subcommand options
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
 pub enum SubcommandCommandOption {
     String(StringOption),
@@ -139,7 +186,7 @@ pub enum SubcommandCommandOption {
     Attachment(AttachmentOption),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubcommandOption {
     #[serde(rename = "type")]
     pub t: TypeField<1>,
@@ -162,7 +209,7 @@ pub struct SubcommandOption {
     pub options: Option<Vec<SubcommandCommandOption>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubcommandGroupOption {
     #[serde(rename = "type")]
     pub t: TypeField<2>,
@@ -185,7 +232,7 @@ pub struct SubcommandGroupOption {
     pub options: Option<Vec<SubcommandOption>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StringOption {
     #[serde(rename = "type")]
     pub t: TypeField<3>,
@@ -225,7 +272,7 @@ pub struct StringOption {
     pub autocomplete: Option<bool>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IntegerOption {
     #[serde(rename = "type")]
     pub t: TypeField<4>,
@@ -265,7 +312,7 @@ pub struct IntegerOption {
     pub autocomplete: Option<bool>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NumberOption {
     #[serde(rename = "type")]
     pub t: TypeField<10>,
@@ -305,7 +352,7 @@ pub struct NumberOption {
     pub autocomplete: Option<bool>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BaseOption<const T: u8> {
     #[serde(rename = "type")]
     pub t: TypeField<T>,
@@ -329,8 +376,36 @@ pub struct BaseOption<const T: u8> {
     pub required: Option<bool>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelOption {
+    #[serde(rename = "type")]
+    pub t: TypeField<7>,
+
+    /// [1-32 character name](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-naming)
+    pub name: String,
+
+    /// Localization dictionary for the name field. Values follow the same restrictions as name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<HashMap<String, String>>,
+
+    /// 1-100 character description
+    pub description: String,
+
+    /// Localization dictionary for the description field. Values follow the same restrictions as description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<HashMap<String, String>>,
+
+    /// If the parameter is required or optional--default false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+
+    /// If the option is a channel type, the channels shown will be restricted to these types
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_types: Option<Vec<ChannelType>>,
+}
+
 /// [Application Command Option Choice Structure](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-choice-structure)
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApplicationCommandOptionChoice<T> {
     /// 1-100 character choice name
     pub name: String,
@@ -340,5 +415,5 @@ pub struct ApplicationCommandOptionChoice<T> {
     pub name_localizations: Option<HashMap<String, String>>,
 
     /// Value for the choice, up to 100 characters if string
-    pub value: Vec<T>,
+    pub value: T,
 }
@@ -0,0 +1,817 @@
+use std::collections::HashMap;
+
+use crate::command::*;
+
+/// Localization maps compare equal when both are missing or empty
+fn localizations_eq(
+    a: &Option<HashMap<String, String>>,
+    b: &Option<HashMap<String, String>>,
+) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a == b,
+        (Some(m), None) | (None, Some(m)) => m.is_empty(),
+        (None, None) => true,
+    }
+}
+
+/// Flags compare equal when they resolve to the same value once Discord's default is applied
+fn flag_eq(a: Option<bool>, b: Option<bool>, default: bool) -> bool {
+    a.unwrap_or(default) == b.unwrap_or(default)
+}
+
+/// Lists compare equal when they hold the same elements in the same order, treating missing as empty
+fn list_eq<T: PartialEq>(a: &Option<Vec<T>>, b: &Option<Vec<T>>) -> bool {
+    a.as_deref().unwrap_or(&[]) == b.as_deref().unwrap_or(&[])
+}
+
+fn normalize_localizations(
+    localizations: Option<HashMap<String, String>>,
+) -> Option<HashMap<String, String>> {
+    localizations.filter(|l| !l.is_empty())
+}
+
+fn normalize_flag(flag: Option<bool>, default: bool) -> Option<bool> {
+    flag.filter(|f| *f != default)
+}
+
+fn normalize_list<T>(list: Option<Vec<T>>) -> Option<Vec<T>> {
+    list.filter(|l| !l.is_empty())
+}
+
+impl ApplicationCommand {
+    /// Canonicalizes the command so that semantically equal commands have identical contents.
+    ///
+    /// Server-assigned fields (`id`, `application_id`, `version`, `guild_id`) are cleared, and
+    /// empty collections or values matching Discord's defaults are replaced with `None`. The
+    /// deprecated `dm_permission` is replaced with the contexts it translates to.
+    pub fn normalize(self) -> Self {
+        match self {
+            ApplicationCommand::ChatInputCommand(c) => {
+                ApplicationCommand::ChatInputCommand(c.normalize())
+            }
+            ApplicationCommand::UserCommand(c) => ApplicationCommand::UserCommand(c.normalize()),
+            ApplicationCommand::MessageCommand(c) => {
+                ApplicationCommand::MessageCommand(c.normalize())
+            }
+            ApplicationCommand::EntryPointCommand(c) => {
+                ApplicationCommand::EntryPointCommand(c.normalize())
+            }
+        }
+    }
+}
+
+/// Compares commands semantically, ignoring server-assigned fields and treating
+/// missing values the same as their defaults
+impl PartialEq for ApplicationCommand {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::ChatInputCommand(a), Self::ChatInputCommand(b)) => a == b,
+            (Self::UserCommand(a), Self::UserCommand(b)) => a == b,
+            (Self::MessageCommand(a), Self::MessageCommand(b)) => a == b,
+            (Self::EntryPointCommand(a), Self::EntryPointCommand(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<const T: u8> CommandDetails<T> {
+    fn normalize(self) -> Self {
+        // dm_permission is written as the contexts it translates to, like PartialEq compares it
+        let contexts = normalize_list(self.effective_contexts());
+
+        Self {
+            t: self.t,
+            id: None,
+            application_id: None,
+            guild_id: None,
+            name: self.name,
+            name_localizations: normalize_localizations(self.name_localizations),
+            default_member_permissions: self.default_member_permissions,
+            dm_permission: None,
+            nsfw: normalize_flag(self.nsfw, false),
+            integration_types: normalize_list(self.integration_types),
+            contexts,
+            version: None,
+        }
+    }
+}
+
+impl<const T: u8> PartialEq for CommandDetails<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && localizations_eq(&self.name_localizations, &other.name_localizations)
+            && self.default_member_permissions.as_ref().map(|p| p.bits())
+                == other.default_member_permissions.as_ref().map(|p| p.bits())
+            && flag_eq(self.nsfw, other.nsfw, false)
+            && list_eq(&self.integration_types, &other.integration_types)
+            // dm_permission compares as the contexts it translates to
+            && list_eq(&self.effective_contexts(), &other.effective_contexts())
+    }
+}
+
+impl<const T: u8> ChatInputCommand<T> {
+    fn normalize(self) -> Self {
+        Self {
+            details: self.details.normalize(),
+            description: self.description,
+            description_localizations: normalize_localizations(self.description_localizations),
+            options: normalize_list(self.options).map(|o| {
+                o.into_iter()
+                    .map(ApplicationCommandOption::normalize)
+                    .collect()
+            }),
+        }
+    }
+}
+
+impl EntryPointCommand {
+    fn normalize(self) -> Self {
+        Self {
+            details: self.details.normalize(),
+            description: self.description,
+            description_localizations: normalize_localizations(self.description_localizations),
+            handler: self
+                .handler
+                .filter(|h| *h != EntryPointCommandHandlerType::AppHandler),
+        }
+    }
+}
+
+impl PartialEq for EntryPointCommand {
+    fn eq(&self, other: &Self) -> bool {
+        self.details == other.details
+            && self.description == other.description
+            && localizations_eq(
+                &self.description_localizations,
+                &other.description_localizations,
+            )
+            && self
+                .handler
+                .unwrap_or(EntryPointCommandHandlerType::AppHandler)
+                == other
+                    .handler
+                    .unwrap_or(EntryPointCommandHandlerType::AppHandler)
+    }
+}
+
+impl<const T: u8> PartialEq for ChatInputCommand<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.details == other.details
+            && self.description == other.description
+            && localizations_eq(
+                &self.description_localizations,
+                &other.description_localizations,
+            )
+            && list_eq(&self.options, &other.options)
+    }
+}
+
+impl ApplicationCommandOption {
+    /// Canonicalizes the option, see [`ApplicationCommand::normalize`]
+    pub fn normalize(self) -> Self {
+        match self {
+            Self::Subcommand(o) => Self::Subcommand(o.normalize()),
+            Self::SubcommandGroup(o) => Self::SubcommandGroup(o.normalize()),
+            Self::String(o) => Self::String(o.normalize()),
+            Self::Integer(o) => Self::Integer(o.normalize()),
+            Self::Boolean(o) => Self::Boolean(o.normalize()),
+            Self::User(o) => Self::User(o.normalize()),
+            Self::Channel(o) => Self::Channel(o.normalize()),
+            Self::Role(o) => Self::Role(o.normalize()),
+            Self::Mentionable(o) => Self::Mentionable(o.normalize()),
+            Self::Number(o) => Self::Number(o.normalize()),
+            Self::Attachment(o) => Self::Attachment(o.normalize()),
+        }
+    }
+}
+
+impl PartialEq for ApplicationCommandOption {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Subcommand(a), Self::Subcommand(b)) => a == b,
+            (Self::SubcommandGroup(a), Self::SubcommandGroup(b)) => a == b,
+            (Self::String(a), Self::String(b)) => a == b,
+            (Self::Integer(a), Self::Integer(b)) => a == b,
+            (Self::Boolean(a), Self::Boolean(b)) => a == b,
+            (Self::User(a), Self::User(b)) => a == b,
+            (Self::Channel(a), Self::Channel(b)) => a == b,
+            (Self::Role(a), Self::Role(b)) => a == b,
+            (Self::Mentionable(a), Self::Mentionable(b)) => a == b,
+            (Self::Number(a), Self::Number(b)) => a == b,
+            (Self::Attachment(a), Self::Attachment(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl SubcommandCommandOption {
+    /// Canonicalizes the option, see [`ApplicationCommand::normalize`]
+    pub fn normalize(self) -> Self {
+        match self {
+            Self::String(o) => Self::String(o.normalize()),
+            Self::Integer(o) => Self::Integer(o.normalize()),
+            Self::Boolean(o) => Self::Boolean(o.normalize()),
+            Self::User(o) => Self::User(o.normalize()),
+            Self::Channel(o) => Self::Channel(o.normalize()),
+            Self::Role(o) => Self::Role(o.normalize()),
+            Self::Mentionable(o) => Self::Mentionable(o.normalize()),
+            Self::Number(o) => Self::Number(o.normalize()),
+            Self::Attachment(o) => Self::Attachment(o.normalize()),
+        }
+    }
+}
+
+impl PartialEq for SubcommandCommandOption {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::String(a), Self::String(b)) => a == b,
+            (Self::Integer(a), Self::Integer(b)) => a == b,
+            (Self::Boolean(a), Self::Boolean(b)) => a == b,
+            (Self::User(a), Self::User(b)) => a == b,
+            (Self::Channel(a), Self::Channel(b)) => a == b,
+            (Self::Role(a), Self::Role(b)) => a == b,
+            (Self::Mentionable(a), Self::Mentionable(b)) => a == b,
+            (Self::Number(a), Self::Number(b)) => a == b,
+            (Self::Attachment(a), Self::Attachment(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl SubcommandOption {
+    fn normalize(self) -> Self {
+        Self {
+            t: self.t,
+            name: self.name,
+            name_localizations: normalize_localizations(self.name_localizations),
+            description: self.description,
+            description_localizations: normalize_localizations(self.description_localizations),
+            options: normalize_list(self.options).map(|o| {
+                o.into_iter()
+                    .map(SubcommandCommandOption::normalize)
+                    .collect()
+            }),
+        }
+    }
+}
+
+impl PartialEq for SubcommandOption {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && localizations_eq(&self.name_localizations, &other.name_localizations)
+            && self.description == other.description
+            && localizations_eq(
+                &self.description_localizations,
+                &other.description_localizations,
+            )
+            && list_eq(&self.options, &other.options)
+    }
+}
+
+impl SubcommandGroupOption {
+    fn normalize(self) -> Self {
+        Self {
+            t: self.t,
+            name: self.name,
+            name_localizations: normalize_localizations(self.name_localizations),
+            description: self.description,
+            description_localizations: normalize_localizations(self.description_localizations),
+            options: normalize_list(self.options)
+                .map(|o| o.into_iter().map(SubcommandOption::normalize).collect()),
+        }
+    }
+}
+
+impl PartialEq for SubcommandGroupOption {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && localizations_eq(&self.name_localizations, &other.name_localizations)
+            && self.description == other.description
+            && localizations_eq(
+                &self.description_localizations,
+                &other.description_localizations,
+            )
+            && list_eq(&self.options, &other.options)
+    }
+}
+
+impl StringOption {
+    fn normalize(self) -> Self {
+        Self {
+            t: self.t,
+            name: self.name,
+            name_localizations: normalize_localizations(self.name_localizations),
+            description: self.description,
+            description_localizations: normalize_localizations(self.description_localizations),
+            required: normalize_flag(self.required, false),
+            choices: normalize_list(self.choices).map(|c| {
+                c.into_iter()
+                    .map(ApplicationCommandOptionChoice::normalize)
+                    .collect()
+            }),
+            min_length: self.min_length,
+            max_length: self.max_length,
+            autocomplete: normalize_flag(self.autocomplete, false),
+        }
+    }
+}
+
+impl PartialEq for StringOption {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && localizations_eq(&self.name_localizations, &other.name_localizations)
+            && self.description == other.description
+            && localizations_eq(
+                &self.description_localizations,
+                &other.description_localizations,
+            )
+            && flag_eq(self.required, other.required, false)
+            && list_eq(&self.choices, &other.choices)
+            && self.min_length == other.min_length
+            && self.max_length == other.max_length
+            && flag_eq(self.autocomplete, other.autocomplete, false)
+    }
+}
+
+impl IntegerOption {
+    fn normalize(self) -> Self {
+        Self {
+            t: self.t,
+            name: self.name,
+            name_localizations: normalize_localizations(self.name_localizations),
+            description: self.description,
+            description_localizations: normalize_localizations(self.description_localizations),
+            required: normalize_flag(self.required, false),
+            choices: normalize_list(self.choices).map(|c| {
+                c.into_iter()
+                    .map(ApplicationCommandOptionChoice::normalize)
+                    .collect()
+            }),
+            min_value: self.min_value,
+            max_value: self.max_value,
+            autocomplete: normalize_flag(self.autocomplete, false),
+        }
+    }
+}
+
+impl PartialEq for IntegerOption {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && localizations_eq(&self.name_localizations, &other.name_localizations)
+            && self.description == other.description
+            && localizations_eq(
+                &self.description_localizations,
+                &other.description_localizations,
+            )
+            && flag_eq(self.required, other.required, false)
+            && list_eq(&self.choices, &other.choices)
+            && self.min_value == other.min_value
+            && self.max_value == other.max_value
+            && flag_eq(self.autocomplete, other.autocomplete, false)
+    }
+}
+
+impl NumberOption {
+    fn normalize(self) -> Self {
+        Self {
+            t: self.t,
+            name: self.name,
+            name_localizations: normalize_localizations(self.name_localizations),
+            description: self.description,
+            description_localizations: normalize_localizations(self.description_localizations),
+            required: normalize_flag(self.required, false),
+            choices: normalize_list(self.choices).map(|c| {
+                c.into_iter()
+                    .map(ApplicationCommandOptionChoice::normalize)
+                    .collect()
+            }),
+            min_value: self.min_value,
+            max_value: self.max_value,
+            autocomplete: normalize_flag(self.autocomplete, false),
+        }
+    }
+}
+
+impl PartialEq for NumberOption {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && localizations_eq(&self.name_localizations, &other.name_localizations)
+            && self.description == other.description
+            && localizations_eq(
+                &self.description_localizations,
+                &other.description_localizations,
+            )
+            && flag_eq(self.required, other.required, false)
+            && list_eq(&self.choices, &other.choices)
+            && self.min_value == other.min_value
+            && self.max_value == other.max_value
+            && flag_eq(self.autocomplete, other.autocomplete, false)
+    }
+}
+
+impl ChannelOption {
+    fn normalize(self) -> Self {
+        Self {
+            t: self.t,
+            name: self.name,
+            name_localizations: normalize_localizations(self.name_localizations),
+            description: self.description,
+            description_localizations: normalize_localizations(self.description_localizations),
+            required: normalize_flag(self.required, false),
+            channel_types: normalize_list(self.channel_types),
+        }
+    }
+}
+
+impl PartialEq for ChannelOption {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && localizations_eq(&self.name_localizations, &other.name_localizations)
+            && self.description == other.description
+            && localizations_eq(
+                &self.description_localizations,
+                &other.description_localizations,
+            )
+            && flag_eq(self.required, other.required, false)
+            && list_eq(&self.channel_types, &other.channel_types)
+    }
+}
+
+impl<const T: u8> BaseOption<T> {
+    fn normalize(self) -> Self {
+        Self {
+            t: self.t,
+            name: self.name,
+            name_localizations: normalize_localizations(self.name_localizations),
+            description: self.description,
+            description_localizations: normalize_localizations(self.description_localizations),
+            required: normalize_flag(self.required, false),
+        }
+    }
+}
+
+impl<const T: u8> PartialEq for BaseOption<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && localizations_eq(&self.name_localizations, &other.name_localizations)
+            && self.description == other.description
+            && localizations_eq(
+                &self.description_localizations,
+                &other.description_localizations,
+            )
+            && flag_eq(self.required, other.required, false)
+    }
+}
+
+impl<T> ApplicationCommandOptionChoice<T> {
+    fn normalize(self) -> Self {
+        Self {
+            name: self.name,
+            name_localizations: normalize_localizations(self.name_localizations),
+            value: self.value,
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for ApplicationCommandOptionChoice<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && localizations_eq(&self.name_localizations, &other.name_localizations)
+            && self.value == other.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use composure_core::models::{InteractionContextType, Permissions, Snowflake};
+
+    use super::*;
+
+    fn chat(options: Option<Vec<ApplicationCommandOption>>) -> ApplicationCommand {
+        ApplicationCommand::new_chat_input_command(
+            String::from("name"),
+            String::from("description"),
+            None,
+            None,
+            None,
+            options,
+        )
+    }
+
+    fn string_option(required: Option<bool>) -> ApplicationCommandOption {
+        ApplicationCommandOption::new_string_option(
+            String::from("text"),
+            String::from("text description"),
+            required,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn choice(
+        name: &str,
+        localizations: Option<HashMap<String, String>>,
+    ) -> ApplicationCommandOptionChoice<i64> {
+        ApplicationCommandOptionChoice {
+            name: String::from(name),
+            name_localizations: localizations,
+            value: 1,
+        }
+    }
+
+    fn integer_option(
+        choices: Option<Vec<ApplicationCommandOptionChoice<i64>>>,
+    ) -> ApplicationCommandOption {
+        ApplicationCommandOption::new_integer_option(
+            String::from("number"),
+            String::from("number description"),
+            None,
+            choices,
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn with_details(
+        mut command: ApplicationCommand,
+        f: impl FnOnce(&mut CommandDetails<1>),
+    ) -> ApplicationCommand {
+        if let ApplicationCommand::ChatInputCommand(ref mut c) = command {
+            f(&mut c.details);
+        }
+        command
+    }
+
+    #[test]
+    pub fn cloned_commands_equal() {
+        let command = with_details(
+            chat(Some(vec![
+                string_option(Some(true)),
+                integer_option(Some(vec![choice("one", None)])),
+            ])),
+            |d| d.default_member_permissions = Some(Permissions::ManageGuild),
+        );
+
+        let copy = command.clone();
+        assert_eq!(copy, command);
+
+        // clone and modify leaves the original alone
+        let renamed = with_details(command.clone(), |d| d.name = String::from("renamed"));
+        assert_ne!(renamed, command);
+        assert_eq!(
+            command.as_chat_input_command().unwrap().details.name,
+            "name"
+        );
+
+        for command in [
+            ApplicationCommand::new_user_command(String::from("user"), None, None, None),
+            ApplicationCommand::new_message_command(String::from("message"), None, None, None),
+        ] {
+            assert_eq!(command.clone(), command);
+        }
+    }
+
+    #[test]
+    pub fn equivalence_rules() {
+        let cases: Vec<(&str, ApplicationCommand, ApplicationCommand, bool)> = vec![
+            ("identical", chat(None), chat(None), true),
+            (
+                "server assigned fields are ignored",
+                chat(None),
+                with_details(chat(None), |d| {
+                    d.id = Some(Snowflake::from_u64(1));
+                    d.application_id = Some(Snowflake::from_u64(2));
+                    d.guild_id = Some(Snowflake::from_u64(3));
+                    d.version = Some(Snowflake::from_u64(4));
+                }),
+                true,
+            ),
+            (
+                "missing and empty name localizations",
+                chat(None),
+                with_details(chat(None), |d| d.name_localizations = Some(HashMap::new())),
+                true,
+            ),
+            (
+                "different name localizations",
+                chat(None),
+                with_details(chat(None), |d| {
+                    d.name_localizations =
+                        Some(HashMap::from([(String::from("de"), String::from("name"))]))
+                }),
+                false,
+            ),
+            (
+                "missing and default dm permission",
+                chat(None),
+                with_details(chat(None), |d| d.dm_permission = Some(true)),
+                true,
+            ),
+            (
+                "dm permission disabled",
+                chat(None),
+                with_details(chat(None), |d| d.dm_permission = Some(false)),
+                false,
+            ),
+            (
+                "dm permission disabled and contexts without bot dms",
+                with_details(chat(None), |d| d.dm_permission = Some(false)),
+                with_details(chat(None), |d| {
+                    d.contexts = Some(vec![
+                        InteractionContextType::Guild,
+                        InteractionContextType::PrivateChannel,
+                    ])
+                }),
+                true,
+            ),
+            (
+                "dm permission disabled and guild only contexts",
+                with_details(chat(None), |d| d.dm_permission = Some(false)),
+                with_details(chat(None), |d| {
+                    d.contexts = Some(vec![InteractionContextType::Guild])
+                }),
+                false,
+            ),
+            (
+                "dm permission ignored next to contexts",
+                with_details(chat(None), |d| {
+                    d.dm_permission = Some(false);
+                    d.contexts = Some(vec![InteractionContextType::BotDm]);
+                }),
+                with_details(chat(None), |d| {
+                    d.contexts = Some(vec![InteractionContextType::BotDm])
+                }),
+                true,
+            ),
+            (
+                "dm permission allowed and guild only contexts",
+                with_details(chat(None), |d| d.dm_permission = Some(true)),
+                with_details(chat(None), |d| {
+                    d.contexts = Some(vec![InteractionContextType::Guild])
+                }),
+                false,
+            ),
+            (
+                "missing and default nsfw",
+                chat(None),
+                with_details(chat(None), |d| d.nsfw = Some(false)),
+                true,
+            ),
+            (
+                "different default member permissions",
+                chat(None),
+                with_details(chat(None), |d| {
+                    d.default_member_permissions = Some(Permissions::Administrator)
+                }),
+                false,
+            ),
+            (
+                "different name",
+                chat(None),
+                with_details(chat(None), |d| d.name = String::from("other")),
+                false,
+            ),
+            (
+                "missing and empty options",
+                chat(None),
+                chat(Some(vec![])),
+                true,
+            ),
+            (
+                "missing and false required",
+                chat(Some(vec![string_option(None)])),
+                chat(Some(vec![string_option(Some(false))])),
+                true,
+            ),
+            (
+                "required differs",
+                chat(Some(vec![string_option(None)])),
+                chat(Some(vec![string_option(Some(true))])),
+                false,
+            ),
+            (
+                "option order matters",
+                chat(Some(vec![string_option(None), integer_option(None)])),
+                chat(Some(vec![integer_option(None), string_option(None)])),
+                false,
+            ),
+            (
+                "missing and empty choices",
+                chat(Some(vec![integer_option(None)])),
+                chat(Some(vec![integer_option(Some(vec![]))])),
+                true,
+            ),
+            (
+                "choice localizations missing and empty",
+                chat(Some(vec![integer_option(Some(vec![choice("a", None)]))])),
+                chat(Some(vec![integer_option(Some(vec![choice(
+                    "a",
+                    Some(HashMap::new()),
+                )]))])),
+                true,
+            ),
+            (
+                "choice order matters",
+                chat(Some(vec![integer_option(Some(vec![
+                    choice("a", None),
+                    choice("b", None),
+                ]))])),
+                chat(Some(vec![integer_option(Some(vec![
+                    choice("b", None),
+                    choice("a", None),
+                ]))])),
+                false,
+            ),
+            (
+                "different command types",
+                ApplicationCommand::new_user_command(String::from("name"), None, None, None),
+                ApplicationCommand::new_message_command(String::from("name"), None, None, None),
+                false,
+            ),
+            (
+                "context menu commands",
+                ApplicationCommand::new_user_command(String::from("name"), None, Some(true), None),
+                ApplicationCommand::new_user_command(String::from("name"), None, None, Some(false)),
+                true,
+            ),
+        ];
+
+        for (name, a, b, expected) in cases {
+            assert_eq!(a == b, expected, "{name}");
+            assert_eq!(b == a, expected, "{name} (reversed)");
+
+            // content_hash hashes the normalized JSON, equal commands must serialize the same
+            let (a, b) = (a.normalize(), b.normalize());
+            assert_eq!(
+                serde_json::to_value(&a).unwrap() == serde_json::to_value(&b).unwrap(),
+                expected,
+                "{name} (normalized JSON)"
+            );
+            assert_eq!(a == b, expected, "{name} (normalized)");
+        }
+    }
+
+    #[test]
+    pub fn normalize_clears_defaults() {
+        let command = with_details(
+            chat(Some(vec![
+                string_option(Some(false)),
+                integer_option(Some(vec![])),
+            ])),
+            |d| {
+                d.id = Some(Snowflake::from_u64(1));
+                d.guild_id = Some(Snowflake::from_u64(2));
+                d.name_localizations = Some(HashMap::new());
+                d.dm_permission = Some(true);
+                d.nsfw = Some(false);
+            },
+        )
+        .normalize();
+
+        let command = command.as_chat_input_command().unwrap();
+        assert!(command.details.id.is_none());
+        assert!(command.details.guild_id.is_none());
+        assert!(command.details.name_localizations.is_none());
+        assert!(command.details.dm_permission.is_none());
+        assert!(command.details.nsfw.is_none());
+
+        let options = command.options.as_ref().unwrap();
+        assert!(matches!(
+            &options[0],
+            ApplicationCommandOption::String(StringOption { required: None, .. })
+        ));
+        assert!(matches!(
+            &options[1],
+            ApplicationCommandOption::Integer(IntegerOption { choices: None, .. })
+        ));
+    }
+
+    #[test]
+    pub fn normalize_keeps_non_default_values() {
+        let command = with_details(chat(Some(vec![string_option(Some(true))])), |d| {
+            d.dm_permission = Some(false)
+        })
+        .normalize();
+
+        let command = command.as_chat_input_command().unwrap();
+        // kept as the contexts it translates to
+        assert_eq!(command.details.dm_permission, None);
+        assert_eq!(
+            command.details.contexts,
+            Some(vec![
+                InteractionContextType::Guild,
+                InteractionContextType::PrivateChannel
+            ])
+        );
+        assert!(matches!(
+            &command.options.as_ref().unwrap()[0],
+            ApplicationCommandOption::String(StringOption {
+                required: Some(true),
+                ..
+            })
+        ));
+    }
+}
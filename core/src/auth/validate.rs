@@ -1,11 +1,32 @@
+use std::fmt;
+
 use ed25519_dalek::{PublicKey, Signature, SignatureError, Verifier};
 use hex::FromHexError;
 
+#[derive(Debug)]
 pub enum ValidateError {
     HexError(FromHexError),
     SignatureError(SignatureError),
 }
 
+impl fmt::Display for ValidateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidateError::HexError(err) => write!(f, "invalid hex: {}", err),
+            ValidateError::SignatureError(err) => write!(f, "invalid signature: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ValidateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidateError::HexError(err) => Some(err),
+            ValidateError::SignatureError(err) => Some(err),
+        }
+    }
+}
+
 /// Validates a request using ed25519
 pub fn validate_request(
     public_key: &str,
@@ -0,0 +1,73 @@
+use std::fmt;
+
+use crate::auth::ValidateError;
+
+/// Error from any composure crate
+///
+/// Each crate keeps its own error, this wraps them for code spanning request validation, the API
+/// client and an adapter. Those crates convert their errors into it with `From` and turn on the
+/// feature their variant is behind, so it only exists when the crate is a dependency.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// a request's signature couldn't be checked
+    Validate(ValidateError),
+
+    /// from `composure_api`
+    #[cfg(feature = "api")]
+    Api(Box<dyn std::error::Error>),
+
+    /// from `composure_adapter_cloudflare`
+    #[cfg(feature = "cloudflare")]
+    Cloudflare(Box<dyn std::error::Error>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Validate(err) => write!(f, "validation error: {}", err),
+            #[cfg(feature = "api")]
+            Error::Api(err) => write!(f, "api error: {}", err),
+            #[cfg(feature = "cloudflare")]
+            Error::Cloudflare(err) => write!(f, "cloudflare error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Validate(err) => Some(err),
+            #[cfg(feature = "api")]
+            Error::Api(err) => Some(err.as_ref()),
+            #[cfg(feature = "cloudflare")]
+            Error::Cloudflare(err) => Some(err.as_ref()),
+        }
+    }
+}
+
+impl From<ValidateError> for Error {
+    fn from(err: ValidateError) -> Self {
+        Error::Validate(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use super::*;
+    use crate::auth::validate_request;
+
+    #[test]
+    pub fn from_validate_error() {
+        let validate_err = validate_request("not hex", "00", "0", b"{}").unwrap_err();
+        let message = validate_err.to_string();
+
+        let err: Error = validate_err.into();
+
+        assert!(matches!(err, Error::Validate(ValidateError::HexError(_))));
+        assert_eq!(err.to_string(), format!("validation error: {}", message));
+        assert_eq!(err.source().unwrap().to_string(), message);
+    }
+}
@@ -0,0 +1,12 @@
+pub mod auth;
+mod error;
+pub mod models;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+pub mod util;
+
+pub use error::Error;
+
+pub trait Mentionable {
+    fn to_mention(&self) -> String;
+}
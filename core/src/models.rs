@@ -3,10 +3,14 @@ use strum::AsRefStr;
 mod common;
 mod deserialize;
 mod serialize;
+mod strict;
 
 pub use common::*;
 pub use deserialize::*;
 pub use serialize::*;
+pub use strict::parse_interaction_strict;
+
+pub(crate) use strict::Buffered;
 
 const DISCORD_CDN: &str = "https://cdn.discordapp.com";
 
@@ -1,5 +1,6 @@
 mod allowed_mentions;
 mod attachment;
+mod color;
 mod component;
 mod embed;
 mod emoji;
@@ -9,6 +10,7 @@ mod type_field;
 
 pub use allowed_mentions::*;
 pub use attachment::*;
+pub use color::*;
 pub use component::*;
 pub use embed::*;
 pub use emoji::*;
@@ -18,6 +18,18 @@ pub struct AllowedMentions {
     pub replied_user: bool,
 }
 
+impl AllowedMentions {
+    /// Mentions nothing, not even users or roles written out in the content
+    pub fn none() -> Self {
+        AllowedMentions {
+            parse: vec![],
+            roles: vec![],
+            users: vec![],
+            replied_user: false,
+        }
+    }
+}
+
 /// [Allowed Mention Types](https://discord.com/developers/docs/resources/channel#allowed-mentions-object-allowed-mention-types)
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
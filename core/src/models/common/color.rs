@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+/// RGB color stored as the integer Discord uses for embeds and accent colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Color(pub u32);
+
+impl Color {
+    pub fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        Color(u32::from(r) << 16 | u32::from(g) << 8 | u32::from(b))
+    }
+
+    pub fn r(&self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    pub fn g(&self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    pub fn b(&self) -> u8 {
+        self.0 as u8
+    }
+}
+
+impl From<u32> for Color {
+    fn from(value: u32) -> Self {
+        Color(value)
+    }
+}
+
+impl From<Color> for u32 {
+    fn from(value: Color) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn rgb_round_trip() {
+        let color = Color::from_rgb(0x58, 0x65, 0xf2);
+
+        assert_eq!(color, Color(0x5865f2));
+        assert_eq!((color.r(), color.g(), color.b()), (0x58, 0x65, 0xf2));
+    }
+}
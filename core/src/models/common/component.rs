@@ -0,0 +1,840 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::models::{Buffered, ChannelType, PartialEmoji, TypeField};
+
+/// Select menu for picking from defined text options
+pub type StringSelect = SelectMenu<3>;
+
+/// Select menu for picking from users
+pub type UserSelect = SelectMenu<5>;
+
+/// Select menu for picking from roles
+pub type RoleSelect = SelectMenu<6>;
+
+/// Select menu for picking from users and roles
+pub type MentionableSelect = SelectMenu<7>;
+
+/// Select menu for picking from channels
+pub type ChannelSelect = SelectMenu<8>;
+
+/// Most options a string select menu can hold
+pub const SELECT_MENU_MAX_OPTIONS: usize = 25;
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Component {
+    Button(ButtonComponent),
+    StringSelect(StringSelect),
+    TextInput(TextInput),
+    UserSelect(UserSelect),
+    RoleSelect(RoleSelect),
+    MentionableSelect(MentionableSelect),
+    ChannelSelect(ChannelSelect),
+}
+
+impl Component {
+    /// # Panics
+    ///
+    /// Panics on a button [ButtonComponent::try_new] rejects
+    pub fn new_button(
+        style: ButtonStyle,
+        label: Option<String>,
+        emoji: Option<PartialEmoji>,
+        custom_id: Option<String>,
+        url: Option<String>,
+        disabled: Option<bool>,
+    ) -> Component {
+        Self::Button(ButtonComponent::new(
+            style, label, emoji, custom_id, url, disabled,
+        ))
+    }
+
+    /// Button that opens `url` when clicked, link buttons don't send an interaction
+    ///
+    /// # Panics
+    ///
+    /// Panics if `url` isn't an http or https url, see [try_link_button](Self::try_link_button)
+    pub fn link_button(label: String, url: String) -> Component {
+        Self::try_link_button(label, url).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// [link_button](Self::link_button), failing if `url` isn't an http or https url
+    pub fn try_link_button(
+        label: impl Into<String>,
+        url: impl Into<String>,
+    ) -> Result<Component, ButtonError> {
+        Ok(Self::Button(ButtonComponent::try_new(
+            ButtonStyle::Link,
+            Some(label.into()),
+            None,
+            None,
+            Some(url.into()),
+            None,
+        )?))
+    }
+
+    pub fn new_string_select(
+        custom_id: String,
+        options: Option<Vec<SelectOption>>,
+        channel_types: Option<Vec<ChannelType>>,
+        placeholder: Option<String>,
+        min_values: Option<i32>,
+        max_values: Option<i32>,
+        disabled: Option<bool>,
+    ) -> Component {
+        Self::StringSelect(SelectMenu::new(
+            custom_id,
+            options,
+            channel_types,
+            placeholder,
+            min_values,
+            max_values,
+            disabled,
+        ))
+    }
+
+    /// String select with an option for each `(label, value)` pair
+    ///
+    /// # Panics
+    ///
+    /// Panics if given more than [SELECT_MENU_MAX_OPTIONS] options
+    pub fn string_select_from(
+        custom_id: String,
+        labels: impl IntoIterator<Item = (String, String)>,
+    ) -> Component {
+        let options: Vec<SelectOption> = labels
+            .into_iter()
+            .map(|(label, value)| SelectOption::new(label, value, None, None, None))
+            .collect();
+
+        assert!(
+            options.len() <= SELECT_MENU_MAX_OPTIONS,
+            "select menu `{}` has {} options, the most allowed is {}",
+            custom_id,
+            options.len(),
+            SELECT_MENU_MAX_OPTIONS
+        );
+
+        Self::new_string_select(custom_id, Some(options), None, None, None, None, None)
+    }
+
+    pub fn new_text_input(
+        custom_id: String,
+        style: TextInputStyle,
+        label: String,
+        min_length: Option<i32>,
+        max_length: Option<i32>,
+        required: Option<bool>,
+        value: Option<String>,
+        placeholder: Option<String>,
+    ) -> Component {
+        Self::TextInput(TextInput::new(
+            custom_id,
+            style,
+            label,
+            min_length,
+            max_length,
+            required,
+            value,
+            placeholder,
+        ))
+    }
+
+    pub fn new_user_select(
+        custom_id: String,
+        options: Option<Vec<SelectOption>>,
+        channel_types: Option<Vec<ChannelType>>,
+        placeholder: Option<String>,
+        min_values: Option<i32>,
+        max_values: Option<i32>,
+        disabled: Option<bool>,
+    ) -> Component {
+        Self::UserSelect(SelectMenu::new(
+            custom_id,
+            options,
+            channel_types,
+            placeholder,
+            min_values,
+            max_values,
+            disabled,
+        ))
+    }
+
+    pub fn new_role_select(
+        custom_id: String,
+        options: Option<Vec<SelectOption>>,
+        channel_types: Option<Vec<ChannelType>>,
+        placeholder: Option<String>,
+        min_values: Option<i32>,
+        max_values: Option<i32>,
+        disabled: Option<bool>,
+    ) -> Component {
+        Self::RoleSelect(SelectMenu::new(
+            custom_id,
+            options,
+            channel_types,
+            placeholder,
+            min_values,
+            max_values,
+            disabled,
+        ))
+    }
+
+    pub fn new_mentionable_select(
+        custom_id: String,
+        options: Option<Vec<SelectOption>>,
+        channel_types: Option<Vec<ChannelType>>,
+        placeholder: Option<String>,
+        min_values: Option<i32>,
+        max_values: Option<i32>,
+        disabled: Option<bool>,
+    ) -> Component {
+        Self::MentionableSelect(SelectMenu::new(
+            custom_id,
+            options,
+            channel_types,
+            placeholder,
+            min_values,
+            max_values,
+            disabled,
+        ))
+    }
+
+    pub fn new_channel_select(
+        custom_id: String,
+        options: Option<Vec<SelectOption>>,
+        channel_types: Option<Vec<ChannelType>>,
+        placeholder: Option<String>,
+        min_values: Option<i32>,
+        max_values: Option<i32>,
+        disabled: Option<bool>,
+    ) -> Component {
+        Self::ChannelSelect(SelectMenu::new(
+            custom_id,
+            options,
+            channel_types,
+            placeholder,
+            min_values,
+            max_values,
+            disabled,
+        ))
+    }
+}
+
+impl Component {
+    /// Disables buttons and select menus, text inputs can't be disabled and are left as is
+    pub fn disable(&mut self) {
+        match self {
+            Component::Button(c) => c.disabled = Some(true),
+            Component::StringSelect(c) => c.disabled = Some(true),
+            Component::UserSelect(c) => c.disabled = Some(true),
+            Component::RoleSelect(c) => c.disabled = Some(true),
+            Component::MentionableSelect(c) => c.disabled = Some(true),
+            Component::ChannelSelect(c) => c.disabled = Some(true),
+            Component::TextInput(_) => {}
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Buffered::deserialize(deserializer)?;
+
+        let t = value
+            .get("type")
+            .and_then(Value::as_u64)
+            .ok_or(serde::de::Error::missing_field("type"))?;
+
+        match t {
+            1 => Err(serde::de::Error::custom(
+                "Should not deserialize ActionRow as a component",
+            )),
+            2 => Ok(Component::Button(
+                value.deserialize_into().map_err(serde::de::Error::custom)?,
+            )),
+            3 => Ok(Component::StringSelect(
+                value.deserialize_into().map_err(serde::de::Error::custom)?,
+            )),
+            4 => Ok(Component::TextInput(
+                value.deserialize_into().map_err(serde::de::Error::custom)?,
+            )),
+            5 => Ok(Component::UserSelect(
+                value.deserialize_into().map_err(serde::de::Error::custom)?,
+            )),
+            6 => Ok(Component::RoleSelect(
+                value.deserialize_into().map_err(serde::de::Error::custom)?,
+            )),
+            7 => Ok(Component::MentionableSelect(
+                value.deserialize_into().map_err(serde::de::Error::custom)?,
+            )),
+            8 => Ok(Component::ChannelSelect(
+                value.deserialize_into().map_err(serde::de::Error::custom)?,
+            )),
+            _ => Err(serde::de::Error::custom("Unknown component")),
+        }
+    }
+}
+
+/// Top level modal component giving a text input or select menu a label and description
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Label {
+    #[serde(rename = "type")]
+    pub t: TypeField<18>,
+
+    /// Label text; max 45 characters
+    pub label: String,
+
+    /// Description text shown under the label; max 100 characters
+    pub description: Option<String>,
+
+    /// The text input or select menu being labelled
+    pub component: Box<Component>,
+}
+
+impl Label {
+    pub fn new(label: impl Into<String>, component: Component) -> Self {
+        Self {
+            t: TypeField,
+            label: label.into(),
+            description: None,
+            component: Box::new(component),
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// Container for other components
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ActionRow {
+    #[serde(rename = "type")]
+    pub t: TypeField<1>,
+
+    pub components: Vec<Component>,
+}
+
+impl ActionRow {
+    pub fn new(components: Vec<Component>) -> Self {
+        Self {
+            t: TypeField,
+            components,
+        }
+    }
+}
+
+/// Green "Confirm" and red "Cancel" buttons, with the custom ids `{id_prefix}:confirm` and
+/// `{id_prefix}:cancel`
+pub fn confirm_cancel_row(id_prefix: &str) -> ActionRow {
+    let button = |style, label: &str, action: &str| {
+        Component::new_button(
+            style,
+            Some(label.to_string()),
+            None,
+            Some(format!("{}:{}", id_prefix, action)),
+            None,
+            None,
+        )
+    };
+
+    ActionRow::new(vec![
+        button(ButtonStyle::Success, "Confirm", "confirm"),
+        button(ButtonStyle::Danger, "Cancel", "cancel"),
+    ])
+}
+
+/// Button Object
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ButtonComponent {
+    #[serde(rename = "type")]
+    pub t: TypeField<2>,
+
+    /// Button style
+    pub style: ButtonStyle,
+
+    /// Text that appears on the button; max 80 characters
+    pub label: Option<String>,
+
+    /// name, id, and animated
+    pub emoji: Option<PartialEmoji>,
+
+    /// Developer-defined identifier for the button; max 100 characters
+    pub custom_id: Option<String>,
+
+    /// URL for link-style buttons
+    pub url: Option<String>,
+
+    /// Whether the button is disabled (defaults to false)
+    pub disabled: Option<bool>,
+}
+
+impl ButtonComponent {
+    /// # Panics
+    ///
+    /// Panics on a button [try_new](Self::try_new) rejects
+    pub fn new(
+        style: ButtonStyle,
+        label: Option<String>,
+        emoji: Option<PartialEmoji>,
+        custom_id: Option<String>,
+        url: Option<String>,
+        disabled: Option<bool>,
+    ) -> Self {
+        Self::try_new(style, label, emoji, custom_id, url, disabled)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Button Discord accepts, a [ButtonStyle::Link] button needs an http or https `url` and no
+    /// `custom_id`, any other style can't have a `url`
+    pub fn try_new(
+        style: ButtonStyle,
+        label: Option<String>,
+        emoji: Option<PartialEmoji>,
+        custom_id: Option<String>,
+        url: Option<String>,
+        disabled: Option<bool>,
+    ) -> Result<Self, ButtonError> {
+        match (style, &url) {
+            (ButtonStyle::Link, None) => return Err(ButtonError::MissingUrl),
+            (ButtonStyle::Link, Some(_)) if custom_id.is_some() => {
+                return Err(ButtonError::LinkWithCustomId)
+            }
+            (ButtonStyle::Link, Some(url)) if !is_http_url(url) => {
+                return Err(ButtonError::InvalidUrl(url.clone()))
+            }
+            (ButtonStyle::Link, Some(_)) | (_, None) => {}
+            (style, Some(_)) => return Err(ButtonError::UrlWithoutLink(style)),
+        }
+
+        Ok(Self {
+            t: TypeField,
+            style,
+            label,
+            emoji,
+            custom_id,
+            url,
+            disabled,
+        })
+    }
+}
+
+fn is_http_url(url: &str) -> bool {
+    ["http://", "https://"]
+        .iter()
+        .any(|scheme| url.len() > scheme.len() && url.starts_with(scheme))
+}
+
+/// Why a button was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ButtonError {
+    /// a [ButtonStyle::Link] button without a url
+    MissingUrl,
+
+    /// a [ButtonStyle::Link] button with a custom_id
+    LinkWithCustomId,
+
+    /// a link button url that isn't http or https
+    InvalidUrl(String),
+
+    /// a url on a button of another style
+    UrlWithoutLink(ButtonStyle),
+}
+
+impl fmt::Display for ButtonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ButtonError::MissingUrl => write!(f, "link buttons must have a url"),
+            ButtonError::LinkWithCustomId => write!(f, "link buttons can't have a custom_id"),
+            ButtonError::InvalidUrl(url) => {
+                write!(f, "link button url `{}` must be http or https", url)
+            }
+            ButtonError::UrlWithoutLink(style) => {
+                write!(f, "only link buttons can have a url, not {:?} ones", style)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ButtonError {}
+
+#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq, Eq, Hash, Clone, Copy)]
+#[repr(u8)]
+pub enum ButtonStyle {
+    /// Blurple
+    Primary = 1,
+
+    /// Grey
+    Secondary = 2,
+
+    /// Green
+    Success = 3,
+
+    /// Red
+    Danger = 4,
+
+    /// Grey, navigates to URL
+    Link = 5,
+}
+
+/// [Select Menu Structure](https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-menu-structure)
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct SelectMenu<const T: u8> {
+    /// [Type](https://discord.com/developers/docs/interactions/message-components#component-object-component-types) of select menu component (text: 3, user: 5, role: 6, mentionable: 7, channels: 8)
+    #[serde(rename = "type")]
+    pub t: TypeField<T>,
+
+    /// ID for the select menu; max 100 characters
+    pub custom_id: String,
+
+    /// Specified choices in a select menu (only required and available for string selects (type 3); max 25
+    pub options: Option<Vec<SelectOption>>,
+
+    /// List of channel types to include in the channel select component (type 8)
+    pub channel_types: Option<Vec<ChannelType>>,
+
+    /// Placeholder text if nothing is selected; max 150 characters
+    pub placeholder: Option<String>,
+
+    /// Minimum number of items that must be chosen (defaults to 1); min 0, max 25
+    pub min_values: Option<i32>,
+
+    /// Maximum number of items that can be chosen (defaults to 1); max 25
+    pub max_values: Option<i32>,
+
+    /// Whether select menu is disabled (defaults to false)
+    pub disabled: Option<bool>,
+}
+
+impl<const T: u8> SelectMenu<T> {
+    pub fn new(
+        custom_id: String,
+        options: Option<Vec<SelectOption>>,
+        channel_types: Option<Vec<ChannelType>>,
+        placeholder: Option<String>,
+        min_values: Option<i32>,
+        max_values: Option<i32>,
+        disabled: Option<bool>,
+    ) -> Self {
+        Self {
+            t: TypeField,
+            custom_id,
+            options,
+            channel_types,
+            placeholder,
+            min_values,
+            max_values,
+            disabled,
+        }
+    }
+}
+
+/// [Select Option Structure](https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-option-structure)
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct SelectOption {
+    /// User-facing name of the option; max 100 characters
+    pub label: String,
+
+    /// Dev-defined value of the option; max 100 characters
+    pub value: String,
+
+    /// Additional description of the option; max 100 characters
+    pub description: Option<String>,
+
+    /// id, name, and animated
+    pub emoji: Option<PartialEmoji>,
+
+    /// Will show this option as selected by default
+    pub default: Option<bool>,
+}
+
+impl SelectOption {
+    pub fn new(
+        label: String,
+        value: String,
+        description: Option<String>,
+        emoji: Option<PartialEmoji>,
+        default: Option<bool>,
+    ) -> Self {
+        Self {
+            label,
+            value,
+            description,
+            emoji,
+            default,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct TextInput {
+    #[serde(rename = "type")]
+    pub t: TypeField<4>,
+
+    /// Developer-defined identifier for the input; max 100 characters
+    pub custom_id: String,
+
+    /// The [Text Input Style](https://discord.com/developers/docs/interactions/message-components#text-inputs-text-input-styles)
+    pub style: TextInputStyle,
+
+    /// Label for this component; max 45 characters
+    pub label: String,
+
+    /// Minimum input length for a text input; min 0, max 4000
+    pub min_length: Option<i32>,
+
+    /// Maximum input length for a text input; min 1, max 4000
+    pub max_length: Option<i32>,
+
+    /// Whether this component is required to be filled (defaults to true)
+    pub required: Option<bool>,
+
+    /// Pre-filled value for this component; max 4000 characters
+    pub value: Option<String>,
+
+    /// Custom placeholder text if the input is empty; max 100 characters
+    pub placeholder: Option<String>,
+}
+
+impl TextInput {
+    pub fn new(
+        custom_id: String,
+        style: TextInputStyle,
+        label: String,
+        min_length: Option<i32>,
+        max_length: Option<i32>,
+        required: Option<bool>,
+        value: Option<String>,
+        placeholder: Option<String>,
+    ) -> Self {
+        Self {
+            t: TypeField,
+            custom_id,
+            style,
+            label,
+            min_length,
+            max_length,
+            required,
+            value,
+            placeholder,
+        }
+    }
+}
+
+/// [Text Input Styles](https://discord.com/developers/docs/interactions/message-components#text-inputs-text-input-styles)
+#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq, Eq, Hash, Clone, Copy)]
+#[repr(u8)]
+pub enum TextInputStyle {
+    /// Single-line input
+    Short = 1,
+
+    /// Multi-line input
+    Paragraph = 2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn string_select_from_labels() {
+        let labels = vec![
+            ("Red".to_string(), "red".to_string()),
+            ("Green".to_string(), "green".to_string()),
+            ("Blue".to_string(), "blue".to_string()),
+        ];
+
+        let select = match Component::string_select_from("color".to_string(), labels) {
+            Component::StringSelect(select) => select,
+            other => panic!("expected a string select but got {:?}", other),
+        };
+
+        assert_eq!(select.custom_id, "color");
+
+        let options = select.options.unwrap();
+        assert_eq!(options.len(), 3);
+        assert_eq!(options[1].label, "Green");
+        assert_eq!(options[1].value, "green");
+        assert!(options[1].description.is_none());
+    }
+
+    #[test]
+    pub fn cloned_components_equal() {
+        let row = ActionRow::new(vec![
+            Component::new_button(
+                ButtonStyle::Primary,
+                Some("Click".to_string()),
+                None,
+                Some("click".to_string()),
+                None,
+                None,
+            ),
+            Component::link_button("Docs".to_string(), "https://example.com".to_string()),
+        ]);
+
+        assert_eq!(row.clone(), row);
+
+        let mut changed = row.clone();
+        changed.components.pop();
+        assert_ne!(changed, row);
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn string_select_from_too_many() {
+        let labels = (0..=SELECT_MENU_MAX_OPTIONS).map(|i| (i.to_string(), i.to_string()));
+
+        Component::string_select_from("numbers".to_string(), labels);
+    }
+
+    #[test]
+    pub fn confirm_cancel() {
+        let row = confirm_cancel_row("ban:300000000000000001");
+
+        let buttons = row
+            .components
+            .iter()
+            .map(|component| match component {
+                Component::Button(button) => (
+                    button.style,
+                    button.label.as_deref(),
+                    button.custom_id.as_deref(),
+                ),
+                other => panic!("expected a button but got {:?}", other),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            buttons,
+            [
+                (
+                    ButtonStyle::Success,
+                    Some("Confirm"),
+                    Some("ban:300000000000000001:confirm")
+                ),
+                (
+                    ButtonStyle::Danger,
+                    Some("Cancel"),
+                    Some("ban:300000000000000001:cancel")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    pub fn link_button() {
+        let button = match Component::link_button(
+            "Docs".to_string(),
+            "https://discord.com/developers/docs".to_string(),
+        ) {
+            Component::Button(button) => button,
+            other => panic!("expected a button but got {:?}", other),
+        };
+
+        assert_eq!(button.style, ButtonStyle::Link);
+        assert_eq!(button.label.as_deref(), Some("Docs"));
+        assert_eq!(
+            button.url.as_deref(),
+            Some("https://discord.com/developers/docs")
+        );
+        assert!(button.custom_id.is_none());
+    }
+
+    #[test]
+    pub fn button_errors() {
+        let docs = || Some("https://discord.com".to_string());
+        let cases = [
+            (ButtonStyle::Link, None, None, ButtonError::MissingUrl),
+            (
+                ButtonStyle::Link,
+                Some("docs".to_string()),
+                docs(),
+                ButtonError::LinkWithCustomId,
+            ),
+            (
+                ButtonStyle::Link,
+                None,
+                Some("javascript:alert(1)".to_string()),
+                ButtonError::InvalidUrl("javascript:alert(1)".to_string()),
+            ),
+            (
+                ButtonStyle::Link,
+                None,
+                Some("https://".to_string()),
+                ButtonError::InvalidUrl("https://".to_string()),
+            ),
+            (
+                ButtonStyle::Primary,
+                Some("docs".to_string()),
+                docs(),
+                ButtonError::UrlWithoutLink(ButtonStyle::Primary),
+            ),
+        ];
+
+        for (style, custom_id, url, err) in cases {
+            assert_eq!(
+                ButtonComponent::try_new(style, None, None, custom_id, url, None),
+                Err(err)
+            );
+        }
+
+        assert_eq!(
+            Component::try_link_button("Run", "javascript:alert(1)"),
+            Err(ButtonError::InvalidUrl("javascript:alert(1)".to_string()))
+        );
+        assert!(Component::try_link_button("Docs", "https://discord.com").is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn link_button_invalid_scheme() {
+        Component::link_button("Run".to_string(), "javascript:alert(1)".to_string());
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn link_button_missing_url() {
+        Component::new_button(
+            ButtonStyle::Link,
+            Some("Docs".to_string()),
+            None,
+            None,
+            None,
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn link_button_with_custom_id() {
+        Component::new_button(
+            ButtonStyle::Link,
+            Some("Docs".to_string()),
+            None,
+            Some("docs".to_string()),
+            Some("https://discord.com".to_string()),
+            None,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    pub fn primary_button_with_url() {
+        Component::new_button(
+            ButtonStyle::Primary,
+            Some("Docs".to_string()),
+            None,
+            Some("docs".to_string()),
+            Some("https://discord.com".to_string()),
+            None,
+        );
+    }
+}
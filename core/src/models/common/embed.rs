@@ -1,5 +1,13 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
+/// Most characters Discord accepts in an embed field's name
+pub const EMBED_FIELD_NAME_LIMIT: usize = 256;
+
+/// Most characters Discord accepts in an embed field's value
+pub const EMBED_FIELD_VALUE_LIMIT: usize = 1024;
+
 /// [Embed Object](https://discord.com/developers/docs/resources/channel#embed-object)
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "type", rename = "rich")]
@@ -314,6 +322,7 @@ pub struct EmbedField {
 }
 
 impl EmbedField {
+    /// Unchecked, see [try_new](Self::try_new)
     pub fn new(name: String, value: String, inline: Option<bool>) -> Self {
         Self {
             name,
@@ -321,8 +330,60 @@ impl EmbedField {
             inline,
         }
     }
+
+    /// Field whose name and value fit Discord's limits, both must be 1 to
+    /// [EMBED_FIELD_NAME_LIMIT] and [EMBED_FIELD_VALUE_LIMIT] characters
+    pub fn try_new(
+        name: impl Into<String>,
+        value: impl Into<String>,
+        inline: Option<bool>,
+    ) -> Result<Self, EmbedError> {
+        let name = name.into();
+        let value = value.into();
+
+        let len = name.chars().count();
+        if len == 0 || len > EMBED_FIELD_NAME_LIMIT {
+            return Err(EmbedError::FieldName { len });
+        }
+
+        let len = value.chars().count();
+        if len == 0 || len > EMBED_FIELD_VALUE_LIMIT {
+            return Err(EmbedError::FieldValue { len });
+        }
+
+        Ok(Self::new(name, value, inline))
+    }
+}
+
+/// Why an embed part was rejected, `len` is in characters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmbedError {
+    /// the field name is empty or over [EMBED_FIELD_NAME_LIMIT]
+    FieldName { len: usize },
+
+    /// the field value is empty or over [EMBED_FIELD_VALUE_LIMIT]
+    FieldValue { len: usize },
 }
 
+impl fmt::Display for EmbedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmbedError::FieldName { len } => write!(
+                f,
+                "embed field name must be 1 to {} characters, got {}",
+                EMBED_FIELD_NAME_LIMIT, len
+            ),
+            EmbedError::FieldValue { len } => write!(
+                f,
+                "embed field value must be 1 to {} characters, got {}",
+                EMBED_FIELD_VALUE_LIMIT, len
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EmbedError {}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -358,4 +419,45 @@ pub mod tests {
 
         println!("{:#?}", embed);
     }
+
+    #[test]
+    pub fn field_limits() {
+        let name = "n".repeat(EMBED_FIELD_NAME_LIMIT);
+        let value = "é".repeat(EMBED_FIELD_VALUE_LIMIT);
+
+        let field = EmbedField::try_new(name.clone(), value.clone(), Some(true)).unwrap();
+        assert_eq!(field.name, name);
+        assert_eq!(field.inline, Some(true));
+
+        let cases = [
+            (
+                format!("{name}n"),
+                value.clone(),
+                EmbedError::FieldName { len: 257 },
+            ),
+            (
+                String::new(),
+                value.clone(),
+                EmbedError::FieldName { len: 0 },
+            ),
+            (
+                name.clone(),
+                format!("{value}é"),
+                EmbedError::FieldValue { len: 1025 },
+            ),
+            (name, String::new(), EmbedError::FieldValue { len: 0 }),
+        ];
+
+        for (name, value, err) in cases {
+            assert_eq!(EmbedField::try_new(name, value, None).unwrap_err(), err);
+        }
+
+        assert_eq!(
+            EmbedError::FieldValue { len: 1025 }.to_string(),
+            "embed field value must be 1 to 1024 characters, got 1025"
+        );
+
+        // the unchecked constructor still takes anything
+        assert_eq!(EmbedField::new(String::new(), value, None).name, "");
+    }
 }
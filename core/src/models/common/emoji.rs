@@ -5,7 +5,7 @@ use crate::models::{
     deserialize::{Role, User},
 };
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct PartialEmoji {
     /// [emoji id](https://discord.com/developers/docs/reference#image-formatting)
     pub id: Option<Snowflake>,
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 bitflags! {
     /// [Bitwise Permission Flags](https://discord.com/developers/docs/topics/permissions#permissions-bitwise-permission-flags)
-    #[derive(Debug)]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
     pub struct Permissions: u64 {
         /// Allows creation of instant invites
         const CreateInstantInvite = (1 << 0);
@@ -139,6 +139,29 @@ bitflags! {
     }
 }
 
+impl Permissions {
+    /// Every permission Discord currently defines, with the unused bits between them (43-45) unset
+    ///
+    /// Only named flags are included, so unlike a mask built from raw bits it never sends Discord
+    /// a value it doesn't recognize. Permissions deserialized from Discord keep unknown bits so
+    /// they can be round tripped, use [Permissions::known] to drop them.
+    pub const fn all_known() -> Self {
+        Self::from_bits_truncate(u64::MAX)
+    }
+
+    /// This set with every bit Discord may have added since this crate was released removed
+    pub const fn known(&self) -> Self {
+        Self::from_bits_truncate(self.bits())
+    }
+
+    /// Permissions in `required` that this set doesn't have
+    ///
+    /// Purely bitwise, [Permissions::Administrator] is not treated as granting everything.
+    pub fn missing(&self, required: Permissions) -> Permissions {
+        required.difference(Self::from_bits_retain(self.bits()))
+    }
+}
+
 impl Serialize for Permissions {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -186,4 +209,49 @@ pub mod tests {
         let permissions: Permissions = serde_json::from_str(json).unwrap();
         println!("{:#?}", permissions);
     }
+
+    #[test]
+    pub fn all_known_excludes_unused_bits() {
+        let all_known = Permissions::all_known();
+
+        assert!(all_known.contains(Permissions::SendVoiceMessages));
+        assert!(all_known.contains(Permissions::CreateInstantInvite));
+        assert_eq!(all_known.bits() & (0b111 << 43), 0);
+        assert_eq!(all_known.bits() >> 47, 0);
+
+        let unknown = Permissions::from_bits_retain(1 << 44 | 1 << 60) | Permissions::Speak;
+        assert_eq!(unknown.known().bits(), Permissions::Speak.bits());
+    }
+
+    #[test]
+    pub fn missing() {
+        let granted = Permissions::ViewChannel | Permissions::SendMessages;
+
+        let cases = [
+            (Permissions::empty(), Permissions::empty()),
+            (Permissions::SendMessages, Permissions::empty()),
+            (
+                Permissions::SendMessages | Permissions::EmbedLinks,
+                Permissions::EmbedLinks,
+            ),
+            (
+                Permissions::BanMembers | Permissions::KickMembers | Permissions::ViewChannel,
+                Permissions::BanMembers | Permissions::KickMembers,
+            ),
+        ];
+
+        for (required, expected) in cases {
+            let bits = required.bits();
+
+            assert_eq!(granted.missing(required).bits(), expected.bits(), "{bits}");
+        }
+
+        // administrator doesn't imply the other flags
+        assert_eq!(
+            Permissions::Administrator
+                .missing(Permissions::BanMembers)
+                .bits(),
+            Permissions::BanMembers.bits()
+        );
+    }
 }
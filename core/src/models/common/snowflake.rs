@@ -0,0 +1,365 @@
+use std::{
+    fmt::Debug,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{de::Visitor, Deserialize, Serialize};
+
+const DISCORD_EPOCH: u64 = 1420070400000;
+
+const WORKER_BITS: u64 = 0x3E0000;
+const PROCESS_ID_BITS: u64 = 0x1F000;
+const INCREMENT_BITS: u64 = 0xFFF;
+
+const TIMESTAMP_SHIFT: u8 = 22;
+const WORKER_SHIFT: u8 = 17;
+const PROCESS_ID_SHIFT: u8 = 12;
+
+/// [Snowflake](https://discord.com/developers/docs/reference#snowflakes), Discord's unique id
+///
+/// Stored as the raw 64 bit id, the parts it's made of are computed when asked for.
+#[derive(Eq, PartialEq, Hash, Clone, Default)]
+pub struct Snowflake(u64);
+
+impl Snowflake {
+    pub const fn from_u64(snowflake: u64) -> Self {
+        Snowflake(snowflake)
+    }
+
+    pub const fn to_u64(&self) -> u64 {
+        self.0
+    }
+
+    /// Lowest snowflake created at `timestamp`, milliseconds since the unix epoch
+    ///
+    /// Ids created at or after `timestamp` sort after it, useful for paginating by time.
+    /// Timestamps before the Discord epoch (2015) saturate to 0.
+    pub const fn from_timestamp(timestamp: u64) -> Self {
+        Snowflake(timestamp.saturating_sub(DISCORD_EPOCH) << TIMESTAMP_SHIFT)
+    }
+
+    /// Milliseconds since the unix epoch when the snowflake was created
+    pub const fn timestamp(&self) -> u64 {
+        (self.0 >> TIMESTAMP_SHIFT) + DISCORD_EPOCH
+    }
+
+    /// Internal id of the worker that created the snowflake
+    pub const fn worker_id(&self) -> u8 {
+        ((self.0 & WORKER_BITS) >> WORKER_SHIFT) as u8
+    }
+
+    /// Internal id of the process that created the snowflake
+    pub const fn internal_process_id(&self) -> u8 {
+        ((self.0 & PROCESS_ID_BITS) >> PROCESS_ID_SHIFT) as u8
+    }
+
+    /// Incremented for every id generated on the process
+    pub const fn increment(&self) -> u16 {
+        (self.0 & INCREMENT_BITS) as u16
+    }
+
+    /// true if `s` can be parsed as a snowflake
+    pub fn is_valid(s: &str) -> bool {
+        Snowflake::try_from(s).is_ok()
+    }
+
+    /// When the snowflake was created
+    pub fn created_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.timestamp())
+    }
+
+    /// Mentions the user with this id, for when only the id is known rather than a
+    /// [Mentionable](crate::Mentionable) object
+    pub fn mention_user(&self) -> String {
+        format!("<@{}>", self.0)
+    }
+
+    /// Mentions the role with this id
+    pub fn mention_role(&self) -> String {
+        format!("<@&{}>", self.0)
+    }
+
+    /// Mentions the channel with this id
+    pub fn mention_channel(&self) -> String {
+        format!("<#{}>", self.0)
+    }
+}
+
+impl From<u64> for Snowflake {
+    fn from(value: u64) -> Self {
+        Self::from_u64(value)
+    }
+}
+
+/// Why a string isn't a valid snowflake
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SnowflakeError {
+    /// The string is empty
+    Empty,
+
+    /// The string contains something other than ascii digits
+    InvalidDigit(String),
+
+    /// The number doesn't fit in 64 bits
+    Overflow(String),
+}
+
+impl std::fmt::Display for SnowflakeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnowflakeError::Empty => write!(f, "snowflake is empty"),
+            SnowflakeError::InvalidDigit(s) => write!(f, "snowflake `{s}` is not a number"),
+            SnowflakeError::Overflow(s) => write!(f, "snowflake `{s}` does not fit in 64 bits"),
+        }
+    }
+}
+
+impl std::error::Error for SnowflakeError {}
+
+impl TryFrom<&str> for Snowflake {
+    type Error = SnowflakeError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if s.is_empty() {
+            return Err(SnowflakeError::Empty);
+        }
+
+        // u64 parsing also accepts a leading +
+        if !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(SnowflakeError::InvalidDigit(s.to_string()));
+        }
+
+        s.parse()
+            .map(Self::from_u64)
+            .map_err(|_| SnowflakeError::Overflow(s.to_string()))
+    }
+}
+
+impl FromStr for Snowflake {
+    type Err = <u64 as FromStr>::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_u64(s.parse()?))
+    }
+}
+
+impl Into<u64> for Snowflake {
+    fn into(self) -> u64 {
+        self.to_u64()
+    }
+}
+
+impl Debug for Snowflake {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Snowflake").field(&self.to_u64()).finish()
+    }
+}
+
+impl std::fmt::Display for Snowflake {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_u64())
+    }
+}
+
+impl<'de> Deserialize<'de> for Snowflake {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SnowflakeVisitor;
+
+        impl<'de> Visitor<'de> for SnowflakeVisitor {
+            type Value = Snowflake;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("SnowflakeVisitor")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Snowflake::from_str(v).map_err(|_| {
+                    serde::de::Error::invalid_value(serde::de::Unexpected::Str(v), &self)
+                })
+            }
+        }
+
+        deserializer.deserialize_str(SnowflakeVisitor)
+    }
+}
+
+impl Serialize for Snowflake {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.to_string().as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn timestamp_correct() {
+        let snowflake: u64 = 282265607313817601;
+        let snowflake = Snowflake::from(snowflake);
+
+        assert_eq!(snowflake.timestamp(), 1487367765025);
+    }
+
+    #[test]
+    pub fn timestamp_correct_from_str() {
+        let snowflake = "282265607313817601";
+        let snowflake = Snowflake::from_str(snowflake);
+
+        assert!(snowflake.is_ok());
+
+        let snowflake = snowflake.unwrap();
+
+        assert_eq!(snowflake.timestamp(), 1487367765025);
+    }
+
+    #[test]
+    pub fn from_timestamp() {
+        let snowflake = Snowflake::from_timestamp(1487367765025);
+
+        assert_eq!(snowflake.timestamp(), 1487367765025);
+        assert!(snowflake.to_u64() <= 282265607313817601);
+        assert_eq!(snowflake.increment(), 0);
+
+        assert_eq!(Snowflake::from_timestamp(0).to_u64(), 0);
+    }
+
+    #[test]
+    pub fn created_at_correct() {
+        let snowflake = Snowflake::from(282265607313817601);
+
+        assert_eq!(
+            snowflake.created_at(),
+            UNIX_EPOCH + Duration::from_millis(1487367765025)
+        );
+    }
+
+    #[test]
+    pub fn try_from_str() {
+        let cases = [
+            (
+                "282265607313817601",
+                Ok(Snowflake::from_u64(282265607313817601)),
+            ),
+            ("0", Ok(Snowflake::from_u64(0))),
+            ("", Err(SnowflakeError::Empty)),
+            ("abc", Err(SnowflakeError::InvalidDigit("abc".to_string()))),
+            ("-1", Err(SnowflakeError::InvalidDigit("-1".to_string()))),
+            ("+1", Err(SnowflakeError::InvalidDigit("+1".to_string()))),
+            (
+                "18446744073709551616",
+                Err(SnowflakeError::Overflow("18446744073709551616".to_string())),
+            ),
+        ];
+
+        for (s, expected) in cases {
+            assert_eq!(Snowflake::try_from(s), expected, "{s}");
+            assert_eq!(Snowflake::is_valid(s), expected.is_ok(), "{s}");
+        }
+    }
+
+    #[test]
+    pub fn mentions() {
+        let snowflake = Snowflake::from_u64(282265607313817601);
+
+        assert_eq!(snowflake.mention_user(), "<@282265607313817601>");
+        assert_eq!(snowflake.mention_role(), "<@&282265607313817601>");
+        assert_eq!(snowflake.mention_channel(), "<#282265607313817601>");
+    }
+
+    #[test]
+    pub fn to_u64_works() {
+        let snowflake_id: u64 = 282265607313817601;
+        let snowflake = Snowflake::from(snowflake_id);
+
+        let back_to_u64 = snowflake.to_u64();
+
+        assert_eq!(snowflake_id, back_to_u64);
+    }
+
+    #[test]
+    pub fn deserialize_works() {
+        let snowflake_id = r#""282265607313817601""#;
+
+        let snowflake = serde_json::from_str::<Snowflake>(snowflake_id);
+
+        assert!(snowflake.is_ok());
+
+        let snowflake = snowflake.unwrap();
+
+        assert_eq!(snowflake.timestamp(), 1487367765025);
+        let trimmed = &snowflake_id[1..snowflake_id.len() - 1];
+        assert_eq!(trimmed, snowflake.to_string().as_str());
+    }
+
+    #[test]
+    pub fn parts_correct() {
+        let snowflake = Snowflake::from_u64(175928847299117063);
+
+        assert_eq!(snowflake.timestamp(), 1462015105796);
+        assert_eq!(snowflake.worker_id(), 1);
+        assert_eq!(snowflake.internal_process_id(), 0);
+        assert_eq!(snowflake.increment(), 7);
+    }
+
+    #[test]
+    pub fn u64_round_trip() {
+        // xorshift, deterministic so failures can be reproduced
+        let mut state: u64 = 0x2545f4914f6cdd1d;
+        let random = std::iter::from_fn(|| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            Some(state)
+        });
+
+        let edges = [
+            0,
+            1,
+            u64::MAX,
+            u64::MAX >> 1,
+            1 << 63,
+            1 << 22,
+            (1 << 22) - 1,
+        ]
+        .into_iter()
+        .chain((0..64).map(|bit| 1 << bit))
+        .chain((0..64).map(|bit| !(1 << bit)));
+
+        for x in edges.chain(random.take(10_000)) {
+            let snowflake = Snowflake::from_u64(x);
+
+            assert_eq!(snowflake.to_u64(), x);
+            assert_eq!(snowflake, Snowflake::from_u64(x));
+            assert_eq!(
+                snowflake.to_string().parse::<Snowflake>().unwrap(),
+                snowflake
+            );
+        }
+    }
+
+    #[test]
+    pub fn equal_snowflakes_hash_equal() {
+        use std::collections::HashSet;
+
+        let set: HashSet<Snowflake> = [
+            Snowflake::from_u64(282265607313817601),
+            Snowflake::from_str("282265607313817601").unwrap(),
+            serde_json::from_str(r#""282265607313817601""#).unwrap(),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(set.len(), 1);
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+/// A `type` field that can only hold `T`, for structs that are only valid as one type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TypeField<const T: u8>;
+
+impl<const T: u8> TypeField<T> {
+    /// The type number, `T`
+    pub const fn value() -> u8 {
+        T
+    }
+}
+
+impl<const T: u8> Serialize for TypeField<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(T)
+    }
+}
+
+impl<'de, const T: u8> Deserialize<'de> for TypeField<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = u8::deserialize(deserializer)?;
+        if value == T {
+            Ok(TypeField::<T>)
+        } else {
+            Err(serde::de::Error::custom(format!(
+                "expected type {T} but found {value}"
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn type_field_round_trip() {
+        assert_eq!(TypeField::<4>::value(), 4);
+        assert_eq!(serde_json::to_string(&TypeField::<4>).unwrap(), "4");
+        assert_eq!(
+            serde_json::from_str::<TypeField<4>>("4").unwrap(),
+            TypeField::<4>::default()
+        );
+    }
+
+    #[test]
+    pub fn type_field_mismatch() {
+        let err = serde_json::from_str::<TypeField<4>>("3").unwrap_err();
+
+        assert_eq!(err.to_string(), "expected type 4 but found 3");
+    }
+}
@@ -0,0 +1,31 @@
+mod application;
+mod channel;
+mod connection;
+mod envelope;
+mod from_option;
+mod guild;
+mod integration;
+mod interaction;
+mod member;
+mod message;
+mod poll;
+mod role;
+mod sku;
+mod sticker;
+mod subscription;
+
+pub use application::*;
+pub use channel::*;
+pub use connection::*;
+pub use envelope::*;
+pub use from_option::*;
+pub use guild::*;
+pub use integration::*;
+pub use interaction::*;
+pub use member::*;
+pub use message::*;
+pub use poll::*;
+pub use role::*;
+pub use sku::*;
+pub use sticker::*;
+pub use subscription::*;
@@ -30,11 +30,32 @@ pub struct PartialChannel {
 
     /// for guild channels: id of the parent category for a channel (each parent category can contain up to 50 channels), for threads: id of the text channel this thread was created
     pub parent_id: Option<Snowflake>,
+
+    /// whether the channel is nsfw, not always included
+    pub nsfw: Option<bool>,
+
+    /// the channel topic, not always included
+    pub topic: Option<String>,
+
+    /// sorting position of the channel, not always included
+    pub position: Option<i32>,
+
+    /// the id of the guild, not always included
+    pub guild_id: Option<Snowflake>,
+
+    /// the id of the last message sent in this channel, not always included
+    pub last_message_id: Option<Snowflake>,
+}
+
+impl PartialChannel {
+    pub fn is_thread(&self) -> bool {
+        self.t.is_thread()
+    }
 }
 
 impl Mentionable for PartialChannel {
     fn to_mention(&self) -> String {
-        format!("<#{}>", self.id)
+        self.id.mention_channel()
     }
 }
 
@@ -148,9 +169,112 @@ pub struct Channel {
     pub default_forum_layout: Option<ForumLayoutType>,
 }
 
+impl Channel {
+    /// The overwrite for the role or member `id`, `@everyone`'s has the guild's id
+    pub fn overwrite_for(&self, id: &Snowflake) -> Option<&Overwrite> {
+        self.permission_overwrites
+            .as_ref()?
+            .iter()
+            .find(|overwrite| &overwrite.id == id)
+    }
+
+    /// A member's permissions in this channel, following Discord's
+    /// [permission overwrite algorithm](https://discord.com/developers/docs/topics/permissions#permission-overwrites)
+    ///
+    /// `base` is the member's guild wide permissions. `@everyone`'s overwrite is applied first,
+    /// then the overwrites of all of `member_roles` at once, then `member_id`'s own overwrite.
+    /// Administrators get every permission regardless of overwrites.
+    pub fn compute_overwrites(
+        &self,
+        base: Permissions,
+        member_id: &Snowflake,
+        member_roles: &[Snowflake],
+    ) -> Permissions {
+        if base.contains(Permissions::Administrator) {
+            return Permissions::all_known();
+        }
+
+        let overwrites = match &self.permission_overwrites {
+            Some(overwrites) => overwrites,
+            None => return base,
+        };
+
+        let apply = |permissions: Permissions, allow: &Permissions, deny: &Permissions| {
+            permissions.difference(deny.clone()).union(allow.clone())
+        };
+
+        let mut permissions = base;
+
+        if let Some(everyone) = self.guild_id.as_ref().and_then(|id| self.overwrite_for(id)) {
+            permissions = apply(permissions, &everyone.allow, &everyone.deny);
+        }
+
+        let (allow, deny) = overwrites
+            .iter()
+            .filter(|o| o.t == OverwriteType::Role && member_roles.contains(&o.id))
+            .fold(
+                (Permissions::empty(), Permissions::empty()),
+                |(allow, deny), o| (allow | o.allow.clone(), deny | o.deny.clone()),
+            );
+        permissions = apply(permissions, &allow, &deny);
+
+        if let Some(member) = overwrites
+            .iter()
+            .find(|o| o.t == OverwriteType::Member && &o.id == member_id)
+        {
+            permissions = apply(permissions, &member.allow, &member.deny);
+        }
+
+        permissions
+    }
+}
+
+/// Lets code written against [Channel] accept resolved channels, fields a [PartialChannel] doesn't have are `None`
+impl From<PartialChannel> for Channel {
+    fn from(channel: PartialChannel) -> Self {
+        Channel {
+            id: channel.id,
+            t: channel.t,
+            guild_id: channel.guild_id,
+            position: channel.position,
+            permission_overwrites: None,
+            name: channel.name,
+            topic: channel.topic,
+            nsfw: channel.nsfw,
+            last_message_id: channel.last_message_id,
+            bitrate: None,
+            user_limit: None,
+            rate_limit_per_user: None,
+            recipients: None,
+            icon: None,
+            owner_id: None,
+            application_id: None,
+            managed: None,
+            parent_id: channel.parent_id,
+            last_pin_timestamp: None,
+            rtc_region: None,
+            video_quality_mode: None,
+            message_count: None,
+            member_count: None,
+            thread_metadata: channel.thread_metadata,
+            member: None,
+            default_auto_archive_duration: None,
+            permissions: channel.permissions,
+            flags: None,
+            total_message_sent: None,
+            available_tags: None,
+            applied_tags: None,
+            default_reaction_emoji: None,
+            default_thread_rate_limit_per_user: None,
+            default_sort_order: None,
+            default_forum_layout: None,
+        }
+    }
+}
+
 impl Mentionable for Channel {
     fn to_mention(&self) -> String {
-        format!("<#{}>", self.id)
+        self.id.mention_channel()
     }
 }
 
@@ -161,48 +285,139 @@ impl PartialEq for Channel {
 }
 
 /// [Channel Types](https://discord.com/developers/docs/resources/channel#channel-object-channel-types)
-#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq, Eq)]
-#[repr(u8)]
+///
+/// Numbers Discord adds after these parse as [Unknown](Self::Unknown), so channels of a new type
+/// still deserialize.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum ChannelType {
     /// a text channel within a server
-    GuildText = 0,
+    GuildText,
 
     /// a direct message between users
-    Dm = 1,
+    Dm,
 
     /// a voice channel within a server
-    GuildVoice = 2,
+    GuildVoice,
 
     /// a direct message between multiple users
-    GroupDm = 3,
+    GroupDm,
 
     /// an [organizational category](https://support.discord.com/hc/en-us/articles/115001580171-Channel-Categories-101) that contains up to 50 channels
-    GuildCategory = 4,
+    GuildCategory,
 
     /// a channel that [users can follow and crosspost into their own server](https://support.discord.com/hc/en-us/articles/360032008192) (formerly news channels)
-    GuildAnnouncement = 5,
+    GuildAnnouncement,
 
     /// a temporary sub-channel within a GUILD_ANNOUNCEMENT channel
-    AnnouncementThread = 10,
+    AnnouncementThread,
 
     /// a temporary sub-channel within a GUILD_TEXT or GUILD_FORUM channel
-    PublicThread = 11,
+    PublicThread,
 
     /// a temporary sub-channel within a GUILD_TEXT channel that is only viewable by those invited and those with the MANAGE_THREADS permission
-    PrivateThread = 12,
+    PrivateThread,
 
     /// a voice channel for [hosting events with an audience](https://support.discord.com/hc/en-us/articles/1500005513722)
-    GuildStageVoice = 13,
+    GuildStageVoice,
 
     /// the channel in a [hub](https://support.discord.com/hc/en-us/articles/4406046651927-Discord-Student-Hubs-FAQ) containing the listed servers
-    GuildDirectory = 14,
+    GuildDirectory,
 
     /// Channel that can only contain threads
-    GuildForum = 15,
+    GuildForum,
+
+    /// a type this version doesn't know about, holding the number Discord sent
+    Unknown(u8),
+}
+
+impl ChannelType {
+    /// The type's number as Discord sends it
+    pub fn to_u8(self) -> u8 {
+        match self {
+            ChannelType::GuildText => 0,
+            ChannelType::Dm => 1,
+            ChannelType::GuildVoice => 2,
+            ChannelType::GroupDm => 3,
+            ChannelType::GuildCategory => 4,
+            ChannelType::GuildAnnouncement => 5,
+            ChannelType::AnnouncementThread => 10,
+            ChannelType::PublicThread => 11,
+            ChannelType::PrivateThread => 12,
+            ChannelType::GuildStageVoice => 13,
+            ChannelType::GuildDirectory => 14,
+            ChannelType::GuildForum => 15,
+            ChannelType::Unknown(t) => t,
+        }
+    }
+
+    /// text based guild channels, including announcement channels
+    pub fn is_text(&self) -> bool {
+        matches!(
+            self,
+            ChannelType::GuildText | ChannelType::GuildAnnouncement
+        )
+    }
+
+    /// voice and stage channels
+    pub fn is_voice(&self) -> bool {
+        matches!(self, ChannelType::GuildVoice | ChannelType::GuildStageVoice)
+    }
+
+    pub fn is_thread(&self) -> bool {
+        matches!(
+            self,
+            ChannelType::AnnouncementThread
+                | ChannelType::PublicThread
+                | ChannelType::PrivateThread
+        )
+    }
+
+    pub fn is_category(&self) -> bool {
+        *self == ChannelType::GuildCategory
+    }
+}
+
+impl From<u8> for ChannelType {
+    fn from(t: u8) -> Self {
+        match t {
+            0 => ChannelType::GuildText,
+            1 => ChannelType::Dm,
+            2 => ChannelType::GuildVoice,
+            3 => ChannelType::GroupDm,
+            4 => ChannelType::GuildCategory,
+            5 => ChannelType::GuildAnnouncement,
+            10 => ChannelType::AnnouncementThread,
+            11 => ChannelType::PublicThread,
+            12 => ChannelType::PrivateThread,
+            13 => ChannelType::GuildStageVoice,
+            14 => ChannelType::GuildDirectory,
+            15 => ChannelType::GuildForum,
+            t => ChannelType::Unknown(t),
+        }
+    }
+}
+
+impl Serialize for ChannelType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(self.to_u8())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChannelType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        u8::deserialize(deserializer).map(ChannelType::from)
+    }
 }
 
 /// [Video Quality Modes](https://discord.com/developers/docs/resources/channel#channel-object-video-quality-modes)
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
 pub enum VideoQualityMode {
     /// Discord chooses the quality for optimal performance
     Auto = 1,
@@ -243,7 +458,8 @@ impl<'de> Deserialize<'de> for ChannelFlags {
 }
 
 /// [Sort Order Types](https://discord.com/developers/docs/resources/channel#channel-object-sort-order-types)
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
 pub enum SortOrderType {
     /// Sort forum posts by activity
     LatestActivity = 0,
@@ -253,7 +469,8 @@ pub enum SortOrderType {
 }
 
 /// [Forum Layout Types](https://discord.com/developers/docs/resources/channel#channel-object-forum-layout-types)
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
 pub enum ForumLayoutType {
     /// No default has been set for forum channel
     NotSet = 0,
@@ -266,7 +483,7 @@ pub enum ForumLayoutType {
 }
 
 /// [Overwrite Object](https://discord.com/developers/docs/resources/channel#overwrite-object)
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Overwrite {
     /// role or user id
     pub id: Snowflake,
@@ -282,13 +499,35 @@ pub struct Overwrite {
     pub deny: Permissions,
 }
 
-#[derive(Debug, Deserialize_repr)]
+#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq, Eq, Clone, Copy)]
 #[repr(u8)]
 pub enum OverwriteType {
     Role = 0,
     Member = 1,
 }
 
+impl Overwrite {
+    /// Overwrite for everyone with the role, `@everyone`'s role id is the guild id
+    pub fn role(id: Snowflake, allow: Permissions, deny: Permissions) -> Self {
+        Overwrite {
+            id,
+            t: OverwriteType::Role,
+            allow,
+            deny,
+        }
+    }
+
+    /// Overwrite for a single member
+    pub fn member(id: Snowflake, allow: Permissions, deny: Permissions) -> Self {
+        Overwrite {
+            id,
+            t: OverwriteType::Member,
+            allow,
+            deny,
+        }
+    }
+}
+
 /// [Thread Metadata Object](https://discord.com/developers/docs/resources/channel#thread-metadata-object)
 #[derive(Debug, Deserialize)]
 pub struct ThreadMetadata {
@@ -362,7 +601,7 @@ impl PartialEq for ForumTag {
 }
 
 /// [Default Reaction Object](https://discord.com/developers/docs/resources/channel#default-reaction-object)
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct DefaultReaction {
     /// the id of a guild's custom emoji
     pub emoji_id: Option<Snowflake>,
@@ -390,6 +629,35 @@ mod tests {
         assert_eq!(channel_type, ChannelType::GuildText);
     }
 
+    #[test]
+    pub fn unknown_channel_type() {
+        let channel = serde_json::from_str::<Channel>(
+            r#"{
+                "id": "941169456686723122",
+                "type": 99,
+                "guild_id": "798662131062931547",
+                "name": "new-kind",
+                "nsfw": false,
+                "parent_id": "798662131678969866"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(channel.t, ChannelType::Unknown(99));
+        assert_eq!(channel.name.as_deref(), Some("new-kind"));
+        assert_eq!(
+            channel.parent_id,
+            Some(Snowflake::from_u64(798662131678969866))
+        );
+
+        assert_eq!(serde_json::to_string(&channel.t).unwrap(), "99");
+        assert!(!channel.t.is_text());
+
+        for t in 0..=u8::MAX {
+            assert_eq!(ChannelType::from(t).to_u8(), t);
+        }
+    }
+
     #[test]
     pub fn can_deserialize_channel() {
         let channel_json = r#"{
@@ -413,4 +681,121 @@ mod tests {
 
         println!("{:#?}", channel);
     }
+
+    #[test]
+    pub fn forum_and_voice_enums_are_numbers() {
+        let channel = serde_json::from_str::<Channel>(
+            r#"{
+                "id": "300000000000000004",
+                "type": 15,
+                "name": "help",
+                "default_sort_order": 1,
+                "default_forum_layout": 2,
+                "video_quality_mode": 2,
+                "available_tags": [],
+                "default_reaction_emoji": {"emoji_id": null, "emoji_name": "👍"}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            channel.default_sort_order,
+            Some(SortOrderType::CreationDate)
+        );
+        assert_eq!(
+            channel.default_forum_layout,
+            Some(ForumLayoutType::GalleryView)
+        );
+        assert_eq!(channel.video_quality_mode, Some(VideoQualityMode::Full));
+        assert_eq!(
+            serde_json::to_string(&SortOrderType::LatestActivity).unwrap(),
+            "0"
+        );
+    }
+
+    #[test]
+    pub fn overwrite_serializes() {
+        let overwrite = Overwrite::role(
+            Snowflake::from_u64(200000000000000001),
+            Permissions::empty(),
+            Permissions::ViewChannel,
+        );
+
+        assert_eq!(
+            serde_json::to_string(&overwrite).unwrap(),
+            r#"{"id":"200000000000000001","type":0,"allow":"0","deny":"1024"}"#
+        );
+    }
+
+    #[test]
+    pub fn compute_overwrites() {
+        let guild = Snowflake::from_u64(200000000000000001);
+        let member = Snowflake::from_u64(400000000000000001);
+        let mods = Snowflake::from_u64(500000000000000001);
+        let muted = Snowflake::from_u64(500000000000000002);
+
+        let mut channel = serde_json::from_str::<Channel>(
+            r#"{"id": "300000000000000001", "type": 0, "guild_id": "200000000000000001"}"#,
+        )
+        .unwrap();
+        channel.permission_overwrites = Some(vec![
+            Overwrite::role(
+                guild.clone(),
+                Permissions::empty(),
+                Permissions::ViewChannel | Permissions::SendMessages,
+            ),
+            Overwrite::role(
+                mods.clone(),
+                Permissions::ViewChannel | Permissions::SendMessages,
+                Permissions::empty(),
+            ),
+            Overwrite::role(
+                muted.clone(),
+                Permissions::empty(),
+                Permissions::SendMessages,
+            ),
+        ]);
+
+        let base = Permissions::ViewChannel | Permissions::SendMessages | Permissions::AddReactions;
+
+        assert!(channel.overwrite_for(&guild).is_some());
+        assert!(channel.overwrite_for(&member).is_none());
+
+        let cases = [
+            (vec![], Permissions::AddReactions),
+            (vec![mods.clone()], base.clone()),
+            // role allows win over role denies
+            (vec![mods.clone(), muted.clone()], base.clone()),
+            (vec![muted], Permissions::AddReactions),
+        ];
+
+        for (roles, expected) in cases {
+            assert_eq!(
+                channel.compute_overwrites(base.clone(), &member, &roles),
+                expected,
+                "{:?}",
+                roles
+            );
+        }
+
+        // the member's own overwrite is applied last
+        channel
+            .permission_overwrites
+            .as_mut()
+            .unwrap()
+            .push(Overwrite::member(
+                member.clone(),
+                Permissions::empty(),
+                Permissions::ViewChannel,
+            ));
+        assert_eq!(
+            channel.compute_overwrites(base, &member, &[mods]),
+            Permissions::SendMessages | Permissions::AddReactions
+        );
+
+        assert_eq!(
+            channel.compute_overwrites(Permissions::Administrator, &member, &[]),
+            Permissions::all_known()
+        );
+    }
 }
@@ -0,0 +1,46 @@
+use serde::Deserialize;
+use serde_repr::Deserialize_repr;
+
+/// [Connection Structure](https://discord.com/developers/docs/resources/user#connection-object-connection-structure),
+/// an account the user has linked, requires the `connections` OAuth2 scope
+#[derive(Debug, Deserialize)]
+pub struct Connection {
+    /// id of the connection account
+    pub id: String,
+
+    /// the username of the connection account
+    pub name: String,
+
+    /// the [service](https://discord.com/developers/docs/resources/user#connection-object-services) of this connection
+    #[serde(rename = "type")]
+    pub t: String,
+
+    /// whether the connection is revoked
+    pub revoked: Option<bool>,
+
+    /// whether the connection is verified
+    pub verified: bool,
+
+    /// whether friend sync is enabled for this connection
+    pub friend_sync: bool,
+
+    /// whether activities related to this connection will be shown in presence updates
+    pub show_activity: bool,
+
+    /// whether this connection has a corresponding third party OAuth2 token
+    pub two_way_link: bool,
+
+    /// [visibility](https://discord.com/developers/docs/resources/user#connection-object-visibility-types) of this connection
+    pub visibility: ConnectionVisibility,
+}
+
+/// [Visibility Types](https://discord.com/developers/docs/resources/user#connection-object-visibility-types)
+#[derive(Debug, Deserialize_repr, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum ConnectionVisibility {
+    /// invisible to everyone except the user themselves
+    None = 0,
+
+    /// visible to everyone
+    Everyone = 1,
+}
@@ -0,0 +1,79 @@
+use std::fmt;
+
+use serde::Deserialize;
+
+use crate::{models::Snowflake, util::Redacted};
+
+/// The few fields of an interaction needed to route and answer it, for handlers bringing their
+/// own models
+///
+/// [parse](Self::parse) skips every other field without building it, so payloads the
+/// [Interaction](crate::models::Interaction) models can't read, like new interaction types,
+/// still parse. Debug printing shortens `token`, see [Redacted].
+#[derive(Deserialize, Clone, PartialEq, Eq)]
+pub struct InteractionEnvelope {
+    /// [Interaction Type](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-type),
+    /// kept as a number so types added after [InteractionType](crate::models::InteractionType)
+    /// still parse
+    #[serde(rename = "type")]
+    pub t: u8,
+
+    /// ID of the interaction
+    pub id: Snowflake,
+
+    /// Continuation token for responding to the interaction
+    pub token: String,
+
+    /// ID of the application this interaction is for
+    pub application_id: Snowflake,
+}
+
+impl InteractionEnvelope {
+    /// Reads the envelope out of an interaction's JSON body
+    pub fn parse(body: &[u8]) -> serde_json::Result<Self> {
+        serde_json::from_slice(body)
+    }
+
+    pub fn is_ping(&self) -> bool {
+        self.t == 1
+    }
+}
+
+impl fmt::Debug for InteractionEnvelope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InteractionEnvelope")
+            .field("t", &self.t)
+            .field("id", &self.id)
+            .field("token", &Redacted(&self.token))
+            .field("application_id", &self.application_id)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn parse() {
+        let body = br#"{
+            "type": 99,
+            "id": "700000000000000001",
+            "application_id": "100000000000000001",
+            "token": "A_UNIQUE_TOKEN",
+            "version": 1,
+            "data": {"shape": ["not", {"known": null}]}
+        }"#;
+
+        let envelope = InteractionEnvelope::parse(body).unwrap();
+
+        assert_eq!(envelope.t, 99);
+        assert!(!envelope.is_ping());
+        assert_eq!(envelope.id.to_u64(), 700000000000000001);
+        assert_eq!(envelope.application_id.to_u64(), 100000000000000001);
+        assert_eq!(envelope.token, "A_UNIQUE_TOKEN");
+
+        assert!(InteractionEnvelope::parse(br#"{"type": 1, "id": "1"}"#).is_err());
+        assert!(InteractionEnvelope::parse(b"not json").is_err());
+    }
+}
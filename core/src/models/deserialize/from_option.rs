@@ -0,0 +1,115 @@
+use crate::models::{ApplicationCommandInteractionDataOption, OptionList, Snowflake};
+
+/// A type that can be read out of a command option's value
+pub trait FromOption: Sized {
+    /// `None` if the option holds a different type
+    fn from_option(option: &ApplicationCommandInteractionDataOption) -> Option<Self>;
+}
+
+impl FromOption for String {
+    fn from_option(option: &ApplicationCommandInteractionDataOption) -> Option<Self> {
+        match option {
+            ApplicationCommandInteractionDataOption::String(o) => Some(o.value.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromOption for i64 {
+    fn from_option(option: &ApplicationCommandInteractionDataOption) -> Option<Self> {
+        match option {
+            ApplicationCommandInteractionDataOption::Integer(o) => Some(o.value),
+            _ => None,
+        }
+    }
+}
+
+impl FromOption for bool {
+    fn from_option(option: &ApplicationCommandInteractionDataOption) -> Option<Self> {
+        match option {
+            ApplicationCommandInteractionDataOption::Boolean(o) => Some(o.value),
+            _ => None,
+        }
+    }
+}
+
+impl FromOption for f64 {
+    fn from_option(option: &ApplicationCommandInteractionDataOption) -> Option<Self> {
+        match option {
+            ApplicationCommandInteractionDataOption::Number(o) => Some(o.value),
+            _ => None,
+        }
+    }
+}
+
+/// The id held by a user, channel, role or mentionable option
+impl FromOption for Snowflake {
+    fn from_option(option: &ApplicationCommandInteractionDataOption) -> Option<Self> {
+        match option {
+            ApplicationCommandInteractionDataOption::User(o)
+            | ApplicationCommandInteractionDataOption::Channel(o)
+            | ApplicationCommandInteractionDataOption::Role(o)
+            | ApplicationCommandInteractionDataOption::Mentionable(o) => Some(o.value.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Typed access to options by name, `opts.get::<i64>("count")`
+pub trait FromOptions {
+    /// Value of the option called `name`, `None` if it is missing or not a `T`
+    fn get<T: FromOption>(&self, name: &str) -> Option<T>;
+}
+
+impl FromOptions for OptionList {
+    fn get<T: FromOption>(&self, name: &str) -> Option<T> {
+        self.get_option(name).and_then(T::from_option)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> OptionList {
+        serde_json::from_str(
+            r#"[
+                {"type": 3, "name": "text", "value": "hello"},
+                {"type": 4, "name": "count", "value": 3},
+                {"type": 5, "name": "flag", "value": true},
+                {"type": 10, "name": "ratio", "value": 0.5},
+                {"type": 6, "name": "user", "value": "400000000000000001"},
+                {"type": 7, "name": "channel", "value": "300000000000000001"}
+            ]"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    pub fn get_primitives() {
+        let options = options();
+
+        assert_eq!(options.get::<String>("text").as_deref(), Some("hello"));
+        assert_eq!(options.get::<i64>("count"), Some(3));
+        assert_eq!(options.get::<bool>("flag"), Some(true));
+        assert_eq!(options.get::<f64>("ratio"), Some(0.5));
+        assert_eq!(
+            options.get::<Snowflake>("user"),
+            Some(Snowflake::from_u64(400000000000000001))
+        );
+        assert_eq!(
+            options.get::<Snowflake>("channel"),
+            Some(Snowflake::from_u64(300000000000000001))
+        );
+    }
+
+    #[test]
+    pub fn get_wrong_type_or_missing() {
+        let options = options();
+
+        assert_eq!(options.get::<i64>("text"), None);
+        assert_eq!(options.get::<String>("count"), None);
+        assert_eq!(options.get::<Snowflake>("flag"), None);
+        assert_eq!(options.get::<bool>("missing"), None);
+    }
+}
@@ -0,0 +1,412 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::models::{Permissions, Role, Snowflake, User};
+
+/// [Guild Structure](https://discord.com/developers/docs/resources/guild#guild-object-guild-structure)
+#[derive(Debug, Deserialize)]
+pub struct Guild {
+    /// guild id
+    pub id: Snowflake,
+
+    /// guild name (2-100 characters, excluding trailing and leading whitespace)
+    pub name: String,
+
+    /// [icon hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub icon: Option<String>,
+
+    /// [splash hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub splash: Option<String>,
+
+    /// id of owner
+    pub owner_id: Snowflake,
+
+    /// id of afk channel
+    pub afk_channel_id: Option<Snowflake>,
+
+    /// afk timeout in seconds
+    pub afk_timeout: u32,
+
+    /// verification level required for the guild
+    pub verification_level: VerificationLevel,
+
+    /// roles in the guild
+    pub roles: Vec<Role>,
+
+    /// enabled [guild features](https://discord.com/developers/docs/resources/guild#guild-object-guild-features)
+    pub features: Vec<String>,
+
+    /// the id of the channel where guild notices such as welcome messages and boost events are posted
+    pub system_channel_id: Option<Snowflake>,
+
+    /// the id of the channel where Community guilds can display rules and/or guidelines
+    pub rules_channel_id: Option<Snowflake>,
+
+    /// the vanity url code for the guild
+    pub vanity_url_code: Option<String>,
+
+    /// the description of a guild
+    pub description: Option<String>,
+
+    /// [banner hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub banner: Option<String>,
+
+    /// server boost level
+    pub premium_tier: PremiumTier,
+
+    /// the number of boosts this guild currently has
+    pub premium_subscription_count: Option<u32>,
+
+    /// the preferred locale of a Community guild, defaults to "en-US"
+    pub preferred_locale: String,
+
+    /// approximate number of members in this guild, returned when `with_counts` is true
+    pub approximate_member_count: Option<u32>,
+
+    /// approximate number of non-offline members in this guild, returned when `with_counts` is true
+    pub approximate_presence_count: Option<u32>,
+}
+
+/// [Verification Level](https://discord.com/developers/docs/resources/guild#guild-object-verification-level)
+#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum VerificationLevel {
+    /// unrestricted
+    None = 0,
+
+    /// must have verified email on account
+    Low = 1,
+
+    /// must be registered on Discord for longer than 5 minutes
+    Medium = 2,
+
+    /// must be a member of the server for longer than 10 minutes
+    High = 3,
+
+    /// must have a verified phone number
+    VeryHigh = 4,
+}
+
+/// [Premium Tier](https://discord.com/developers/docs/resources/guild#guild-object-premium-tier)
+#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum PremiumTier {
+    /// guild has not unlocked any Server Boost perks
+    None = 0,
+
+    /// guild has unlocked Server Boost level 1 perks
+    Tier1 = 1,
+
+    /// guild has unlocked Server Boost level 2 perks
+    Tier2 = 2,
+
+    /// guild has unlocked Server Boost level 3 perks
+    Tier3 = 3,
+}
+
+/// Guild as returned by [Get Current User Guilds](https://discord.com/developers/docs/resources/user#get-current-user-guilds)
+#[derive(Debug, Deserialize)]
+pub struct PartialGuild {
+    /// guild id
+    pub id: Snowflake,
+
+    /// guild name (2-100 characters, excluding trailing and leading whitespace)
+    pub name: String,
+
+    /// [icon hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub icon: Option<String>,
+
+    /// true if the user is the owner of the guild
+    pub owner: bool,
+
+    /// total permissions for the user in the guild (excludes overwrites)
+    pub permissions: Permissions,
+
+    /// enabled [guild features](https://discord.com/developers/docs/resources/guild#guild-object-guild-features)
+    pub features: Vec<String>,
+
+    /// approximate number of members in this guild, returned when `with_counts` is true
+    pub approximate_member_count: Option<u32>,
+
+    /// approximate number of non-offline members in this guild, returned when `with_counts` is true
+    pub approximate_presence_count: Option<u32>,
+}
+
+/// [Guild Widget Settings](https://discord.com/developers/docs/resources/guild#guild-widget-settings-object)
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GuildWidgetSettings {
+    /// whether the widget is enabled
+    pub enabled: bool,
+
+    /// the widget channel id
+    pub channel_id: Option<Snowflake>,
+}
+
+/// [Guild Widget](https://discord.com/developers/docs/resources/guild#guild-widget-object), the
+/// public JSON shown by embeds of the server
+#[derive(Debug, Deserialize)]
+pub struct GuildWidget {
+    /// guild id
+    pub id: Snowflake,
+
+    /// guild name (2-100 characters)
+    pub name: String,
+
+    /// instant invite for the guilds specified widget invite channel
+    pub instant_invite: Option<String>,
+
+    /// voice and stage channels which are accessible by @everyone
+    pub channels: Vec<WidgetChannel>,
+
+    /// special widget user objects that includes users presence (Limit 100)
+    pub members: Vec<WidgetMember>,
+
+    /// number of online members in this guild
+    pub presence_count: u32,
+}
+
+/// Channel as listed in a [GuildWidget]
+#[derive(Debug, Deserialize)]
+pub struct WidgetChannel {
+    /// channel id
+    pub id: Snowflake,
+
+    /// channel name
+    pub name: String,
+
+    /// sorting position of the channel
+    pub position: i32,
+}
+
+/// Online member as listed in a [GuildWidget]
+///
+/// Widget members are anonymized, their `id` is an index rather than the user's id so it is not
+/// kept here.
+#[derive(Debug, Deserialize)]
+pub struct WidgetMember {
+    /// the user's username
+    pub username: String,
+
+    /// always `"0000"` in widgets
+    pub discriminator: String,
+
+    /// always null in widgets, use `avatar_url`
+    pub avatar: Option<String>,
+
+    /// presence status, `online`, `idle` or `dnd`
+    pub status: String,
+
+    /// url of the user's avatar served through the widget
+    pub avatar_url: String,
+
+    /// game or stream the member is playing
+    pub activity: Option<WidgetActivity>,
+
+    /// voice channel the member is connected to
+    pub channel_id: Option<Snowflake>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WidgetActivity {
+    /// name of the activity
+    pub name: String,
+}
+
+/// [Guild Vanity Url](https://discord.com/developers/docs/resources/guild#get-guild-vanity-url)
+#[derive(Debug, Deserialize)]
+pub struct GuildVanityUrl {
+    /// vanity invite code, null when the guild has none set
+    pub code: Option<String>,
+
+    /// number of times the vanity invite has been used
+    pub uses: u32,
+}
+
+/// [Guild Template](https://discord.com/developers/docs/resources/guild-template#guild-template-object)
+#[derive(Debug, Deserialize)]
+pub struct GuildTemplate {
+    /// the template code (unique ID)
+    pub code: String,
+
+    /// template name
+    pub name: String,
+
+    /// the description for the template
+    pub description: Option<String>,
+
+    /// number of times this template has been used
+    pub usage_count: u32,
+
+    /// the ID of the user who created the template
+    pub creator_id: Snowflake,
+
+    /// the user who created the template
+    pub creator: User,
+
+    /// when this template was created, ISO8601 timestamp
+    pub created_at: String,
+
+    /// when this template was last synced to the source guild, ISO8601 timestamp
+    pub updated_at: String,
+
+    /// the ID of the guild this template is based on
+    pub source_guild_id: Snowflake,
+
+    /// the guild snapshot this template contains, roles and channels included
+    pub serialized_source_guild: Value,
+
+    /// whether the template has unsynced changes
+    pub is_dirty: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn guild_widget() {
+        let json = r#"{
+            "id": "290926798626357250",
+            "name": "Test Server",
+            "instant_invite": "https://discord.com/invite/abcdef",
+            "channels": [
+                {"id": "705216630279993882", "name": "Voice", "position": 0}
+            ],
+            "members": [
+                {
+                    "id": "0",
+                    "username": "1234",
+                    "discriminator": "0000",
+                    "avatar": null,
+                    "status": "online",
+                    "avatar_url": "https://cdn.discordapp.com/widget-avatars/FfvURgcr3Za92K3JtoCppqnYMppMDc5B-Rll74YrGCU/C-1DyBZPQ6t5q2RuATFuMFgq0_uEMZVzd_6LbJR4stMMchx12p4ja_SSe6kLCAvBO_o",
+                    "activity": {"name": "Some Game"}
+                },
+                {
+                    "id": "1",
+                    "username": "other",
+                    "discriminator": "0000",
+                    "avatar": null,
+                    "status": "idle",
+                    "avatar_url": "https://cdn.discordapp.com/widget-avatars/abc/def",
+                    "channel_id": "705216630279993882",
+                    "deaf": false,
+                    "mute": false,
+                    "self_deaf": false,
+                    "self_mute": true,
+                    "suppress": false
+                }
+            ],
+            "presence_count": 2
+        }"#;
+
+        let widget: GuildWidget = serde_json::from_str(json).unwrap();
+
+        assert_eq!(widget.id, Snowflake::from_u64(290926798626357250));
+        assert_eq!(widget.presence_count, 2);
+        assert_eq!(widget.channels[0].name, "Voice");
+        assert_eq!(widget.members.len(), 2);
+        assert_eq!(
+            widget.members[0].activity.as_ref().unwrap().name,
+            "Some Game"
+        );
+        assert_eq!(
+            widget.members[1].channel_id,
+            Some(Snowflake::from_u64(705216630279993882))
+        );
+    }
+
+    #[test]
+    pub fn guild_widget_no_invite() {
+        let json = r#"{
+            "id": "290926798626357250",
+            "name": "Test Server",
+            "instant_invite": null,
+            "channels": [],
+            "members": [],
+            "presence_count": 0
+        }"#;
+
+        let widget: GuildWidget = serde_json::from_str(json).unwrap();
+
+        assert!(widget.instant_invite.is_none());
+        assert!(widget.members.is_empty());
+    }
+
+    #[test]
+    pub fn guild_template() {
+        let json = r#"{
+            "code": "hgM48av5Q69A",
+            "name": "Friends & Family",
+            "description": "",
+            "usage_count": 49605,
+            "creator_id": "132837293881950208",
+            "creator": {
+                "id": "132837293881950208",
+                "username": "hoges",
+                "avatar": "79b0d7f8e0f4ff9f4d5c8a5c4a5b6a2d",
+                "discriminator": "0001",
+                "public_flags": 131072
+            },
+            "created_at": "2020-04-02T21:10:38+00:00",
+            "updated_at": "2020-05-01T17:57:38+00:00",
+            "source_guild_id": "678070694164299796",
+            "serialized_source_guild": {
+                "name": "Friends & Family",
+                "description": null,
+                "region": "us-west",
+                "verification_level": 0,
+                "default_message_notifications": 0,
+                "explicit_content_filter": 0,
+                "preferred_locale": "en-US",
+                "afk_timeout": 300,
+                "roles": [
+                    {
+                        "id": 0,
+                        "name": "@everyone",
+                        "permissions": 104324689,
+                        "color": 0,
+                        "hoist": false,
+                        "mentionable": false
+                    }
+                ],
+                "channels": [
+                    {
+                        "name": "Text Channels",
+                        "position": 1,
+                        "topic": null,
+                        "bitrate": 64000,
+                        "user_limit": 0,
+                        "nsfw": false,
+                        "rate_limit_per_user": 0,
+                        "parent_id": null,
+                        "permission_overwrites": [],
+                        "id": 1,
+                        "type": 4
+                    }
+                ],
+                "afk_channel_id": null,
+                "system_channel_id": 2,
+                "system_channel_flags": 0,
+                "icon_hash": null
+            },
+            "is_dirty": null
+        }"#;
+
+        let template: GuildTemplate = serde_json::from_str(json).unwrap();
+
+        assert_eq!(template.code, "hgM48av5Q69A");
+        assert_eq!(template.usage_count, 49605);
+        assert_eq!(template.creator.username, "hoges");
+        assert_eq!(
+            template.source_guild_id,
+            Snowflake::from_u64(678070694164299796)
+        );
+        assert_eq!(
+            template.serialized_source_guild["roles"][0]["name"],
+            "@everyone"
+        );
+        assert!(template.is_dirty.is_none());
+    }
+}
@@ -0,0 +1,190 @@
+use serde::Deserialize;
+use serde_repr::Deserialize_repr;
+
+use crate::models::{Snowflake, User};
+
+/// [Integration Structure](https://discord.com/developers/docs/resources/guild#integration-object-integration-structure)
+#[derive(Debug, Deserialize)]
+pub struct Integration {
+    /// integration id
+    pub id: Snowflake,
+
+    /// integration name
+    pub name: String,
+
+    /// integration type (`twitch`, `youtube`, `discord`, or `guild_subscription`)
+    #[serde(rename = "type")]
+    pub t: String,
+
+    /// is this integration enabled
+    pub enabled: bool,
+
+    /// is this integration syncing, not provided for discord bot integrations
+    pub syncing: Option<bool>,
+
+    /// id that this integration uses for "subscribers", not provided for discord bot integrations
+    pub role_id: Option<Snowflake>,
+
+    /// whether emoticons should be synced for this integration (twitch only currently), not provided for discord bot integrations
+    pub enable_emoticons: Option<bool>,
+
+    /// the behavior of expiring subscribers, not provided for discord bot integrations
+    pub expire_behavior: Option<IntegrationExpireBehavior>,
+
+    /// the grace period (in days) before expiring subscribers, not provided for discord bot integrations
+    pub expire_grace_period: Option<u32>,
+
+    /// user for this integration, some older integrations may not have an attached user
+    pub user: Option<User>,
+
+    /// integration account information
+    pub account: IntegrationAccount,
+
+    /// when this integration was last synced, not provided for discord bot integrations
+    pub synced_at: Option<String>,
+
+    /// how many subscribers this integration has, not provided for discord bot integrations
+    pub subscriber_count: Option<u32>,
+
+    /// has this integration been revoked, not provided for discord bot integrations
+    pub revoked: Option<bool>,
+
+    /// The bot/OAuth2 application for discord integrations
+    pub application: Option<IntegrationApplication>,
+
+    /// the scopes the application has been authorized for
+    pub scopes: Option<Vec<String>>,
+}
+
+/// [Integration Expire Behaviors](https://discord.com/developers/docs/resources/guild#integration-object-integration-expire-behaviors)
+#[derive(Debug, Deserialize_repr, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum IntegrationExpireBehavior {
+    RemoveRole = 0,
+    Kick = 1,
+}
+
+/// [Integration Account Structure](https://discord.com/developers/docs/resources/guild#integration-account-object-integration-account-structure)
+#[derive(Debug, Deserialize)]
+pub struct IntegrationAccount {
+    /// id of the account, not a snowflake for third party services
+    pub id: String,
+
+    /// name of the account
+    pub name: String,
+}
+
+/// [Integration Application Structure](https://discord.com/developers/docs/resources/guild#integration-application-object-integration-application-structure)
+#[derive(Debug, Deserialize)]
+pub struct IntegrationApplication {
+    /// the id of the app
+    pub id: Snowflake,
+
+    /// the name of the app
+    pub name: String,
+
+    /// the [icon hash](https://discord.com/developers/docs/reference#image-formatting) of the app
+    pub icon: Option<String>,
+
+    /// the description of the app
+    pub description: String,
+
+    /// the bot associated with this application
+    pub bot: Option<User>,
+}
+
+impl Integration {
+    /// Whether this is a bot or OAuth2 application added to the guild
+    pub fn is_bot(&self) -> bool {
+        self.t == "discord"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn twitch_integration() {
+        let json = r#"{
+            "id": "600000000000000010",
+            "name": "streamer",
+            "type": "twitch",
+            "enabled": true,
+            "syncing": false,
+            "role_id": "500000000000000010",
+            "enable_emoticons": true,
+            "expire_behavior": 1,
+            "expire_grace_period": 7,
+            "user": {
+                "id": "400000000000000001",
+                "username": "user",
+                "avatar": null,
+                "discriminator": "0",
+                "public_flags": 0
+            },
+            "account": {"id": "123456789", "name": "streamer"},
+            "synced_at": "2023-01-01T00:00:00.000000+00:00",
+            "subscriber_count": 12,
+            "revoked": false
+        }"#;
+
+        let integration: Integration = serde_json::from_str(json).unwrap();
+
+        assert!(!integration.is_bot());
+        assert_eq!(integration.account.id, "123456789");
+        assert_eq!(
+            integration.expire_behavior,
+            Some(IntegrationExpireBehavior::Kick)
+        );
+        assert_eq!(integration.expire_grace_period, Some(7));
+        assert!(integration.application.is_none());
+    }
+
+    #[test]
+    pub fn bot_integration() {
+        let json = r#"{
+            "id": "600000000000000011",
+            "name": "Composure",
+            "type": "discord",
+            "enabled": true,
+            "account": {"id": "100000000000000001", "name": "Composure"},
+            "application": {
+                "id": "100000000000000001",
+                "name": "Composure",
+                "icon": null,
+                "description": "",
+                "bot": {
+                    "id": "100000000000000001",
+                    "username": "Composure",
+                    "avatar": null,
+                    "discriminator": "0",
+                    "public_flags": 0,
+                    "bot": true
+                }
+            },
+            "scopes": ["bot", "applications.commands"],
+            "user": {
+                "id": "400000000000000001",
+                "username": "user",
+                "avatar": null,
+                "discriminator": "0",
+                "public_flags": 0
+            }
+        }"#;
+
+        let integration: Integration = serde_json::from_str(json).unwrap();
+
+        assert!(integration.is_bot());
+        assert!(integration.syncing.is_none());
+        assert!(integration.expire_behavior.is_none());
+
+        let application = integration.application.unwrap();
+        assert_eq!(application.name, "Composure");
+        assert_eq!(application.bot.unwrap().username, "Composure");
+        assert_eq!(
+            integration.scopes.unwrap(),
+            ["bot", "applications.commands"]
+        );
+    }
+}
@@ -0,0 +1,1994 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use once_cell::sync::OnceCell;
+use serde::{de::DeserializeOwned, ser::SerializeMap, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::models::{
+    ActionRow, Attachment, Buffered, Channel, ChannelType, InteractionResponse, Member, Message,
+    MessageCallbackData, MessageFlags, PartialChannel, PartialMember, Permissions, Role, Snowflake,
+    TypeField, User,
+};
+use crate::util::Redacted;
+
+pub type ApplicationCommandInteraction = DataInteraction<ApplicationCommandInteractionData>;
+pub type MessageComponentInteraction = DataInteraction<MessageComponentData>;
+pub type ModalSubmitInteraction = DataInteraction<ModalSubmitData>;
+
+/// [Interaction Structure](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-structure)
+#[derive(Debug)]
+pub enum Interaction {
+    Ping(PingInteraction),
+    ApplicationCommand(ApplicationCommandInteraction),
+    MessageComponent(MessageComponentInteraction),
+    ApplicationCommandAutocomplete(ApplicationCommandInteraction),
+    ModalSubmit(ModalSubmitInteraction),
+}
+
+impl<'de> Deserialize<'de> for Interaction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Buffered::deserialize(deserializer)?;
+
+        let t = value
+            .get("type")
+            .and_then(Value::as_u64)
+            .ok_or(serde::de::Error::missing_field("type"))?;
+
+        match t {
+            // Ping
+            1 => Ok(Interaction::Ping(
+                value.deserialize_into().map_err(serde::de::Error::custom)?,
+            )),
+            // Application Command
+            2 => Ok(Interaction::ApplicationCommand(
+                value
+                    .deserialize_into()
+                    .map_err(|e| serde::de::Error::custom(e))?,
+            )),
+            // Message Component
+            3 => Ok(Interaction::MessageComponent(
+                value
+                    .deserialize_into()
+                    .map_err(|e| serde::de::Error::custom(e))?,
+            )),
+            // Autocomplete
+            4 => Ok(Interaction::ApplicationCommandAutocomplete(
+                value
+                    .deserialize_into()
+                    .map_err(|e| serde::de::Error::custom(e))?,
+            )),
+            // Modal Submit
+            5 => Ok(Interaction::ModalSubmit(
+                value
+                    .deserialize_into()
+                    .map_err(|e| serde::de::Error::custom(e))?,
+            )),
+            _ => Err(serde::de::Error::custom("Unknown interaction")),
+        }
+    }
+}
+
+impl Interaction {
+    /// Fields shared by every kind of interaction
+    pub fn common(&self) -> &InteractionCommon {
+        match self {
+            Interaction::Ping(ping) => &ping.common,
+            Interaction::ApplicationCommand(command) => &command.common,
+            Interaction::MessageComponent(component) => &component.common,
+            Interaction::ApplicationCommandAutocomplete(command) => &command.common,
+            Interaction::ModalSubmit(modal) => &modal.common,
+        }
+    }
+}
+
+/// [Interaction Type](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-type)
+#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq, Eq, Hash, Clone, Copy)]
+#[repr(u8)]
+pub enum InteractionType {
+    Ping = 1,
+    ApplicationCommand = 2,
+    MessageComponent = 3,
+    ApplicationCommandAutocomplete = 4,
+    ModalSubmit = 5,
+}
+
+/// Debug printing shortens `token`, see [Redacted]
+#[derive(Deserialize)]
+pub struct InteractionCommon {
+    /// ID of the interaction
+    pub id: Snowflake,
+
+    /// ID of the application this interaction is for
+    pub application_id: Snowflake,
+
+    /// Guild that the interaction was sent from
+    pub guild_id: Option<Snowflake>,
+
+    /// Channel that the interaction was sent from
+    pub channel: Option<Channel>,
+
+    /// Channel that the interaction was sent from
+    pub channel_id: Option<Snowflake>,
+
+    /// Guild member data for the invoking user, including permissions
+    pub member: Option<Member>,
+
+    /// User object for the invoking user, if invoked in a DM
+    pub user: Option<User>,
+
+    /// Continuation token for responding to the interaction
+    pub token: String,
+
+    /// Read-only property, always 1
+    pub version: u8,
+
+    /// For components, the message they were attached to
+    pub message: Option<Message>,
+
+    /// Bitwise set of permissions the app or bot has within the channel the interaction was sent from
+    pub app_permissions: Option<Permissions>,
+
+    /// [Guild's preferred locale](https://discord.com/developers/docs/resources/guild#guild-object), if invoked in a guild
+    pub guild_locale: Option<String>,
+
+    /// Context where the interaction was triggered from
+    pub context: Option<InteractionContextType>,
+
+    /// Installations that authorized the interaction, see [InteractionCommon::install_context]
+    pub authorizing_integration_owners: Option<AuthorizingIntegrationOwners>,
+}
+
+impl fmt::Debug for InteractionCommon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InteractionCommon")
+            .field("id", &self.id)
+            .field("application_id", &self.application_id)
+            .field("guild_id", &self.guild_id)
+            .field("channel", &self.channel)
+            .field("channel_id", &self.channel_id)
+            .field("member", &self.member)
+            .field("user", &self.user)
+            .field("token", &Redacted(&self.token))
+            .field("version", &self.version)
+            .field("message", &self.message)
+            .field("app_permissions", &self.app_permissions)
+            .field("guild_locale", &self.guild_locale)
+            .field("context", &self.context)
+            .field(
+                "authorizing_integration_owners",
+                &self.authorizing_integration_owners,
+            )
+            .finish()
+    }
+}
+
+impl InteractionCommon {
+    /// true if the interaction was sent from a DM, where only `user` is present
+    pub fn is_dm(&self) -> bool {
+        self.guild_id.is_none() && self.user.is_some()
+    }
+
+    /// true if the interaction was sent from a guild
+    pub fn is_guild(&self) -> bool {
+        self.guild_id.is_some()
+    }
+
+    /// ID of the user who triggered the interaction, from `member` in guilds and `user` in DMs
+    pub fn invoker_id(&self) -> Option<&Snowflake> {
+        match (&self.member, &self.user) {
+            (Some(member), _) => Some(&member.user.id),
+            (None, Some(user)) => Some(&user.id),
+            (None, None) => None,
+        }
+    }
+
+    /// How the app was installed where the interaction came from
+    ///
+    /// A guild install wins when the app is installed both ways. Payloads without
+    /// `authorizing_integration_owners` predate user installs and are treated as guild installs.
+    pub fn install_context(&self) -> InstallContext {
+        let user_install_only = matches!(
+            &self.authorizing_integration_owners,
+            Some(AuthorizingIntegrationOwners {
+                guild_install: None,
+                user_install: Some(_),
+            })
+        );
+
+        if !user_install_only {
+            return InstallContext::GuildInstall;
+        }
+
+        let in_guild = match self.context {
+            Some(context) => context == InteractionContextType::Guild,
+            None => self.guild_id.is_some(),
+        };
+
+        if in_guild {
+            InstallContext::UserInstallInGuild
+        } else {
+            InstallContext::UserInstallInDm
+        }
+    }
+}
+
+/// [Interaction Context Types](https://discord.com/developers/docs/interactions/application-commands#interaction-contexts)
+#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq, Eq, Hash, Clone, Copy)]
+#[repr(u8)]
+pub enum InteractionContextType {
+    /// inside a guild
+    Guild = 0,
+
+    /// DM with the app's bot user
+    BotDm = 1,
+
+    /// group DMs and DMs other than the app's bot user
+    PrivateChannel = 2,
+}
+
+/// [Application Integration Types](https://discord.com/developers/docs/resources/application#application-object-application-integration-types),
+/// where an app can be installed
+#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq, Eq, Hash, Clone, Copy)]
+#[repr(u8)]
+pub enum ApplicationIntegrationType {
+    /// installed to a guild
+    GuildInstall = 0,
+
+    /// installed to a user
+    UserInstall = 1,
+}
+
+/// [Authorizing Integration Owners](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-authorizing-integration-owners-object)
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+pub struct AuthorizingIntegrationOwners {
+    /// Guild the app is installed to, `0` when used from a DM with the bot
+    #[serde(rename = "0")]
+    pub guild_install: Option<Snowflake>,
+
+    /// User who installed the app
+    #[serde(rename = "1")]
+    pub user_install: Option<Snowflake>,
+}
+
+/// Installation an interaction ran under, from [InteractionCommon::install_context]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum InstallContext {
+    /// the app is installed to the guild, or the interaction came from a DM with its bot
+    GuildInstall,
+
+    /// the app is only installed to the user, who ran it in a guild the app may not be in
+    UserInstallInGuild,
+
+    /// the app is only installed to the user, who ran it in a DM or group DM
+    UserInstallInDm,
+}
+
+#[derive(Debug)]
+pub struct PingInteraction {
+    pub common: InteractionCommon,
+}
+
+impl<'de> Deserialize<'de> for PingInteraction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut value = Buffered::deserialize(deserializer)?;
+
+        // consumed by the dispatch in `Interaction`
+        value.take("type");
+
+        Ok(PingInteraction {
+            common: value.deserialize_into().map_err(serde::de::Error::custom)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct DataInteraction<D> {
+    pub common: InteractionCommon,
+    pub locale: Option<String>,
+    pub data: D,
+}
+
+impl<'de, T> Deserialize<'de> for DataInteraction<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut value = Buffered::deserialize(deserializer)?;
+
+        // consumed by the dispatch in `Interaction`
+        value.take("type");
+
+        let locale = match value.take("locale") {
+            Some(locale) => locale
+                .deserialize_into()
+                .map_err(serde::de::Error::custom)?,
+            None => None,
+        };
+
+        let data = value
+            .take("data")
+            .ok_or(serde::de::Error::missing_field("data"))?
+            .deserialize_into()
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(DataInteraction {
+            common: value.deserialize_into().map_err(serde::de::Error::custom)?,
+            locale,
+            data,
+        })
+    }
+}
+
+/// [Interaction Data](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-data)
+#[derive(Debug, Deserialize)]
+pub struct ApplicationCommandInteractionData {
+    /// the [ID](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-structure) of the invoked command
+    pub id: Snowflake,
+
+    /// the [name](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-structure) of the invoked command
+    pub name: String,
+
+    /// the [type](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-structure) of the invoked command
+    #[serde(rename = "type")]
+    pub t: ApplicationCommandType,
+
+    /// converted users + roles + channels + attachments
+    pub resolved: Option<ResolvedData>,
+
+    /// the params + values from the user, empty when Discord leaves the key out
+    #[serde(default, deserialize_with = "options_or_empty")]
+    pub options: OptionList,
+
+    /// the id of the guild the command is registered to
+    pub guild_id: Option<Snowflake>,
+
+    /// id of the [user](https://discord.com/developers/docs/interactions/application-commands#user-commands) or [message](https://discord.com/developers/docs/interactions/application-commands#message-commands) targeted by a user or message command
+    pub target_id: Option<Snowflake>,
+}
+
+impl ApplicationCommandInteractionData {
+    pub fn resolved_user(&self, snowflake: &Snowflake) -> Option<&User> {
+        self.resolved
+            .as_ref()
+            .and_then(|r| r.users.as_ref())
+            .and_then(|u| u.get(snowflake))
+    }
+
+    pub fn resolved_member(&self, snowflake: &Snowflake) -> Option<&PartialMember> {
+        self.resolved
+            .as_ref()
+            .and_then(|r| r.members.as_ref())
+            .and_then(|u| u.get(snowflake))
+    }
+
+    /// The resolved member with their user, which Discord sends in a separate map
+    ///
+    /// `None` if either is missing, like outside of guilds where only the user is resolved.
+    pub fn resolved_member_with_user(
+        &self,
+        snowflake: &Snowflake,
+    ) -> Option<(&PartialMember, &User)> {
+        Some((
+            self.resolved_member(snowflake)?,
+            self.resolved_user(snowflake)?,
+        ))
+    }
+
+    pub fn resolved_role(&self, snowflake: &Snowflake) -> Option<&Role> {
+        self.resolved
+            .as_ref()
+            .and_then(|r| r.roles.as_ref())
+            .and_then(|u| u.get(snowflake))
+    }
+
+    pub fn resolved_channel(&self, snowflake: &Snowflake) -> Option<&PartialChannel> {
+        self.resolved
+            .as_ref()
+            .and_then(|r| r.channels.as_ref())
+            .and_then(|u| u.get(snowflake))
+    }
+
+    /// Like [resolved_channel](Self::resolved_channel), but only if the channel is one of `types`
+    pub fn resolved_channel_of_type(
+        &self,
+        snowflake: &Snowflake,
+        types: &[ChannelType],
+    ) -> Option<&PartialChannel> {
+        self.resolved_channel(snowflake)
+            .filter(|channel| types.contains(&channel.t))
+    }
+
+    pub fn first_option(&self) -> Option<&ApplicationCommandInteractionDataOption> {
+        self.options.single()
+    }
+
+    /// The option the user is typing in during autocomplete, looking through subcommands
+    pub fn focused_option(&self) -> Option<&ApplicationCommandInteractionDataOption> {
+        self.options.focused()
+    }
+
+    /// What the user has typed so far in the focused option
+    pub fn focused_value(&self) -> Option<FocusedValue> {
+        match self.focused_option()? {
+            ApplicationCommandInteractionDataOption::String(o) => {
+                Some(FocusedValue::String(o.value.clone()))
+            }
+            ApplicationCommandInteractionDataOption::Integer(o) => {
+                Some(FocusedValue::Integer(o.value))
+            }
+            ApplicationCommandInteractionDataOption::Number(o) => {
+                Some(FocusedValue::Number(o.value))
+            }
+            ApplicationCommandInteractionDataOption::Unparsed(o) => {
+                Some(FocusedValue::Unparsed(o.raw.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// The user a user command was used on, with their member info when invoked in a guild
+    pub fn target_user(&self) -> Option<TargetUser> {
+        if self.t != ApplicationCommandType::User {
+            return None;
+        }
+
+        let id = self.target_id.as_ref()?;
+
+        Some(TargetUser {
+            id,
+            user: self.resolved_user(id)?,
+            member: self.resolved_member(id),
+        })
+    }
+
+    /// The message a message command was used on
+    pub fn target_message(&self) -> Option<&Message> {
+        if self.t != ApplicationCommandType::Message {
+            return None;
+        }
+
+        let id = self.target_id.as_ref()?;
+
+        self.resolved
+            .as_ref()
+            .and_then(|r| r.messages.as_ref())
+            .and_then(|m| m.get(id))
+    }
+
+    /// Finds a channel option by name, looking through subcommands, and pairs it with its resolved channel
+    pub fn channel_option(&self, name: &str) -> Option<ResolvedChannelOption> {
+        let options = &self.options;
+
+        let option = options
+            .get_channel_option(name)
+            .or_else(|| options.subcommand()?.options.get_channel_option(name))
+            .or_else(|| {
+                options
+                    .subcommand_group()?
+                    .subcommand
+                    .options
+                    .get_channel_option(name)
+            })?;
+
+        Some(ResolvedChannelOption {
+            id: option.value.clone(),
+            channel: self.resolved_channel(&option.value)?,
+        })
+    }
+
+    /// Like [channel_option](Self::channel_option), but fails if the channel is missing or not one of `types`
+    pub fn require_channel_of_type(
+        &self,
+        name: &str,
+        types: &[ChannelType],
+    ) -> Result<ResolvedChannelOption, ChannelOptionError> {
+        let option = self
+            .channel_option(name)
+            .ok_or_else(|| ChannelOptionError::Missing(name.to_string()))?;
+
+        if types.contains(&option.channel.t) {
+            Ok(option)
+        } else {
+            Err(ChannelOptionError::WrongType {
+                name: name.to_string(),
+                expected: types.to_vec(),
+                found: option.channel.t,
+            })
+        }
+    }
+}
+
+/// Target of a user command
+#[derive(Debug)]
+pub struct TargetUser<'a> {
+    pub id: &'a Snowflake,
+    pub user: &'a User,
+
+    /// present when the command was used in a guild
+    pub member: Option<&'a PartialMember>,
+}
+
+/// Partial input of the focused option in an autocomplete interaction
+///
+/// Only string, integer and number options can have autocomplete.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FocusedValue {
+    String(String),
+    Integer(i64),
+    Number(f64),
+
+    /// an integer or number input that doesn't parse yet, like `12a`
+    Unparsed(String),
+}
+
+/// A channel option together with the channel Discord resolved for it
+#[derive(Debug)]
+pub struct ResolvedChannelOption<'a> {
+    pub id: Snowflake,
+    pub channel: &'a PartialChannel,
+}
+
+impl ResolvedChannelOption<'_> {
+    pub fn is_text(&self) -> bool {
+        self.channel.t.is_text()
+    }
+
+    pub fn is_voice(&self) -> bool {
+        self.channel.t.is_voice()
+    }
+
+    pub fn is_thread(&self) -> bool {
+        self.channel.t.is_thread()
+    }
+
+    pub fn is_category(&self) -> bool {
+        self.channel.t.is_category()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ChannelOptionError {
+    /// No channel option with this name, or it was not resolved
+    Missing(String),
+
+    /// The channel is not one of the expected types
+    WrongType {
+        name: String,
+        expected: Vec<ChannelType>,
+        found: ChannelType,
+    },
+}
+
+impl ChannelOptionError {
+    /// Ephemeral message explaining the error to the user
+    pub fn to_response(&self) -> InteractionResponse {
+        InteractionResponse::ChannelMessageWithSource(MessageCallbackData {
+            tts: None,
+            content: Some(self.to_string()),
+            embeds: None,
+            allowed_mentions: None,
+            flags: Some(MessageFlags::Ephemeral),
+            components: None,
+            attachments: None,
+        })
+    }
+}
+
+impl std::fmt::Display for ChannelOptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChannelOptionError::Missing(name) => write!(f, "Missing channel for `{name}`"),
+            ChannelOptionError::WrongType {
+                name,
+                expected,
+                found,
+            } => {
+                let expected = expected
+                    .iter()
+                    .map(|t| format!("{t:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                write!(
+                    f,
+                    "`{name}` must be one of {expected}, but {found:?} was given"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ChannelOptionError {}
+
+impl MessageComponentInteraction {
+    /// Message data for replying to the component, already ephemeral if the component is on an
+    /// ephemeral message
+    ///
+    /// Replies to a component on an ephemeral message have to be ephemeral too, otherwise Discord
+    /// reports the interaction as failed. Send it with
+    /// [InteractionResponse::ChannelMessageWithSource].
+    pub fn reply(&self) -> MessageCallbackData {
+        let ephemeral = self
+            .common
+            .message
+            .as_ref()
+            .and_then(|m| m.flags.as_ref())
+            .map_or(false, |f| f.contains(MessageFlags::Ephemeral));
+
+        if ephemeral {
+            MessageCallbackData::new().ephemeral()
+        } else {
+            MessageCallbackData::new()
+        }
+    }
+
+    /// Copy of the source message's components with every button and select menu disabled
+    ///
+    /// Return it with [InteractionResponse::UpdateMessage] through
+    /// [with_components](crate::models::UpdateMessageData::with_components)
+    /// to stop the components being used again.
+    pub fn disable_components(&self) -> Vec<ActionRow> {
+        let mut rows = self
+            .common
+            .message
+            .as_ref()
+            .and_then(|m| m.components.clone())
+            .unwrap_or_default();
+
+        for component in rows.iter_mut().flat_map(|r| r.components.iter_mut()) {
+            component.disable();
+        }
+
+        rows
+    }
+}
+
+/// [Message Component Data Structure](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-message-component-data-structure)
+#[derive(Debug, Deserialize)]
+pub struct MessageComponentData {
+    /// the [custom_id](https://discord.com/developers/docs/interactions/message-components#custom-id) of the component
+    pub custom_id: String,
+
+    /// the [type](https://discord.com/developers/docs/interactions/message-components#component-object-component-types) of the component
+    pub component_type: MessageComponentType,
+
+    /// values the user selected in a [select menu](https://discord.com/developers/docs/interactions/message-components#select-menu-object) component
+    pub values: Option<Vec<String>>,
+
+    /// resolved entities from selected options, for user, role, mentionable and channel selects
+    pub resolved: Option<ResolvedData>,
+}
+
+impl MessageComponentData {
+    /// Users picked in a user or mentionable select, in the order they were selected
+    pub fn resolved_users(&self) -> Vec<&User> {
+        self.selected(self.resolved.as_ref().and_then(|r| r.users.as_ref()))
+    }
+
+    /// Roles picked in a role or mentionable select, in the order they were selected
+    pub fn resolved_roles(&self) -> Vec<&Role> {
+        self.selected(self.resolved.as_ref().and_then(|r| r.roles.as_ref()))
+    }
+
+    /// Channels picked in a channel select, in the order they were selected
+    pub fn resolved_channels(&self) -> Vec<&PartialChannel> {
+        self.selected(self.resolved.as_ref().and_then(|r| r.channels.as_ref()))
+    }
+
+    /// Picked entities of a user, role, mentionable or channel select joined with `resolved`
+    ///
+    /// `None` for other components. Values without a matching `resolved` entry are listed in
+    /// [Selection::unresolved] rather than dropped.
+    pub fn resolved_selection(&self) -> Option<Selection> {
+        let resolved = self.resolved.as_ref();
+        let users = resolved.and_then(|r| r.users.as_ref());
+        let members = resolved.and_then(|r| r.members.as_ref());
+        let roles = resolved.and_then(|r| r.roles.as_ref());
+        let channels = resolved.and_then(|r| r.channels.as_ref());
+
+        let user = |id: &Snowflake| {
+            let (id, user) = users?.get_key_value(id)?;
+            Some((id, user, members.and_then(|m| m.get(id))))
+        };
+        let role = |id: &Snowflake| roles?.get_key_value(id);
+        let channel = |id: &Snowflake| channels?.get_key_value(id);
+
+        let mut unresolved = Vec::new();
+        let values = self.values.as_deref().unwrap_or_default();
+
+        let selected = match self.component_type {
+            MessageComponentType::UserSelect => {
+                SelectedEntities::Users(pick(values, &mut unresolved, user))
+            }
+            MessageComponentType::RoleSelect => {
+                SelectedEntities::Roles(pick(values, &mut unresolved, role))
+            }
+            MessageComponentType::ChannelSelect => {
+                SelectedEntities::Channels(pick(values, &mut unresolved, channel))
+            }
+            MessageComponentType::MentionableSelect => {
+                SelectedEntities::Mentionables(pick(values, &mut unresolved, |id| {
+                    user(id)
+                        .map(|(id, user, member)| SelectedMentionable::User(id, user, member))
+                        .or_else(|| role(id).map(|(id, role)| SelectedMentionable::Role(id, role)))
+                }))
+            }
+            _ => return None,
+        };
+
+        Some(Selection {
+            selected,
+            unresolved,
+        })
+    }
+
+    fn selected<'a, T>(&'a self, resolved: Option<&'a HashMap<Snowflake, T>>) -> Vec<&'a T> {
+        match (self.values.as_ref(), resolved) {
+            (Some(values), Some(resolved)) => values
+                .iter()
+                .filter_map(|v| v.parse::<Snowflake>().ok())
+                .filter_map(|id| resolved.get(&id))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// What was picked in a select menu, from [MessageComponentData::resolved_selection]
+#[derive(Debug)]
+pub struct Selection<'a> {
+    /// picked entities that were found in `resolved`, in the order they were picked
+    pub selected: SelectedEntities<'a>,
+
+    /// values with no entry in `resolved`
+    pub unresolved: Vec<&'a str>,
+}
+
+/// Entities picked in a select menu, by the kind of select
+#[derive(Debug)]
+pub enum SelectedEntities<'a> {
+    /// users with their member data when picked in a guild
+    Users(Vec<(&'a Snowflake, &'a User, Option<&'a PartialMember>)>),
+    Roles(Vec<(&'a Snowflake, &'a Role)>),
+    Channels(Vec<(&'a Snowflake, &'a PartialChannel)>),
+    Mentionables(Vec<SelectedMentionable<'a>>),
+}
+
+/// A user or role picked in a mentionable select
+#[derive(Debug)]
+pub enum SelectedMentionable<'a> {
+    User(&'a Snowflake, &'a User, Option<&'a PartialMember>),
+    Role(&'a Snowflake, &'a Role),
+}
+
+/// Looks up each picked id in order, collecting the values that couldn't be found
+fn pick<'a, T>(
+    values: &'a [String],
+    unresolved: &mut Vec<&'a str>,
+    lookup: impl Fn(&Snowflake) -> Option<T>,
+) -> Vec<T> {
+    values
+        .iter()
+        .filter_map(|value| {
+            let found = value.parse::<Snowflake>().ok().and_then(|id| lookup(&id));
+            if found.is_none() {
+                unresolved.push(value);
+            }
+            found
+        })
+        .collect()
+}
+
+#[derive(Debug, Deserialize_repr, PartialEq, Eq, Hash, Clone, Copy)]
+#[repr(u8)]
+pub enum MessageComponentType {
+    ActionRow = 1,
+    Button = 2,
+    StringSelect = 3,
+    TextInput = 4,
+    UserSelect = 5,
+    RoleSelect = 6,
+    MentionableSelect = 7,
+    ChannelSelect = 8,
+    Label = 18,
+}
+
+/// [Modal Submit Data Structure](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-modal-submit-data-structure)
+#[derive(Debug, Deserialize)]
+pub struct ModalSubmitData {
+    /// the [custom_id](https://discord.com/developers/docs/interactions/message-components#custom-id) of the modal
+    pub custom_id: String,
+
+    /// the values submitted by the user
+    pub components: Vec<ModalSubmitRow>,
+}
+
+impl ModalSubmitData {
+    /// The submitted component with `custom_id`, in an action row or a label
+    pub fn component(&self, custom_id: &str) -> Option<&ModalSubmitComponent> {
+        self.components
+            .iter()
+            .flat_map(|row| row.components())
+            .find(|component| component.custom_id == custom_id)
+    }
+}
+
+/// Top level component of a modal submission
+#[derive(Debug)]
+pub enum ModalSubmitRow {
+    ActionRow(ModalSubmitActionRow),
+    Label(ModalSubmitLabel),
+}
+
+impl ModalSubmitRow {
+    /// Components in the row, a label holds exactly one
+    pub fn components(&self) -> &[ModalSubmitComponent] {
+        match self {
+            ModalSubmitRow::ActionRow(row) => &row.components,
+            ModalSubmitRow::Label(label) => std::slice::from_ref(&label.component),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ModalSubmitRow {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Buffered::deserialize(deserializer)?;
+
+        let t = value
+            .get("type")
+            .and_then(Value::as_u64)
+            .ok_or(serde::de::Error::missing_field("type"))?;
+
+        match t {
+            1 => Ok(ModalSubmitRow::ActionRow(
+                value.deserialize_into().map_err(serde::de::Error::custom)?,
+            )),
+            18 => Ok(ModalSubmitRow::Label(
+                value.deserialize_into().map_err(serde::de::Error::custom)?,
+            )),
+            _ => Err(serde::de::Error::custom("Unknown modal component")),
+        }
+    }
+}
+
+/// Action row received in a modal submission, containing only the submitted values
+#[derive(Debug, Deserialize)]
+pub struct ModalSubmitActionRow {
+    #[serde(rename = "type")]
+    pub t: TypeField<1>,
+
+    pub components: Vec<ModalSubmitComponent>,
+}
+
+/// Label received in a modal submission, wrapping the labelled component's values
+#[derive(Debug, Deserialize)]
+pub struct ModalSubmitLabel {
+    #[serde(rename = "type")]
+    pub t: TypeField<18>,
+
+    pub component: ModalSubmitComponent,
+}
+
+/// Component received in a modal submission
+#[derive(Debug, Deserialize)]
+pub struct ModalSubmitComponent {
+    /// the [type](https://discord.com/developers/docs/interactions/message-components#component-object-component-types) of the component
+    #[serde(rename = "type")]
+    pub t: MessageComponentType,
+
+    /// the [custom_id](https://discord.com/developers/docs/interactions/message-components#custom-id) of the component
+    pub custom_id: String,
+
+    /// the value submitted by the user, for text inputs
+    pub value: Option<String>,
+
+    /// the values selected by the user, for select menus
+    pub values: Option<Vec<String>>,
+}
+
+/// [Resolved Data Structure](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-resolved-data-structure)
+#[derive(Debug, Deserialize)]
+pub struct ResolvedData {
+    /// the ids and User objects
+    pub users: Option<HashMap<Snowflake, User>>,
+
+    /// the ids and partial Member objects, without their user which is in `users` under the same id
+    pub members: Option<HashMap<Snowflake, PartialMember>>,
+
+    /// the ids and Role objects
+    pub roles: Option<HashMap<Snowflake, Role>>,
+
+    /// the ids and partial Channel objects
+    pub channels: Option<HashMap<Snowflake, PartialChannel>>,
+
+    /// the ids and partial Message objects
+    pub messages: Option<HashMap<Snowflake, Message>>,
+
+    /// the ids and attachment objects
+    pub attachments: Option<HashMap<Snowflake, Attachment>>,
+}
+
+pub type StringOption = ValueOption<String>;
+pub type IntegerOption = ValueOption<i64>;
+pub type BooleanOption = ValueOption<bool>;
+pub type SnowflakeOption = ValueOption<Snowflake>;
+pub type NumberOption = ValueOption<f64>;
+
+/// [Application Command Interaction Data Option Structure](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-application-command-interaction-data-option-structure)
+#[derive(Debug)]
+pub enum ApplicationCommandInteractionDataOption {
+    Subcommand(Subcommand),
+    SubcommandGroup(SubcommandGroup),
+    String(StringOption),
+    Integer(IntegerOption),
+    Boolean(BooleanOption),
+    User(SnowflakeOption),
+    Channel(SnowflakeOption),
+    Role(SnowflakeOption),
+    Mentionable(SnowflakeOption),
+    Number(NumberOption),
+    Attachment, // TODO: Figure out value type
+
+    /// focused integer or number input that doesn't parse yet during autocomplete
+    Unparsed(UnparsedOption),
+}
+
+impl<'de> Deserialize<'de> for ApplicationCommandInteractionDataOption {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut value = Buffered::deserialize(deserializer)?;
+
+        let t = value
+            .take("type")
+            .and_then(|t| t.deserialize_into::<u64>().ok())
+            .ok_or(serde::de::Error::missing_field("type"))?;
+
+        match t {
+            1 => Ok(ApplicationCommandInteractionDataOption::Subcommand(
+                value.deserialize_into().map_err(serde::de::Error::custom)?,
+            )),
+            2 => Ok(ApplicationCommandInteractionDataOption::SubcommandGroup(
+                value.deserialize_into().map_err(serde::de::Error::custom)?,
+            )),
+            3 => Ok(ApplicationCommandInteractionDataOption::String(
+                value
+                    .deserialize_into()
+                    .map_err(|e| serde::de::Error::custom(e))?,
+            )),
+            4 | 10 if UnparsedOption::is_unparsed(t, &value) => {
+                let mut option: UnparsedOption =
+                    value.deserialize_into().map_err(serde::de::Error::custom)?;
+                option.option_type = t as u8;
+
+                Ok(ApplicationCommandInteractionDataOption::Unparsed(option))
+            }
+            4 => Ok(ApplicationCommandInteractionDataOption::Integer(
+                ValueOption::deserialize_lenient(value).map_err(serde::de::Error::custom)?,
+            )),
+            5 => Ok(ApplicationCommandInteractionDataOption::Boolean(
+                ValueOption::deserialize_lenient(value).map_err(serde::de::Error::custom)?,
+            )),
+            6 => Ok(ApplicationCommandInteractionDataOption::User(
+                value
+                    .deserialize_into()
+                    .map_err(|e| serde::de::Error::custom(e))?,
+            )),
+            7 => Ok(ApplicationCommandInteractionDataOption::Channel(
+                value
+                    .deserialize_into()
+                    .map_err(|e| serde::de::Error::custom(e))?,
+            )),
+            8 => Ok(ApplicationCommandInteractionDataOption::Role(
+                value
+                    .deserialize_into()
+                    .map_err(|e| serde::de::Error::custom(e))?,
+            )),
+            9 => Ok(ApplicationCommandInteractionDataOption::Mentionable(
+                value
+                    .deserialize_into()
+                    .map_err(|e| serde::de::Error::custom(e))?,
+            )),
+            10 => Ok(ApplicationCommandInteractionDataOption::Number(
+                ValueOption::deserialize_lenient(value).map_err(serde::de::Error::custom)?,
+            )),
+            11 => Ok(ApplicationCommandInteractionDataOption::Attachment),
+            _ => Err(serde::de::Error::custom("Unknown option")),
+        }
+    }
+}
+
+/// Mirrors the deserializer, writing the numeric `type` alongside the option's fields
+impl Serialize for ApplicationCommandInteractionDataOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            ApplicationCommandInteractionDataOption::Subcommand(s) => s.serialize(serializer),
+            ApplicationCommandInteractionDataOption::SubcommandGroup(g) => g.serialize(serializer),
+            ApplicationCommandInteractionDataOption::String(o) => o.serialize_typed(3, serializer),
+            ApplicationCommandInteractionDataOption::Integer(o) => o.serialize_typed(4, serializer),
+            ApplicationCommandInteractionDataOption::Boolean(o) => o.serialize_typed(5, serializer),
+            ApplicationCommandInteractionDataOption::User(o) => o.serialize_typed(6, serializer),
+            ApplicationCommandInteractionDataOption::Channel(o) => o.serialize_typed(7, serializer),
+            ApplicationCommandInteractionDataOption::Role(o) => o.serialize_typed(8, serializer),
+            ApplicationCommandInteractionDataOption::Mentionable(o) => {
+                o.serialize_typed(9, serializer)
+            }
+            ApplicationCommandInteractionDataOption::Number(o) => o.serialize_typed(10, serializer),
+            ApplicationCommandInteractionDataOption::Unparsed(o) => o.serialize(serializer),
+            ApplicationCommandInteractionDataOption::Attachment => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("type", &11)?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl ApplicationCommandInteractionDataOption {
+    /// Name of the option, attachments don't carry one yet
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            ApplicationCommandInteractionDataOption::Subcommand(s) => Some(&s.name),
+            ApplicationCommandInteractionDataOption::SubcommandGroup(s) => Some(&s.name),
+            ApplicationCommandInteractionDataOption::String(s) => Some(&s.name),
+            ApplicationCommandInteractionDataOption::Integer(s) => Some(&s.name),
+            ApplicationCommandInteractionDataOption::Boolean(s) => Some(&s.name),
+            ApplicationCommandInteractionDataOption::User(s) => Some(&s.name),
+            ApplicationCommandInteractionDataOption::Channel(s) => Some(&s.name),
+            ApplicationCommandInteractionDataOption::Role(s) => Some(&s.name),
+            ApplicationCommandInteractionDataOption::Mentionable(s) => Some(&s.name),
+            ApplicationCommandInteractionDataOption::Number(s) => Some(&s.name),
+            ApplicationCommandInteractionDataOption::Unparsed(s) => Some(&s.name),
+            ApplicationCommandInteractionDataOption::Attachment => None,
+        }
+    }
+
+    /// Whether this is the option the user is typing in during autocomplete
+    pub fn is_focused(&self) -> bool {
+        let focused = match self {
+            ApplicationCommandInteractionDataOption::Subcommand(s) => s.focused,
+            ApplicationCommandInteractionDataOption::SubcommandGroup(s) => s.focused,
+            ApplicationCommandInteractionDataOption::String(s) => s.focused,
+            ApplicationCommandInteractionDataOption::Integer(s) => s.focused,
+            ApplicationCommandInteractionDataOption::Boolean(s) => s.focused,
+            ApplicationCommandInteractionDataOption::User(s) => s.focused,
+            ApplicationCommandInteractionDataOption::Channel(s) => s.focused,
+            ApplicationCommandInteractionDataOption::Role(s) => s.focused,
+            ApplicationCommandInteractionDataOption::Mentionable(s) => s.focused,
+            ApplicationCommandInteractionDataOption::Number(s) => s.focused,
+            ApplicationCommandInteractionDataOption::Unparsed(s) => s.focused,
+            ApplicationCommandInteractionDataOption::Attachment => None,
+        };
+
+        focused == Some(true)
+    }
+}
+
+/// Options of a command or subcommand, looked up by name
+///
+/// Lookups go through an index of the options sorted by name, built on first use. When names
+/// repeat the first matching option wins, as it would scanning the list in order.
+pub struct OptionList {
+    options: Vec<ApplicationCommandInteractionDataOption>,
+
+    /// positions in `options` sorted by name, ties kept in their original order
+    index: OnceCell<Vec<usize>>,
+}
+
+impl OptionList {
+    fn new(options: Vec<ApplicationCommandInteractionDataOption>) -> Self {
+        OptionList {
+            options,
+            index: OnceCell::new(),
+        }
+    }
+
+    fn index(&self) -> &[usize] {
+        self.index.get_or_init(|| {
+            let mut index: Vec<usize> = (0..self.options.len()).collect();
+            // stable, so duplicate names stay in list order
+            index.sort_by_key(|&i| self.options[i].name());
+            index
+        })
+    }
+
+    /// Options called `name` in list order
+    fn named<'a>(
+        &'a self,
+        name: &str,
+    ) -> impl Iterator<Item = &'a ApplicationCommandInteractionDataOption> {
+        let index = self.index();
+        let start = index.partition_point(|&i| self.options[i].name() < Some(name));
+        let end = start + index[start..].partition_point(|&i| self.options[i].name() == Some(name));
+
+        index[start..end].iter().map(|&i| &self.options[i])
+    }
+
+    pub fn len(&self) -> usize {
+        self.options.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.options.is_empty()
+    }
+
+    pub fn single(&self) -> Option<&ApplicationCommandInteractionDataOption> {
+        self.options.get(0)
+    }
+
+    /// The focused option, looking through subcommands and subcommand groups
+    pub fn focused(&self) -> Option<&ApplicationCommandInteractionDataOption> {
+        self.options.iter().find(|o| o.is_focused()).or_else(|| {
+            self.subcommand()
+                .or_else(|| Some(&self.subcommand_group()?.subcommand))?
+                .options
+                .focused()
+        })
+    }
+
+    pub fn subcommand(&self) -> Option<&Subcommand> {
+        self.options.iter().find_map(|o| match o {
+            ApplicationCommandInteractionDataOption::Subcommand(s) => Some(s),
+            _ => None,
+        })
+    }
+
+    pub fn subcommand_group(&self) -> Option<&SubcommandGroup> {
+        self.options.iter().find_map(|o| match o {
+            ApplicationCommandInteractionDataOption::SubcommandGroup(s) => Some(s),
+            _ => None,
+        })
+    }
+
+    pub fn get_option(&self, name: &str) -> Option<&ApplicationCommandInteractionDataOption> {
+        self.named(name).next()
+    }
+
+    /// Looks up several options at once, each result lines up with its name
+    pub fn get_many(
+        &self,
+        names: &[&str],
+    ) -> Vec<Option<&ApplicationCommandInteractionDataOption>> {
+        names.iter().map(|name| self.get_option(name)).collect()
+    }
+
+    pub fn get_string_option(&self, name: &str) -> Option<&StringOption> {
+        self.named(name).find_map(|o| match o {
+            ApplicationCommandInteractionDataOption::String(s) => Some(s),
+            _ => None,
+        })
+    }
+
+    pub fn get_integer_option(&self, name: &str) -> Option<&IntegerOption> {
+        self.named(name).find_map(|o| match o {
+            ApplicationCommandInteractionDataOption::Integer(s) => Some(s),
+            _ => None,
+        })
+    }
+
+    pub fn get_boolean_option(&self, name: &str) -> Option<&BooleanOption> {
+        self.named(name).find_map(|o| match o {
+            ApplicationCommandInteractionDataOption::Boolean(s) => Some(s),
+            _ => None,
+        })
+    }
+
+    pub fn get_user_option(&self, name: &str) -> Option<&SnowflakeOption> {
+        self.named(name).find_map(|o| match o {
+            ApplicationCommandInteractionDataOption::User(s) => Some(s),
+            _ => None,
+        })
+    }
+
+    pub fn get_channel_option(&self, name: &str) -> Option<&SnowflakeOption> {
+        self.named(name).find_map(|o| match o {
+            ApplicationCommandInteractionDataOption::Channel(s) => Some(s),
+            _ => None,
+        })
+    }
+
+    pub fn get_role_option(&self, name: &str) -> Option<&SnowflakeOption> {
+        self.named(name).find_map(|o| match o {
+            ApplicationCommandInteractionDataOption::Role(s) => Some(s),
+            _ => None,
+        })
+    }
+
+    pub fn get_mentionable_option(&self, name: &str) -> Option<&SnowflakeOption> {
+        self.named(name).find_map(|o| match o {
+            ApplicationCommandInteractionDataOption::Mentionable(s) => Some(s),
+            _ => None,
+        })
+    }
+}
+
+impl Default for OptionList {
+    fn default() -> Self {
+        OptionList::new(Vec::new())
+    }
+}
+
+/// Discord leaves `options` out, sends `[]` or `null` for commands without any, all of them are an empty list
+fn options_or_empty<'de, D>(deserializer: D) -> Result<OptionList, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<OptionList>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+impl std::fmt::Debug for OptionList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("OptionList").field(&self.options).finish()
+    }
+}
+
+impl Serialize for OptionList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.options.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for OptionList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(OptionList::new(Vec::<
+            ApplicationCommandInteractionDataOption,
+        >::deserialize(deserializer)?))
+    }
+}
+
+/// Partial input of a focused integer or number option that doesn't parse yet, like `12a`
+#[derive(Debug, Deserialize)]
+pub struct UnparsedOption {
+    /// Name of the parameter
+    pub name: String,
+
+    /// `4` for an integer option, `10` for a number option
+    #[serde(skip)]
+    pub option_type: u8,
+
+    /// What the user typed, as Discord sent it
+    #[serde(rename = "value")]
+    pub raw: String,
+
+    /// true if this option is the currently focused option for autocomplete
+    pub focused: Option<bool>,
+}
+
+impl UnparsedOption {
+    /// Only the focused option holds partial input, anywhere else a string that doesn't parse is
+    /// an error
+    fn is_unparsed(t: u64, option: &Buffered) -> bool {
+        let focused = option.get("focused").and_then(Value::as_bool) == Some(true);
+        let text = match option.get("value").and_then(Value::as_str) {
+            Some(text) if focused => text.trim(),
+            _ => return false,
+        };
+
+        match t {
+            4 => text.parse::<i64>().is_err(),
+            _ => text.parse::<f64>().is_err(),
+        }
+    }
+}
+
+impl Serialize for UnparsedOption {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &self.option_type)?;
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("value", &self.raw)?;
+        if let Some(focused) = self.focused {
+            map.serialize_entry("focused", &focused)?;
+        }
+        map.end()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Subcommand {
+    /// Name of the parameter
+    pub name: String,
+
+    /// Present if this option is a group or subcommand
+    pub options: OptionList,
+
+    /// true if this option is the currently focused option for autocomplete
+    pub focused: Option<bool>,
+}
+
+impl Serialize for Subcommand {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &1)?;
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("options", &self.options)?;
+        if let Some(focused) = self.focused {
+            map.serialize_entry("focused", &focused)?;
+        }
+        map.end()
+    }
+}
+
+#[derive(Debug)]
+pub struct SubcommandGroup {
+    /// Name of the parameter
+    pub name: String,
+
+    /// The command being called
+    pub subcommand: Subcommand,
+
+    /// true if this option is the currently focused option for autocomplete
+    pub focused: Option<bool>,
+}
+
+impl<'de> Deserialize<'de> for SubcommandGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct InnerData {
+            name: String,
+            options: Vec<ApplicationCommandInteractionDataOption>,
+            focused: Option<bool>,
+        }
+
+        let InnerData {
+            name,
+            focused,
+            mut options,
+        } = InnerData::deserialize(deserializer)?;
+
+        let option = options.remove(0);
+
+        match option {
+            ApplicationCommandInteractionDataOption::Subcommand(subcommand) => {
+                Ok(SubcommandGroup {
+                    name,
+                    subcommand,
+                    focused,
+                })
+            }
+            _ => return Err(serde::de::Error::custom("Not a subcommand")),
+        }
+    }
+}
+
+impl Serialize for SubcommandGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &2)?;
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("options", std::slice::from_ref(&self.subcommand))?;
+        if let Some(focused) = self.focused {
+            map.serialize_entry("focused", &focused)?;
+        }
+        map.end()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ValueOption<T> {
+    /// Name of the parameter
+    pub name: String,
+
+    /// Value of the option resulting from user input
+    pub value: T,
+
+    /// true if this option is the currently focused option for autocomplete
+    pub focused: Option<bool>,
+
+    /// Text of an integer, number or boolean value Discord sent as a string
+    #[serde(skip)]
+    pub raw: Option<String>,
+}
+
+impl<T> ValueOption<T> {
+    /// The value as Discord sent it, when an integer, number or boolean came as a string
+    pub fn raw_value(&self) -> Option<&str> {
+        self.raw.as_deref()
+    }
+}
+
+impl<T: DeserializeOwned + FromStr> ValueOption<T> {
+    /// Integer, number and boolean values sometimes arrive as strings, most often the focused
+    /// option during autocomplete
+    ///
+    /// A focused input that doesn't parse never gets here, it becomes an [UnparsedOption].
+    fn deserialize_lenient(mut option: Buffered) -> serde_json::Result<Self> {
+        #[derive(Deserialize)]
+        struct Fields {
+            name: String,
+            focused: Option<bool>,
+        }
+
+        let value = option
+            .take("value")
+            .ok_or_else(|| serde::de::Error::missing_field("value"))?
+            .deserialize_into::<Value>()?;
+        let Fields { name, focused } = option.deserialize_into()?;
+
+        let (value, raw) = match value {
+            Value::String(raw) => match raw.trim().parse() {
+                Ok(value) => (value, Some(raw)),
+                Err(_) => {
+                    return Err(serde::de::Error::custom(format!(
+                        "invalid value {:?} for option {}",
+                        raw, name
+                    )))
+                }
+            },
+            value => (T::deserialize(value)?, None),
+        };
+
+        Ok(ValueOption {
+            name,
+            value,
+            focused,
+            raw,
+        })
+    }
+}
+
+impl<T: Serialize> ValueOption<T> {
+    fn serialize_typed<S>(&self, t: u8, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &t)?;
+        map.serialize_entry("name", &self.name)?;
+        map.serialize_entry("value", &self.value)?;
+        if let Some(focused) = self.focused {
+            map.serialize_entry("focused", &focused)?;
+        }
+        map.end()
+    }
+}
+
+/// [Application Command Types](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-types)
+#[derive(Debug, Deserialize_repr, Serialize_repr, PartialEq, Eq, Hash, Clone, Copy)]
+#[repr(u8)]
+pub enum ApplicationCommandType {
+    /// Slash commands; a text-based command that shows up when a user types /
+    ChatInput = 1,
+
+    /// A UI-based command that shows up when you right click or tap on a user
+    User = 2,
+
+    /// A UI-based command that shows up when you right click or tap on a message
+    Message = 3,
+
+    /// A UI-based command that represents the primary way to invoke an app's Activity
+    PrimaryEntryPoint = 4,
+}
+
+/// [Application Command Data](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-application-command-data-structure)
+#[derive(Debug, Deserialize)]
+pub struct InteractionData {
+    /// the [ID](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-structure) of the invoked command
+    pub id: Snowflake,
+
+    /// the [name](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-structure) of the invoked command
+    pub name: String,
+
+    /// the [type](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-structure) of the invoked command
+    #[serde(rename = "type")]
+    pub t: ApplicationCommandType,
+
+    /// converted users + roles + channels + attachments
+    pub resolved: Option<ResolvedData>,
+
+    /// the params + values from the user
+    pub options: Option<Vec<ApplicationCommandInteractionDataOption>>,
+
+    /// the id of the guild the command is registered to
+    pub guild_id: Option<Snowflake>,
+
+    /// id of the [user](https://discord.com/developers/docs/interactions/application-commands#user-commands) or [message](https://discord.com/developers/docs/interactions/application-commands#message-commands) targeted by a user or message command
+    pub target_id: Option<Snowflake>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn ping_interaction() {
+        let json = r#"{
+            "application_id": "1052322265397739523",
+			"type": 1,
+			"token": "A_UNIQUE_TOKEN",
+			"member": {
+				"user": {
+					"id": "53908232506183680",
+					"username": "Mason",
+					"avatar": "a_d5efa99b3eeaa7dd43acca82f5692432",
+					"discriminator": "1337",
+					"public_flags": 131141
+				},
+				"roles": ["539082325061836999"],
+				"premium_since": null,
+				"permissions": "2147483647",
+				"pending": false,
+				"nick": null,
+				"mute": false,
+				"joined_at": "2017-03-13T19:19:14.040000+00:00",
+				"is_pending": false,
+				"deaf": false,
+                "flags": 0
+			},
+			"id": "786008729715212338",
+			"guild_id": "290926798626357999",
+			"app_permissions": "442368",
+			"guild_locale": "en-US",
+			"locale": "en-US",
+			"channel_id": "645027906669510667",
+            "version": 1
+		}"#;
+
+        let res = serde_json::from_str::<Interaction>(json);
+
+        assert!(res.is_ok());
+
+        let interaction = res.unwrap();
+
+        assert!(matches!(
+            interaction,
+            Interaction::Ping(PingInteraction { .. })
+        ))
+    }
+
+    #[test]
+    pub fn command_interaction() {
+        let json = r#"{
+            "application_id": "1052322265397739523",
+            "version": 1,
+			"type": 2,
+			"token": "A_UNIQUE_TOKEN",
+			"member": {
+				"user": {
+					"id": "53908232506183680",
+					"username": "Mason",
+					"avatar": "a_d5efa99b3eeaa7dd43acca82f5692432",
+					"discriminator": "1337",
+					"public_flags": 131141
+				},
+				"roles": ["539082325061836999"],
+				"premium_since": null,
+				"permissions": "2147483647",
+				"pending": false,
+				"nick": null,
+				"mute": false,
+				"joined_at": "2017-03-13T19:19:14.040000+00:00",
+				"is_pending": false,
+				"deaf": false,
+                "flags": 0
+			},
+			"id": "786008729715212338",
+			"guild_id": "290926798626357999",
+			"app_permissions": "442368",
+			"guild_locale": "en-US",
+			"locale": "en-US",
+			"data": {
+				"options": [{
+					"type": 3,
+					"name": "cardname",
+					"value": "The Gitrog Monster"
+				}],
+				"type": 1,
+				"name": "cardsearch",
+				"id": "771825006014889984"
+			},
+			"channel_id": "645027906669510667"
+		}"#;
+
+        let res = serde_json::from_str::<Interaction>(json);
+
+        assert!(res.is_ok());
+
+        let interaction = res.unwrap();
+
+        assert!(matches!(
+            interaction,
+            Interaction::ApplicationCommand(DataInteraction { .. })
+        ))
+    }
+
+    #[test]
+    pub fn real_interaction() {
+        let json = r#"{
+            "app_permissions": "137411140374081",
+            "application_id": "1052322265397739523",
+            "channel": {
+                "flags": 0,
+                "guild_id": "798662131062931547",
+                "id": "941169456686723122",
+                "last_message_id": "1100155827400229026",
+                "name": "bot-stuff",
+                "nsfw": false,
+                "parent_id": "798662131678969866",
+                "permissions": "140737488355327",
+                "position": 1,
+                "rate_limit_per_user": 0,
+                "topic": null,
+                "type": 0
+            },
+            "channel_id": "941169456686723122",
+            "data": {
+                "guild_id": "798662131062931547",
+                "id": "1052358444704862218",
+                "name": "ping",
+                "type": 1
+            },
+            "entitlement_sku_ids": [],
+            "entitlements": [],
+            "guild_id": "798662131062931547",
+            "guild_locale": "en-US",
+            "id": "1100173248714518568",
+            "locale": "en-US",
+            "member": {
+                "avatar": null,
+                "communication_disabled_until": null,
+                "deaf": false,
+                "flags": 0,
+                "is_pending": false,
+                "joined_at": "2021-01-12T21:18:10.481000+00:00",
+                "mute": false,
+                "nick": null,
+                "pending": false,
+                "permissions": "140737488355327",
+                "premium_since": null,
+                "roles": [
+                    "943607715639484456"
+                ],
+                "user": {
+                    "avatar": "fa82e15e24ee16c9fcbf8dd34d10b4cc",
+                    "avatar_decoration": null,
+                    "discriminator": "9846",
+                    "display_name": null,
+                    "global_name": null,
+                    "id": "282265607313817601",
+                    "public_flags": 0,
+                    "username": "BlueFrog"
+                }
+            },
+            "token": "aW50ZXJhY3Rpb246MTEwMDE3MzI0ODcxNDUxODU2ODppVTFuSkNSbndrZ01Na3RCWk81MVhTWkdSbk8yTlBaM1U3Z3JlckR4YUZJMTZFTm9wc21nZnlaSnN4ZUZCTTd0Q0Jzc09ac3BHV1E1MGlBZGZnZzh0NDJmTElIcTB1M0FZQTJPS1BxcG1GTEtZUjNDWWFEamhEeTRPMWZnS0R4dQ",
+            "type": 2,
+            "version": 1
+        }"#;
+
+        let interaction = serde_json::from_str::<Interaction>(json);
+
+        println!("{:#?}", interaction);
+
+        assert!(interaction.is_ok());
+    }
+
+    #[test]
+    #[cfg(not(feature = "unredacted-debug"))]
+    pub fn debug_redacts_token() {
+        let token = "aW50ZXJhY3Rpb246MTEwMDE3MzI0ODcxNDUxODU2ODppVTFuSkNSbndrZ01Na3RCWk81MVhTWkdSbk8yTlBaM1U3Z3JlckR4YUZJMTZFTm9wc21nZnlaSnN4ZUZCTTd0Q0Jzc09ac3BHV1E1MGlBZGZnZzh0NDJmTElIcTB1M0FZQTJPS1BxcG1GTEtZUjNDWWFEamhEeTRPMWZnS0R4dQ";
+        let json = format!(
+            r#"{{"id":"700000000000000001","application_id":"100000000000000001","type":1,"token":"{token}","version":1}}"#
+        );
+
+        let interaction = serde_json::from_str::<Interaction>(&json).unwrap();
+
+        for debug in [format!("{:?}", interaction), format!("{:#?}", interaction)] {
+            assert!(!debug.contains(token), "{}", debug);
+            assert!(debug.contains(r#"token: "aW50ZXJh…""#), "{}", debug);
+        }
+    }
+
+    #[test]
+    pub fn command_type_as_map_key() {
+        let types = [
+            ApplicationCommandType::ChatInput,
+            ApplicationCommandType::User,
+            ApplicationCommandType::ChatInput,
+            ApplicationCommandType::Message,
+            ApplicationCommandType::ChatInput,
+        ];
+
+        let mut counts: HashMap<ApplicationCommandType, usize> = HashMap::new();
+
+        for t in types {
+            *counts.entry(t).or_default() += 1;
+        }
+
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&ApplicationCommandType::ChatInput], 3);
+        assert_eq!(counts[&ApplicationCommandType::User], 1);
+        assert_eq!(counts[&ApplicationCommandType::Message], 1);
+    }
+
+    #[test]
+    pub fn option_round_trip() {
+        let json = r#"[
+            {"type": 3, "name": "query", "value": "rust"},
+            {"type": 4, "name": "limit", "value": 25, "focused": true}
+        ]"#;
+
+        let options: OptionList = serde_json::from_str(json).unwrap();
+        let serialized = serde_json::to_value(options).unwrap();
+
+        assert_eq!(
+            serialized,
+            serde_json::json!([
+                {"type": 3, "name": "query", "value": "rust"},
+                {"type": 4, "name": "limit", "value": 25, "focused": true}
+            ])
+        );
+
+        let options: OptionList = serde_json::from_value(serialized).unwrap();
+
+        assert_eq!(options.get_string_option("query").unwrap().value, "rust");
+
+        let limit = options.get_integer_option("limit").unwrap();
+        assert_eq!(limit.value, 25);
+        assert_eq!(limit.focused, Some(true));
+    }
+
+    #[test]
+    pub fn lenient_option_values() {
+        let json = r#"[
+            {"type": 4, "name": "count", "value": 5},
+            {"type": 4, "name": "page", "value": " 12 "},
+            {"type": 10, "name": "ratio", "value": "0.5"},
+            {"type": 5, "name": "public", "value": "true"}
+        ]"#;
+
+        let options: OptionList = serde_json::from_str(json).unwrap();
+
+        let count = options.get_integer_option("count").unwrap();
+        assert_eq!((count.value, count.raw_value()), (5, None));
+        let page = options.get_integer_option("page").unwrap();
+        assert_eq!((page.value, page.raw_value()), (12, Some(" 12 ")));
+
+        match options.get_option("ratio").unwrap() {
+            ApplicationCommandInteractionDataOption::Number(ratio) => assert_eq!(ratio.value, 0.5),
+            other => panic!("expected number option but got {:?}", other),
+        }
+
+        assert!(options.get_boolean_option("public").unwrap().value);
+
+        assert_eq!(
+            serde_json::to_value(options).unwrap()[1],
+            serde_json::json!({"type": 4, "name": "page", "value": 12})
+        );
+
+        let focused = serde_json::json!([
+            {"type": 3, "name": "query", "value": "rust"},
+            {"type": 4, "name": "page", "value": "12a", "focused": true}
+        ]);
+        let options: OptionList = serde_json::from_value(focused.clone()).unwrap();
+
+        assert_eq!(options.len(), 2);
+        assert!(options.get_integer_option("page").is_none());
+        match options.get_option("page").unwrap() {
+            ApplicationCommandInteractionDataOption::Unparsed(page) => {
+                assert_eq!((page.option_type, page.raw.as_str()), (4, "12a"))
+            }
+            other => panic!("expected unparsed option but got {:?}", other),
+        }
+        assert!(options.focused().is_some());
+        assert_eq!(serde_json::to_value(options).unwrap(), focused);
+
+        let unfocused = r#"[{"type": 4, "name": "page", "value": "12a"}]"#;
+        assert!(serde_json::from_str::<OptionList>(unfocused).is_err());
+    }
+
+    #[test]
+    pub fn selection_reports_unresolved() {
+        let data: MessageComponentData = serde_json::from_str(
+            r#"{
+                "custom_id": "roles",
+                "component_type": 6,
+                "values": ["500000000000000001", "500000000000000002", "not an id"],
+                "resolved": {
+                    "roles": {
+                        "500000000000000001": {
+                            "id": "500000000000000001",
+                            "name": "role",
+                            "color": 0,
+                            "hoist": false,
+                            "position": 1,
+                            "permissions": "0",
+                            "managed": false,
+                            "mentionable": true,
+                            "flags": 0
+                        }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let selection = data.resolved_selection().unwrap();
+
+        match selection.selected {
+            SelectedEntities::Roles(roles) => assert_eq!(roles[0].1.name, "role"),
+            other => panic!("expected roles but got {:?}", other),
+        }
+        assert_eq!(selection.unresolved, ["500000000000000002", "not an id"]);
+    }
+
+    #[test]
+    pub fn snowflake_option_values() {
+        let cases = [(6, "user"), (7, "channel"), (8, "role"), (9, "mentionable")];
+
+        for (t, name) in cases {
+            let option: ApplicationCommandInteractionDataOption = serde_json::from_str(&format!(
+                r#"{{"type": {t}, "name": "{name}", "value": "400000000000000001"}}"#
+            ))
+            .unwrap();
+
+            let value = match &option {
+                ApplicationCommandInteractionDataOption::User(o) if t == 6 => &o.value,
+                ApplicationCommandInteractionDataOption::Channel(o) if t == 7 => &o.value,
+                ApplicationCommandInteractionDataOption::Role(o) if t == 8 => &o.value,
+                ApplicationCommandInteractionDataOption::Mentionable(o) if t == 9 => &o.value,
+                other => panic!("unexpected option for type {t}: {other:?}"),
+            };
+
+            assert_eq!(value, &Snowflake::from_u64(400000000000000001));
+            assert_eq!(option.name(), Some(name));
+        }
+
+        let invalid = serde_json::from_str::<ApplicationCommandInteractionDataOption>(
+            r#"{"type": 6, "name": "user", "value": "not an id"}"#,
+        );
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    pub fn missing_and_empty_options_match() {
+        let cases = [
+            r#"{"id": "800000000000000001", "name": "ping", "type": 1}"#,
+            r#"{"id": "800000000000000001", "name": "ping", "type": 1, "options": []}"#,
+            r#"{"id": "800000000000000001", "name": "ping", "type": 1, "options": null}"#,
+        ];
+
+        for json in cases {
+            let data: ApplicationCommandInteractionData = serde_json::from_str(json).unwrap();
+
+            assert!(data.options.is_empty(), "{json}");
+            assert_eq!(data.options.len(), 0);
+            assert!(data.first_option().is_none());
+            assert!(data.options.subcommand().is_none());
+            assert!(data.options.subcommand_group().is_none());
+            assert!(data.options.get_string_option("query").is_none());
+            assert!(data.channel_option("channel").is_none());
+            assert_eq!(serde_json::to_string(&data.options).unwrap(), "[]");
+        }
+
+        let data: ApplicationCommandInteractionData = serde_json::from_str(
+            r#"{"id": "800000000000000001", "name": "ping", "type": 1, "options": [
+                {"type": 3, "name": "query", "value": "composure"}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(data.options.len(), 1);
+        assert_eq!(data.first_option().and_then(|o| o.name()), Some("query"));
+    }
+
+    #[test]
+    pub fn option_lookup_duplicates() {
+        let options: OptionList = serde_json::from_str(
+            r#"[
+                {"type": 4, "name": "value", "value": 1},
+                {"type": 3, "name": "value", "value": "first"},
+                {"type": 3, "name": "value", "value": "second"},
+                {"type": 11},
+                {"type": 5, "name": "alpha", "value": true},
+                {"type": 3, "name": "zeta", "value": "last"}
+            ]"#,
+        )
+        .unwrap();
+
+        // first option with the name, whatever its type
+        assert!(matches!(
+            options.get_option("value"),
+            Some(ApplicationCommandInteractionDataOption::Integer(o)) if o.value == 1
+        ));
+
+        // first option with the name and the requested type
+        assert_eq!(options.get_string_option("value").unwrap().value, "first");
+        assert_eq!(options.get_integer_option("value").unwrap().value, 1);
+        assert!(options.get_boolean_option("value").is_none());
+
+        assert!(options.get_boolean_option("alpha").unwrap().value);
+        assert_eq!(options.get_string_option("zeta").unwrap().value, "last");
+        assert!(options.get_option("missing").is_none());
+        assert!(options.get_option("").is_none());
+
+        let many = options.get_many(&["zeta", "missing", "value"]);
+        assert_eq!(many.len(), 3);
+        assert_eq!(many[0].and_then(|o| o.name()), Some("zeta"));
+        assert!(many[1].is_none());
+        assert!(matches!(
+            many[2],
+            Some(ApplicationCommandInteractionDataOption::Integer(_))
+        ));
+    }
+
+    #[test]
+    pub fn subcommand_group_round_trip() {
+        let json = serde_json::json!([{
+            "type": 2,
+            "name": "notifications",
+            "options": [{
+                "type": 1,
+                "name": "set",
+                "options": [{"type": 7, "name": "channel", "value": "300000000000000001"}]
+            }]
+        }]);
+
+        let options: OptionList = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(options).unwrap(), json);
+    }
+
+    #[test]
+    pub fn modal_submit_with_label() {
+        let data: ModalSubmitData = serde_json::from_value(serde_json::json!({
+            "custom_id": "report",
+            "components": [
+                {
+                    "type": 1,
+                    "components": [{"type": 4, "custom_id": "details", "value": "Posting links"}]
+                },
+                {
+                    "type": 18,
+                    "component": {"type": 3, "custom_id": "reason", "values": ["spam"]}
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert!(matches!(data.components[1], ModalSubmitRow::Label(_)));
+
+        let details = data.component("details").unwrap();
+        assert_eq!(details.value.as_deref(), Some("Posting links"));
+
+        let reason = data.component("reason").unwrap();
+        assert!(matches!(reason.t, MessageComponentType::StringSelect));
+        assert_eq!(reason.values, Some(vec![String::from("spam")]));
+
+        assert!(data.component("missing").is_none());
+    }
+}
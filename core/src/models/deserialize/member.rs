@@ -0,0 +1,444 @@
+use serde::Deserialize;
+
+use crate::{
+    models::{
+        common::{Color, Permissions, Snowflake},
+        Avatar, ImageFormat, DISCORD_CDN,
+    },
+    Mentionable,
+};
+
+/// User object
+#[derive(Debug, Deserialize)]
+pub struct User {
+    /// User's [avatar hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub avatar: Option<String>,
+
+    /// User's 4 digit discord tag
+    pub discriminator: String,
+
+    /// User's display name
+    pub display_name: Option<String>,
+
+    /// User's display name set on their account, takes precedence over `display_name`
+    pub global_name: Option<String>,
+
+    /// User Id
+    pub id: Snowflake,
+
+    /// Public [flags](https://discord.com/developers/docs/resources/user#user-object-user-flags) on a user's account
+    pub public_flags: u64,
+
+    /// Users name - not unique
+    pub username: String,
+
+    /// User's [banner hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub banner: Option<String>,
+
+    /// User's banner color encoded as an integer representation of hexadecimal color code
+    pub accent_color: Option<u32>,
+
+    /// Data for the user's avatar decoration
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
+}
+
+/// [Avatar Decoration Data](https://discord.com/developers/docs/resources/user#avatar-decoration-data-object)
+#[derive(Debug, Deserialize)]
+pub struct AvatarDecorationData {
+    /// the [avatar decoration hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub asset: String,
+
+    /// id of the avatar decoration's SKU
+    pub sku_id: Snowflake,
+}
+
+impl User {
+    /// Url of the user's avatar decoration, if they have one
+    pub fn avatar_decoration_url(&self) -> Option<String> {
+        self.avatar_decoration_data.as_ref().map(|decoration| {
+            format!(
+                "{}/avatar-decoration-presets/{}.png",
+                DISCORD_CDN, decoration.asset
+            )
+        })
+    }
+
+    /// Url of the user's banner, if they have one
+    ///
+    /// Only animated banners (hash starting with `a_`) are available as gif, a png is returned
+    /// instead when a gif is requested for a static banner. `size` should be a power of 2 between
+    /// 16 and 4096.
+    pub fn banner_url(&self, format: ImageFormat, size: u16) -> Option<String> {
+        let hash = self.banner.as_ref()?;
+
+        let format = match format {
+            ImageFormat::Gif if !hash.starts_with("a_") => ImageFormat::Png,
+            format => format,
+        };
+
+        Some(format!(
+            "{}/banners/{}/{}.{}?size={}",
+            DISCORD_CDN,
+            self.id,
+            hash,
+            format.as_ref().to_lowercase(),
+            size
+        ))
+    }
+
+    /// Name to show for the user, their `global_name`, then `display_name`, then `username`
+    pub fn effective_name(&self) -> &str {
+        self.global_name
+            .as_deref()
+            .or(self.display_name.as_deref())
+            .unwrap_or(&self.username)
+    }
+
+    /// The user's accent color, or [Color::default] which Discord renders as no color
+    pub fn accent_color_or_default(&self) -> Color {
+        self.accent_color.map(Color).unwrap_or_default()
+    }
+
+    /// Index of the [default avatar](https://discord.com/developers/docs/reference#image-formatting-cdn-endpoints) shown when the user has none
+    ///
+    /// Users migrated to the new username system have a `"0"` discriminator and use
+    /// `(id >> 22) % 6`, legacy users use `discriminator % 5`.
+    pub fn default_avatar_index(&self) -> u64 {
+        match self.discriminator.parse::<u16>() {
+            Ok(discriminator) if discriminator != 0 => discriminator as u64 % 5,
+            _ => (self.id.to_u64() >> 22) % 6,
+        }
+    }
+}
+
+impl Avatar for User {
+    fn get_avatar_url(&self, preferred_format: ImageFormat) -> Option<String> {
+        if let Some(avatar) = &self.avatar {
+            let mut hash = avatar.clone();
+
+            if preferred_format == ImageFormat::Gif {
+                hash.insert_str(0, "a_");
+            }
+
+            return Some(format!(
+                "{}/avatars/{}/{}.{}",
+                Self::get_cdn_url(),
+                self.id.to_string(),
+                hash,
+                preferred_format.as_ref().to_lowercase()
+            ));
+        }
+
+        Some(format!(
+            "{}/embed/avatars/{}.png",
+            Self::get_cdn_url(),
+            self.default_avatar_index()
+        ))
+    }
+}
+
+impl Mentionable for User {
+    fn to_mention(&self) -> String {
+        self.id.mention_user()
+    }
+}
+
+/// Member as found in [resolved data](crate::models::ResolvedData), which leaves out `user`, `deaf`
+/// and `mute`
+///
+/// The user is resolved separately under the same id, see
+/// [resolved_member_with_user](crate::models::ApplicationCommandInteractionData::resolved_member_with_user).
+#[derive(Debug, Deserialize)]
+pub struct PartialMember {
+    /// Guild nickname
+    pub nick: Option<String>,
+
+    /// Guild member's [guild avatar hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub avatar: Option<String>,
+
+    /// Array of role snowflakes
+    pub roles: Vec<Snowflake>,
+
+    /// When the user joined the guild formatted as ISO timestamp
+    pub joined_at: String,
+
+    /// When the user started boosting the guild
+    pub premium_since: Option<String>,
+
+    /// Whether the user has not yet passed the guild's [Membership Screening requirements](https://discord.com/developers/docs/resources/guild#membership-screening-object)
+    pub pending: Option<bool>,
+
+    /// total permissions of the member in the channel, including overwrites, returned when in the interaction object
+    pub permissions: Permissions,
+}
+
+/// [Guild Member](https://discord.com/developers/docs/resources/guild#guild-member-object)
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    /// User this member represents, always included in an interaction's `member` unlike in
+    /// [PartialMember]
+    pub user: User,
+
+    /// Guild nickname
+    pub nick: Option<String>,
+
+    /// Guild member's [guild avatar hash](https://discord.com/developers/docs/reference#image-formatting)
+    pub avatar: Option<String>,
+
+    /// Array of role snowflakes
+    pub roles: Vec<Snowflake>,
+
+    /// When the user joined the guild formatted as ISO timestamp
+    pub joined_at: String,
+
+    /// When the user started boosting the guild
+    pub premium_since: Option<String>,
+
+    /// Whether the user is deafened in voice channels
+    pub deaf: bool,
+
+    /// Whether the user is muted in voice channels
+    pub mute: bool,
+
+    /// [Guild member flags](https://discord.com/developers/docs/resources/guild#guild-member-object-guild-member-flags)
+    pub flags: u64,
+
+    /// Whether the user has not yet passed the guild's [Membership Screening requirements](https://discord.com/developers/docs/resources/guild#membership-screening-object)
+    pub pending: Option<bool>,
+
+    /// total permissions of the member in the channel, including overwrites, returned when in the interaction object
+    pub permissions: Option<Permissions>,
+
+    /// when the user's [timeout](https://support.discord.com/hc/en-us/articles/4413305239191-Time-Out-FAQ) will expire and the user will be able to communicate in the guild again, null or a time in the past if the user is not timed out
+    pub communication_disabled_until: Option<String>,
+}
+
+impl Member {
+    /// Name shown in the guild, the member's `nick`, then the user's [effective_name](User::effective_name)
+    pub fn display_name(&self) -> &str {
+        self.nick
+            .as_deref()
+            .unwrap_or_else(|| self.user.effective_name())
+    }
+}
+
+impl Mentionable for Member {
+    fn to_mention(&self) -> String {
+        self.user.id.mention_user()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn avatar_url_valid() {
+        let user = User {
+            avatar: Some("fa82e15e24ee16c9fcbf8dd34d10b4cc".to_string()),
+            discriminator: "9846".to_string(),
+            display_name: None,
+            global_name: None,
+            id: Snowflake::from_u64(282265607313817601),
+            public_flags: 0,
+            username: "BlueFrog".to_string(),
+            banner: None,
+            accent_color: None,
+            avatar_decoration_data: None,
+        };
+
+        let url = user.get_avatar_url(ImageFormat::Webp);
+
+        assert!(url.is_some());
+
+        let url = url.unwrap();
+
+        assert_eq!("https://cdn.discordapp.com/avatars/282265607313817601/fa82e15e24ee16c9fcbf8dd34d10b4cc.webp", url.as_str());
+    }
+
+    #[test]
+    pub fn default_avatar_url_valid() {
+        let user = User {
+            avatar: None,
+            discriminator: "9846".to_string(),
+            display_name: None,
+            global_name: None,
+            id: Snowflake::from_u64(282265607313817601),
+            public_flags: 0,
+            username: "BlueFrog".to_string(),
+            banner: None,
+            accent_color: None,
+            avatar_decoration_data: None,
+        };
+
+        let url = user.get_avatar_url(ImageFormat::Webp);
+
+        assert!(url.is_some());
+
+        let url = url.unwrap();
+
+        assert_eq!(
+            "https://cdn.discordapp.com/embed/avatars/1.png",
+            url.as_str()
+        );
+    }
+
+    #[test]
+    pub fn default_avatar_url_migrated() {
+        let user = User {
+            avatar: None,
+            discriminator: "0".to_string(),
+            display_name: None,
+            global_name: None,
+            id: Snowflake::from_u64(1052322265397739523),
+            public_flags: 0,
+            username: "bluefrog".to_string(),
+            banner: None,
+            accent_color: None,
+            avatar_decoration_data: None,
+        };
+
+        // (1052322265397739523 >> 22) % 6
+        assert_eq!(user.default_avatar_index(), 5);
+        assert_eq!(
+            user.get_avatar_url(ImageFormat::Webp).unwrap(),
+            "https://cdn.discordapp.com/embed/avatars/5.png"
+        );
+    }
+
+    #[test]
+    pub fn default_avatar_index_by_discriminator() {
+        let cases = [
+            ("0001", 1),
+            ("0005", 0),
+            ("9846", 1),
+            ("9999", 4),
+            // migrated users fall back to the id, (282265607313817601 >> 22) % 6
+            ("0", 1),
+            ("0000", 1),
+        ];
+
+        for (discriminator, expected) in cases {
+            let user = User {
+                avatar: None,
+                discriminator: discriminator.to_string(),
+                display_name: None,
+                global_name: None,
+                id: Snowflake::from_u64(282265607313817601),
+                public_flags: 0,
+                username: "BlueFrog".to_string(),
+                banner: None,
+                accent_color: None,
+                avatar_decoration_data: None,
+            };
+
+            assert_eq!(user.default_avatar_index(), expected, "{discriminator}");
+        }
+    }
+
+    #[test]
+    pub fn effective_name_precedence() {
+        let cases = [
+            (Some("Global"), Some("Display"), "Global"),
+            (Some("Global"), None, "Global"),
+            (None, Some("Display"), "Display"),
+            (None, None, "username"),
+        ];
+
+        for (global_name, display_name, expected) in cases {
+            let user = User {
+                avatar: None,
+                discriminator: "0".to_string(),
+                display_name: display_name.map(String::from),
+                global_name: global_name.map(String::from),
+                id: Snowflake::from_u64(282265607313817601),
+                public_flags: 0,
+                username: "username".to_string(),
+                banner: None,
+                accent_color: None,
+                avatar_decoration_data: None,
+            };
+
+            assert_eq!(user.effective_name(), expected);
+        }
+    }
+
+    fn profile_user() -> User {
+        User {
+            avatar: None,
+            discriminator: "0".to_string(),
+            display_name: None,
+            global_name: None,
+            id: Snowflake::from_u64(282265607313817601),
+            public_flags: 0,
+            username: "BlueFrog".to_string(),
+            banner: Some("0123456789abcdef0123456789abcdef".to_string()),
+            accent_color: Some(0x5865f2),
+            avatar_decoration_data: Some(AvatarDecorationData {
+                asset: "a_fed43ab12698df65902ba06727e20c0e".to_string(),
+                sku_id: Snowflake::from_u64(1144058844004233369),
+            }),
+        }
+    }
+
+    #[test]
+    pub fn avatar_decoration_url_valid() {
+        let user = profile_user();
+
+        assert_eq!(
+            user.avatar_decoration_url().unwrap(),
+            "https://cdn.discordapp.com/avatar-decoration-presets/a_fed43ab12698df65902ba06727e20c0e.png"
+        );
+    }
+
+    #[test]
+    pub fn banner_url_valid() {
+        let user = profile_user();
+
+        assert_eq!(
+            user.banner_url(ImageFormat::Webp, 600).unwrap(),
+            "https://cdn.discordapp.com/banners/282265607313817601/0123456789abcdef0123456789abcdef.webp?size=600"
+        );
+
+        // static banners aren't available as gif
+        assert_eq!(
+            user.banner_url(ImageFormat::Gif, 1024).unwrap(),
+            "https://cdn.discordapp.com/banners/282265607313817601/0123456789abcdef0123456789abcdef.png?size=1024"
+        );
+    }
+
+    #[test]
+    pub fn animated_banner_url_valid() {
+        let mut user = profile_user();
+        user.banner = Some("a_0123456789abcdef0123456789abcdef".to_string());
+
+        assert_eq!(
+            user.banner_url(ImageFormat::Gif, 1024).unwrap(),
+            "https://cdn.discordapp.com/banners/282265607313817601/a_0123456789abcdef0123456789abcdef.gif?size=1024"
+        );
+        assert_eq!(
+            user.banner_url(ImageFormat::Png, 1024).unwrap(),
+            "https://cdn.discordapp.com/banners/282265607313817601/a_0123456789abcdef0123456789abcdef.png?size=1024"
+        );
+    }
+
+    #[test]
+    pub fn missing_profile_assets() {
+        let mut user = profile_user();
+        user.banner = None;
+        user.accent_color = None;
+        user.avatar_decoration_data = None;
+
+        assert!(user.banner_url(ImageFormat::Png, 1024).is_none());
+        assert!(user.avatar_decoration_url().is_none());
+        assert_eq!(user.accent_color_or_default(), Color(0));
+    }
+
+    #[test]
+    pub fn accent_color_valid() {
+        assert_eq!(
+            profile_user().accent_color_or_default(),
+            Color::from_rgb(0x58, 0x65, 0xf2)
+        );
+    }
+}
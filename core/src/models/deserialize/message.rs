@@ -0,0 +1,819 @@
+use core::str;
+
+use serde::{Deserialize, Serialize};
+use serde_repr::Deserialize_repr;
+
+use crate::models::{
+    ActionRow, Application, Attachment, Channel, Embed, Emoji, InteractionType, PartialMember,
+    Poll, RoleSubscriptionData, Snowflake, StickerItem, User,
+};
+
+/// [Message Structure](https://discord.com/developers/docs/resources/channel#message-object-message-structure)
+#[derive(Debug, Deserialize)]
+pub struct Message {
+    /// id of the message
+    pub id: Snowflake,
+
+    /// id of the channel the message was sent in
+    pub channel_id: Snowflake,
+
+    /// the author of this message (not guaranteed to be a valid user, see below)
+    pub author: User,
+
+    /// contents of the message
+    pub content: String,
+
+    /// when this message was sent
+    pub timestamp: String,
+
+    /// when this message was edited (or null if never)
+    pub edited_timestamp: Option<String>,
+
+    /// whether this was a TTS message
+    pub tts: bool,
+
+    /// whether this message mentions everyone
+    pub mention_everyone: bool,
+
+    /// users specifically mentioned in the message
+    pub mentions: Vec<User>,
+
+    /// ids of roles specifically mentioned in this message
+    pub mention_roles: Vec<Snowflake>,
+
+    /// channels specifically mentioned in this message
+    pub mention_channels: Option<Vec<ChannelMention>>,
+
+    /// any attached files
+    pub attachments: Vec<Attachment>,
+
+    /// any embedded content
+    pub embeds: Vec<Embed>,
+
+    /// reactions to the message
+    pub reactions: Option<Vec<Reaction>>,
+
+    // /// used for validating a message was sent
+    // pub nonce: Option<todo>,
+    /// whether this message is pinned
+    pub pinned: bool,
+
+    /// if the message is generated by a webhook, this is the webhook's id
+    pub webhook_id: Option<Snowflake>,
+
+    /// [type of message](https://discord.com/developers/docs/resources/channel#message-object-message-types)
+    #[serde(rename = "type")]
+    pub t: MessageType,
+
+    /// sent with Rich Presence-related chat embeds
+    pub activity: Option<MessageActivity>,
+
+    /// sent with Rich Presence-related chat embeds
+    pub application: Option<Application>,
+
+    /// if the message is an [Interaction](https://discord.com/developers/docs/interactions/receiving-and-responding) or application-owned webhook, this is the id of the application
+    pub application_id: Option<Snowflake>,
+
+    /// data showing the source of a crosspost, channel follow add, pin, or reply message
+    pub message_reference: Option<MessageReference>,
+
+    /// [message flags](https://discord.com/developers/docs/resources/channel#message-object-message-flags) combined as a [bitfield](https://en.wikipedia.org/wiki/Bit_field)
+    pub flags: Option<MessageFlags>,
+
+    /// the message associated with the message_reference, only sent for replies and `None` if the
+    /// original message was deleted
+    pub referenced_message: Option<Box<Message>>,
+
+    /// sent if the message is a response to an [Interaction](https://discord.com/developers/docs/interactions/receiving-and-responding)
+    pub interaction: Option<MessageInteraction>,
+
+    /// the thread that was started from this message, includes [thread member](https://discord.com/developers/docs/resources/channel#thread-member-object) object
+    pub thread: Option<Channel>,
+
+    /// sent if the message contains components like buttons, action rows, or other interactive components
+    pub components: Option<Vec<ActionRow>>,
+
+    /// sent if the message contains stickers
+    pub sticker_items: Option<Vec<StickerItem>>,
+
+    /// A generally increasing integer (there may be gaps or duplicates) that represents the approximate position of the message in a thread, it can be used to estimate the relative position of the message in a thread in company with total_message_sent on parent thread
+    pub position: Option<i32>,
+
+    /// data of the role subscription purchase or renewal that prompted this ROLE_SUBSCRIPTION_PURCHASE message
+    pub role_subscription_data: Option<RoleSubscriptionData>,
+
+    /// the poll in the message, if any
+    pub poll: Option<Poll>,
+}
+
+impl Message {
+    /// true if the message replies to another message, which may since have been deleted
+    pub fn is_reply(&self) -> bool {
+        self.t == MessageType::Reply && self.message_reference.is_some()
+    }
+
+    /// true if Discord posted the message about an event, like a member joining or a pin
+    pub fn is_system(&self) -> bool {
+        self.t.is_system()
+    }
+}
+
+/// [Channel Mention Object](https://discord.com/developers/docs/resources/channel#channel-mention-object)
+#[derive(Debug, Deserialize)]
+pub struct ChannelMention {
+    /// id of the channel
+    pub id: Snowflake,
+
+    /// id of the guild containing the channel
+    pub guild_id: Snowflake,
+
+    /// the [type of channel](https://discord.com/developers/docs/resources/channel#channel-object-channel-types)
+    #[serde(rename = "type")]
+    pub t: i32,
+
+    /// the name of the channel
+    pub name: String,
+}
+
+/// [Reaction Object](https://discord.com/developers/docs/resources/channel#reaction-object)
+#[derive(Debug, Deserialize)]
+pub struct Reaction {
+    /// times this emoji has been used to react
+    pub count: i32,
+
+    /// whether the current user reacted using this emoji
+    pub me: bool,
+
+    /// emoji information
+    pub emoji: Emoji,
+}
+
+/// [Message Types](https://discord.com/developers/docs/resources/channel#message-object-message-types)
+#[derive(Debug, Deserialize_repr, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum MessageType {
+    /// Deletable: true
+    Default = 0,
+
+    /// Deletable: false
+    RecipientAdd = 1,
+
+    /// Deletable: false
+    RecipientRemove = 2,
+
+    /// Deletable: false
+    Call = 3,
+
+    /// Deletable: false
+    ChannelNameChange = 4,
+
+    /// Deletable: false
+    ChannelIconChange = 5,
+
+    /// Deletable: true
+    ChannelPinnedMessage = 6,
+
+    /// Deletable: true
+    UserJoin = 7,
+
+    /// Deletable: true
+    GuildBoost = 8,
+
+    /// Deletable: true
+    GuildBoostTier1 = 9,
+
+    /// Deletable: true
+    GuildBoostTier2 = 10,
+
+    /// Deletable: true
+    GuildBoostTier3 = 11,
+
+    /// Deletable: true
+    ChannelFollowAdd = 12,
+
+    /// Deletable: false
+    GuildDiscoveryDisqualified = 14,
+
+    /// Deletable: false
+    GuildDiscoveryRequalified = 15,
+
+    /// Deletable: false
+    GuildDiscoveryGracePeriodInitialWarning = 16,
+
+    /// Deletable: false
+    GuildDiscoveryGracePeriodFinalWarning = 17,
+
+    /// Deletable: true
+    ThreadCreated = 18,
+
+    /// Deletable: true
+    Reply = 19,
+
+    /// Deletable: true
+    ChatInputCommand = 20,
+
+    /// Deletable: false
+    ThreadStarterMessage = 21,
+
+    /// Deletable: true
+    GuildInviteReminder = 22,
+
+    /// Deletable: true
+    ContextMenuCommand = 23,
+
+    /// Deletable: true, can only be deleted by members with MANAGE_MESSAGES permission
+    AutoModerationAction = 24,
+
+    /// Deletable: true
+    RoleSubscriptionPurchase = 25,
+
+    /// Deletable: true
+    InteractionPremiumUpsell = 26,
+
+    /// Deletable: true
+    StageStart = 27,
+
+    /// Deletable: true
+    StageEnd = 28,
+
+    /// Deletable: true
+    StageSpeaker = 29,
+
+    /// Deletable: true
+    StageTopic = 31,
+
+    /// Deletable: false
+    GuildApplicationPremiumSubscription = 32,
+
+    /// Deletable: true
+    GuildIncidentAlertModeEnabled = 36,
+
+    /// Deletable: true
+    GuildIncidentAlertModeDisabled = 37,
+
+    /// Deletable: true
+    GuildIncidentReportRaid = 38,
+
+    /// Deletable: true
+    GuildIncidentReportFalseAlarm = 39,
+
+    /// Deletable: true
+    PurchaseNotification = 44,
+
+    /// Deletable: true
+    PollResult = 46,
+}
+
+impl MessageType {
+    /// true for messages Discord posts about an event, false for ones written by a user or an
+    /// application, including replies and command responses
+    pub fn is_system(&self) -> bool {
+        !matches!(
+            self,
+            MessageType::Default
+                | MessageType::Reply
+                | MessageType::ChatInputCommand
+                | MessageType::ContextMenuCommand
+        )
+    }
+}
+
+/// [Message Activity Structure](https://discord.com/developers/docs/resources/channel#message-object-message-activity-structure)
+#[derive(Debug, Deserialize)]
+pub struct MessageActivity {
+    /// [type of message activity](https://discord.com/developers/docs/resources/channel#message-object-message-activity-types)
+    #[serde(rename = "type")]
+    pub t: MessageActivityType,
+
+    /// party_id from a [Rich Presence event](https://discord.com/developers/docs/rich-presence/how-to#updating-presence-update-presence-payload-fields)
+    pub party_id: Option<String>,
+}
+
+/// [Message Activity Types](https://discord.com/developers/docs/resources/channel#message-object-message-activity-types)
+#[derive(Debug, Deserialize_repr)]
+#[repr(u8)]
+pub enum MessageActivityType {
+    Join = 1,
+
+    Spectate = 2,
+
+    Listen = 3,
+
+    JoinRequest = 5,
+}
+
+bitflags::bitflags! {
+    /// [Message Flags](https://discord.com/developers/docs/resources/channel#message-object-message-flags)
+    #[derive(Debug)]
+    pub struct MessageFlags: u16 {
+        /// this message has been published to subscribed channels (via Channel Following)
+        const Crossposted = 1 << 0;
+
+        /// this message originated from a message in another channel (via Channel Following)
+        const IsCrosspost = 1 << 1;
+
+        /// do not include any embeds when serializing this message
+        const SuppressEmbeds = 1 << 2;
+
+        /// the source message for this crosspost has been deleted (via Channel Following)
+        const SourceMessageDeleted = 1 << 3;
+
+        /// this message came from the urgent message system
+        const Urgent = 1 << 4;
+
+        /// this message has an associated thread, with the same id as the message
+        const HasThread = 1 << 5;
+
+        /// this message is only visible to the user who invoked the Interaction
+        const Ephemeral = 1 << 6;
+
+        /// this message is an Interaction Response and the bot is "thinking"
+        const Loading = 1 << 7;
+
+        /// this message failed to mention some roles and add their members to the thread
+        const FailedToMentionSomeRolesInThread = 1 << 8;
+
+        /// this message will not trigger push and desktop notifications
+        const SuppressNotifications = 1 << 12;
+
+        /// this message is a voice message
+        const IsVoiceMessage = 1 << 13;
+    }
+}
+
+impl Serialize for MessageFlags {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u16(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageFlags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let bits = u16::deserialize(deserializer)?;
+        Ok(MessageFlags::from_bits_retain(bits))
+    }
+}
+
+/// [Message Interaction Structure](https://discord.com/developers/docs/interactions/receiving-and-responding#message-interaction-object-message-interaction-structure)
+#[derive(Debug, Deserialize)]
+pub struct MessageInteraction {
+    /// ID of the interaction
+    pub id: Snowflake,
+
+    /// Type of interaction
+    #[serde(rename = "type")]
+    pub t: InteractionType,
+
+    /// Name of the application command, including subcommands and subcommand groups
+    pub name: String,
+
+    /// User who invoked the interaction
+    pub user: User,
+
+    /// Member who invoked the interaction in the guild
+    pub member: Option<PartialMember>,
+}
+
+/// [Message Reference Structure](https://discord.com/developers/docs/resources/channel#message-reference-object-message-reference-structure)
+#[derive(Debug, Deserialize)]
+pub struct MessageReference {
+    /// id of the originating message
+    pub message_id: Option<Snowflake>,
+
+    /// id of the originating message's channel
+    pub channel_id: Option<Snowflake>,
+
+    /// id of the originating message's guild
+    pub guild_id: Option<Snowflake>,
+
+    /// when sending, whether to error if the referenced message doesn't exist instead of sending as a normal (non-reply) message, default true
+    pub fail_if_not_exists: Option<bool>,
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::models::{Component, PollLayoutType};
+
+    use super::*;
+
+    #[test]
+    pub fn message_with_embed_and_components() {
+        let json = r#"{
+            "id": "600000000000000001",
+            "channel_id": "300000000000000001",
+            "author": {
+                "id": "100000000000000001",
+                "username": "bot",
+                "avatar": null,
+                "discriminator": "1234",
+                "public_flags": 0,
+                "bot": true
+            },
+            "content": "",
+            "timestamp": "2023-05-01T00:00:00.000000+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [
+                {
+                    "type": "rich",
+                    "title": "Poll",
+                    "description": "Pick one",
+                    "color": 16711680,
+                    "fields": [
+                        {
+                            "name": "Votes",
+                            "value": "0",
+                            "inline": true
+                        }
+                    ]
+                }
+            ],
+            "pinned": false,
+            "type": 0,
+            "components": [
+                {
+                    "type": 1,
+                    "components": [
+                        {
+                            "type": 2,
+                            "label": "Vote",
+                            "style": 1,
+                            "custom_id": "vote"
+                        }
+                    ]
+                }
+            ]
+        }"#;
+
+        let message = serde_json::from_str::<Message>(json).unwrap();
+
+        assert_eq!(message.embeds.len(), 1);
+        assert_eq!(message.embeds[0].title.as_deref(), Some("Poll"));
+        assert_eq!(message.embeds[0].fields.as_ref().unwrap()[0].name, "Votes");
+
+        let rows = message.components.unwrap();
+        assert_eq!(rows.len(), 1);
+
+        match &rows[0].components[0] {
+            Component::Button(button) => {
+                assert_eq!(button.custom_id.as_deref(), Some("vote"));
+                assert_eq!(button.label.as_deref(), Some("Vote"));
+            }
+            other => panic!("expected button but got {:#?}", other),
+        }
+    }
+
+    #[test]
+    pub fn message_with_button_and_select_rows() {
+        let json = r#"{
+            "id": "600000000000000002",
+            "channel_id": "300000000000000001",
+            "author": {
+                "id": "100000000000000001",
+                "username": "bot",
+                "avatar": null,
+                "discriminator": "1234",
+                "public_flags": 0,
+                "bot": true
+            },
+            "content": "Pick a color",
+            "timestamp": "2023-05-01T00:00:00.000000+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+            "components": [
+                {
+                    "type": 1,
+                    "components": [
+                        {
+                            "type": 2,
+                            "label": "Confirm",
+                            "style": 3,
+                            "custom_id": "color:confirm"
+                        },
+                        {
+                            "type": 2,
+                            "label": "Docs",
+                            "style": 5,
+                            "url": "https://example.com"
+                        }
+                    ]
+                },
+                {
+                    "type": 1,
+                    "components": [
+                        {
+                            "type": 3,
+                            "custom_id": "color:pick",
+                            "placeholder": "Color",
+                            "min_values": 1,
+                            "max_values": 1,
+                            "options": [
+                                {"label": "Red", "value": "red", "default": true},
+                                {"label": "Blue", "value": "blue", "description": "Like the sky"}
+                            ]
+                        }
+                    ]
+                }
+            ]
+        }"#;
+
+        let message = serde_json::from_str::<Message>(json).unwrap();
+        let mut rows = message.components.unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].components.len(), 2);
+
+        match &rows[0].components[1] {
+            Component::Button(button) => {
+                assert_eq!(button.custom_id, None);
+                assert_eq!(button.url.as_deref(), Some("https://example.com"));
+            }
+            other => panic!("expected button but got {:#?}", other),
+        }
+
+        match &rows[1].components[0] {
+            Component::StringSelect(select) => {
+                assert_eq!(select.custom_id, "color:pick");
+                let options = select.options.as_ref().unwrap();
+                assert_eq!(options.len(), 2);
+                assert_eq!(options[0].default, Some(true));
+                assert_eq!(options[1].description.as_deref(), Some("Like the sky"));
+            }
+            other => panic!("expected string select but got {:#?}", other),
+        }
+
+        // rows edited and sent back, like when disabling a message's components after a click
+        rows.iter_mut()
+            .flat_map(|row| row.components.iter_mut())
+            .for_each(Component::disable);
+
+        let sent = serde_json::to_string(&rows).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<ActionRow>>(&sent).unwrap(), rows);
+    }
+
+    #[test]
+    pub fn reply_message() {
+        let author = r#"{
+            "id": "400000000000000001",
+            "username": "user",
+            "avatar": null,
+            "discriminator": "0",
+            "public_flags": 0
+        }"#;
+        let message = |id: &str, t: u8, content: &str, reference: &str| {
+            format!(
+                r#"{{
+                    "id": "{id}",
+                    "channel_id": "300000000000000001",
+                    "author": {author},
+                    "content": "{content}",
+                    "timestamp": "2023-05-01T00:00:00.000000+00:00",
+                    "edited_timestamp": null,
+                    "tts": false,
+                    "mention_everyone": false,
+                    "mentions": [],
+                    "mention_roles": [],
+                    "attachments": [],
+                    "embeds": [],
+                    "pinned": false,
+                    "type": {t}{reference}
+                }}"#
+            )
+        };
+
+        let original = message("600000000000000001", 0, "first", "");
+        let middle = message(
+            "600000000000000002",
+            19,
+            "second",
+            &format!(
+                r#", "message_reference": {{"message_id": "600000000000000001", "channel_id": "300000000000000001", "guild_id": "200000000000000001"}}, "referenced_message": {original}"#
+            ),
+        );
+        let reply = message(
+            "600000000000000003",
+            19,
+            "third",
+            &format!(
+                r#", "message_reference": {{"message_id": "600000000000000002", "channel_id": "300000000000000001"}}, "referenced_message": {middle}"#
+            ),
+        );
+
+        let reply = serde_json::from_str::<Message>(&reply).unwrap();
+
+        assert!(reply.is_reply());
+        assert_eq!(reply.t, MessageType::Reply);
+        assert!(!reply.is_system());
+        assert_eq!(
+            reply.message_reference.as_ref().unwrap().message_id,
+            Some(Snowflake::from_u64(600000000000000002))
+        );
+
+        // walk the chain back to the message that started it
+        let mut chain = vec![reply.content.as_str()];
+        let mut current = &reply;
+        while let Some(parent) = current.referenced_message.as_deref() {
+            chain.push(&parent.content);
+            current = parent;
+        }
+
+        assert_eq!(chain, ["third", "second", "first"]);
+        assert!(!current.is_reply());
+
+        // the original was deleted
+        let deleted = message(
+            "600000000000000004",
+            19,
+            "orphan",
+            r#", "message_reference": {"message_id": "600000000000000001"}, "referenced_message": null"#,
+        );
+        let deleted = serde_json::from_str::<Message>(&deleted).unwrap();
+
+        assert!(deleted.is_reply());
+        assert!(deleted.referenced_message.is_none());
+    }
+
+    #[test]
+    pub fn system_message_types() {
+        let cases = [
+            (0, MessageType::Default, false),
+            (7, MessageType::UserJoin, true),
+            (19, MessageType::Reply, false),
+            (20, MessageType::ChatInputCommand, false),
+            (23, MessageType::ContextMenuCommand, false),
+            (46, MessageType::PollResult, true),
+        ];
+
+        for (value, t, system) in cases {
+            let parsed = serde_json::from_str::<MessageType>(&value.to_string()).unwrap();
+
+            assert_eq!(parsed, t);
+            assert_eq!(parsed.is_system(), system, "{t:?}");
+        }
+    }
+
+    #[test]
+    pub fn message_with_poll() {
+        let json = r#"{
+            "id": "600000000000000002",
+            "channel_id": "300000000000000001",
+            "author": {
+                "id": "400000000000000001",
+                "username": "user",
+                "avatar": null,
+                "discriminator": "0",
+                "public_flags": 0
+            },
+            "content": "",
+            "timestamp": "2024-04-20T00:00:00.000000+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+            "poll": {
+                "question": {"text": "Best language?"},
+                "answers": [
+                    {"answer_id": 1, "poll_media": {"text": "Rust", "emoji": {"id": null, "name": "🦀"}}},
+                    {"answer_id": 2, "poll_media": {"text": "Go"}}
+                ],
+                "expiry": "2024-04-21T00:00:00.000000+00:00",
+                "allow_multiselect": false,
+                "layout_type": 1,
+                "results": {
+                    "is_finalized": false,
+                    "answer_counts": [
+                        {"id": 1, "count": 12, "me_voted": true}
+                    ]
+                }
+            }
+        }"#;
+
+        let message = serde_json::from_str::<Message>(json).unwrap();
+        let poll = message.poll.unwrap();
+
+        assert_eq!(poll.question.text.as_deref(), Some("Best language?"));
+        assert_eq!(poll.answers.len(), 2);
+        assert_eq!(poll.answers[0].answer_id, 1);
+        assert_eq!(
+            poll.answers[0]
+                .poll_media
+                .emoji
+                .as_ref()
+                .unwrap()
+                .name
+                .as_deref(),
+            Some("🦀")
+        );
+        assert!(poll.answers[1].poll_media.emoji.is_none());
+        assert!(!poll.allow_multiselect);
+        assert_eq!(poll.layout_type, PollLayoutType::Default);
+        assert_eq!(
+            serde_json::from_str::<PollLayoutType>("2").unwrap(),
+            PollLayoutType::Unknown(2)
+        );
+
+        let results = poll.results.unwrap();
+        assert!(!results.is_finalized);
+        assert_eq!(results.answer_counts[0].count, 12);
+        assert!(results.answer_counts[0].me_voted);
+    }
+
+    #[test]
+    pub fn button_component() {
+        let json = r#"{
+            "type": 1,
+            "components": [
+                {
+                    "type": 2,
+                    "label": "Click me!",
+                    "style": 1,
+                    "custom_id": "click_one"
+                }
+            ]
+        }"#;
+
+        let res = serde_json::from_str::<ActionRow>(json);
+
+        assert!(res.is_ok());
+
+        let action_row = res.unwrap();
+
+        assert_eq!(action_row.components.len(), 1);
+        assert!(matches!(action_row.components[0], Component::Button { .. }));
+    }
+
+    #[test]
+    pub fn select_menu_component() {
+        let json = r#" {
+            "type": 1,
+            "components": [
+                {
+                    "type": 3,
+                    "custom_id": "class_select_1",
+                    "options":[
+                        {
+                            "label": "Rogue",
+                            "value": "rogue",
+                            "description": "Sneak n stab",
+                            "emoji": {
+                                "name": "rogue",
+                                "id": "625891304148303894"
+                            }
+                        },
+                        {
+                            "label": "Mage",
+                            "value": "mage",
+                            "description": "Turn 'em into a sheep",
+                            "emoji": {
+                                "name": "mage",
+                                "id": "625891304081063986"
+                            }
+                        },
+                        {
+                            "label": "Priest",
+                            "value": "priest",
+                            "description": "You get heals when I'm done doing damage",
+                            "emoji": {
+                                "name": "priest",
+                                "id": "625891303795982337"
+                            }
+                        }
+                    ],
+                    "placeholder": "Choose a class",
+                    "min_values": 1,
+                    "max_values": 3
+                }
+            ]
+        }"#;
+
+        let res = serde_json::from_str::<ActionRow>(json);
+
+        assert!(res.is_ok());
+
+        let component = res.unwrap();
+
+        println!("{:#?}", component);
+    }
+}
@@ -0,0 +1,98 @@
+use serde::Deserialize;
+
+use crate::models::PartialEmoji;
+
+/// [Poll Object](https://discord.com/developers/docs/resources/poll#poll-object)
+#[derive(Debug, Deserialize)]
+pub struct Poll {
+    /// the question of the poll, only `text` is supported
+    pub question: PollMedia,
+
+    /// each of the answers available in the poll
+    pub answers: Vec<PollAnswer>,
+
+    /// the time when the poll ends, null for polls that never expire
+    pub expiry: Option<String>,
+
+    /// whether a user can select multiple answers
+    pub allow_multiselect: bool,
+
+    /// the [layout type](https://discord.com/developers/docs/resources/poll#layout-type) of the poll
+    pub layout_type: PollLayoutType,
+
+    /// the results of the poll, may be missing while votes are still being counted
+    pub results: Option<PollResults>,
+}
+
+/// [Poll Media Object](https://discord.com/developers/docs/resources/poll#poll-media-object)
+#[derive(Debug, Deserialize)]
+pub struct PollMedia {
+    /// the text of the field, 300 characters for a question and 55 for an answer
+    pub text: Option<String>,
+
+    /// the emoji of the field
+    pub emoji: Option<PartialEmoji>,
+}
+
+/// [Poll Answer Object](https://discord.com/developers/docs/resources/poll#poll-answer-object)
+#[derive(Debug, Deserialize)]
+pub struct PollAnswer {
+    /// the id of the answer, a number starting at 1 that labels each answer
+    pub answer_id: u32,
+
+    /// the data of the answer
+    pub poll_media: PollMedia,
+}
+
+/// [Poll Results Object](https://discord.com/developers/docs/resources/poll#poll-results-object)
+#[derive(Debug, Deserialize)]
+pub struct PollResults {
+    /// whether the votes have been precisely counted
+    pub is_finalized: bool,
+
+    /// the counts for each answer, answers without votes are left out
+    pub answer_counts: Vec<PollAnswerCount>,
+}
+
+/// [Poll Answer Count Object](https://discord.com/developers/docs/resources/poll#poll-results-object-poll-answer-count-object-structure)
+#[derive(Debug, Deserialize)]
+pub struct PollAnswerCount {
+    /// the `answer_id`
+    pub id: u32,
+
+    /// the number of votes for this answer
+    pub count: u32,
+
+    /// whether the current user voted for this answer
+    pub me_voted: bool,
+}
+
+/// [Layout Type](https://discord.com/developers/docs/resources/poll#layout-type)
+///
+/// Layouts Discord adds later parse as [Unknown](Self::Unknown), so their polls still
+/// deserialize.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PollLayoutType {
+    Default,
+
+    /// a layout type this version doesn't know about yet
+    Unknown(u8),
+}
+
+impl From<u8> for PollLayoutType {
+    fn from(t: u8) -> Self {
+        match t {
+            1 => PollLayoutType::Default,
+            t => PollLayoutType::Unknown(t),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PollLayoutType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        u8::deserialize(deserializer).map(PollLayoutType::from)
+    }
+}
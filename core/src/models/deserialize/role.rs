@@ -1,7 +1,9 @@
+use std::cmp::Ordering;
+
 use serde::{de::Visitor, Deserialize};
 
 use crate::{
-    models::{Permissions, Snowflake},
+    models::{Member, Permissions, Snowflake},
     Mentionable,
 };
 
@@ -44,7 +46,107 @@ pub struct Role {
 
 impl Mentionable for Role {
     fn to_mention(&self) -> String {
-        format!("<@&{}>", self.id)
+        self.id.mention_role()
+    }
+}
+
+impl Role {
+    /// Orders roles by their place in the hierarchy, roles sharing a position are ranked by id with the older (lower) id on top
+    pub fn compare_position(&self, other: &Role) -> Ordering {
+        self.position
+            .cmp(&other.position)
+            .then_with(|| other.id.to_u64().cmp(&self.id.to_u64()))
+    }
+}
+
+/// Highest ranked role of `roles`, if any
+pub fn highest_role<'a>(roles: impl Iterator<Item = &'a Role>) -> Option<&'a Role> {
+    roles.max_by(|a, b| a.compare_position(b))
+}
+
+/// A guild member as seen by the role hierarchy
+#[derive(Debug, Clone, Copy)]
+pub struct HierarchyMember<'a> {
+    /// id of the user
+    pub id: &'a Snowflake,
+
+    /// ids of the member's roles
+    pub roles: &'a [Snowflake],
+}
+
+impl<'a> HierarchyMember<'a> {
+    /// For members without a user attached, such as resolved [PartialMember](crate::models::PartialMember)s
+    pub fn new(id: &'a Snowflake, roles: &'a [Snowflake]) -> Self {
+        HierarchyMember { id, roles }
+    }
+
+    fn highest_role(&self, guild_roles: &'a [Role]) -> Option<&'a Role> {
+        highest_role(guild_roles.iter().filter(|r| self.roles.contains(&r.id)))
+    }
+}
+
+impl<'a> From<&'a Member> for HierarchyMember<'a> {
+    fn from(member: &'a Member) -> Self {
+        HierarchyMember::new(&member.user.id, &member.roles)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HierarchyError {
+    /// The guild owner can't be moderated
+    TargetIsOwner,
+
+    /// Both members' highest roles are the same
+    EqualPosition,
+
+    /// The target's highest role is above the invoker's
+    LowerPosition,
+}
+
+impl std::fmt::Display for HierarchyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HierarchyError::TargetIsOwner => write!(f, "the guild owner can't be moderated"),
+            HierarchyError::EqualPosition => write!(f, "both members' highest roles are the same"),
+            HierarchyError::LowerPosition => {
+                write!(f, "the target's highest role is above the invoker's")
+            }
+        }
+    }
+}
+
+impl std::error::Error for HierarchyError {}
+
+/// Checks the invoker's highest role outranks the target's
+///
+/// To check the bot can act as well, call this again with the bot's member as the invoker.
+pub fn can_moderate(
+    invoker: HierarchyMember,
+    target: HierarchyMember,
+    guild_roles: &[Role],
+    owner_id: &Snowflake,
+) -> Result<(), HierarchyError> {
+    if target.id == owner_id {
+        return Err(HierarchyError::TargetIsOwner);
+    }
+
+    if invoker.id == owner_id {
+        return Ok(());
+    }
+
+    // members without roles only have @everyone, which is below every other role
+    let ordering = match (
+        invoker.highest_role(guild_roles),
+        target.highest_role(guild_roles),
+    ) {
+        (Some(invoker), Some(target)) => invoker.compare_position(target),
+        (invoker, target) => invoker.is_some().cmp(&target.is_some()),
+    };
+
+    match ordering {
+        Ordering::Greater => Ok(()),
+        Ordering::Equal => Err(HierarchyError::EqualPosition),
+        Ordering::Less => Err(HierarchyError::LowerPosition),
     }
 }
 
@@ -213,6 +315,141 @@ impl<'de> Deserialize<'de> for RoleTags {
 mod tests {
     use super::*;
 
+    fn role(id: u64, position: i32) -> Role {
+        Role {
+            id: Snowflake::from_u64(id),
+            name: format!("role {id}"),
+            color: 0,
+            hoist: false,
+            icon: None,
+            unicode_emoji: None,
+            position,
+            permissions: Permissions::empty(),
+            managed: false,
+            mentionable: false,
+            tags: None,
+        }
+    }
+
+    #[test]
+    pub fn role_ordering() {
+        let cases = [
+            // (a, b, expected)
+            (role(1, 2), role(2, 1), Ordering::Greater),
+            (role(2, 1), role(1, 2), Ordering::Less),
+            (role(1, 1), role(1, 1), Ordering::Equal),
+            // same position, lower id ranks higher
+            (role(1, 1), role(2, 1), Ordering::Greater),
+            (role(2, 1), role(1, 1), Ordering::Less),
+            // position wins over id
+            (role(2, 3), role(1, 1), Ordering::Greater),
+        ];
+
+        for (a, b, expected) in cases {
+            assert_eq!(
+                a.compare_position(&b),
+                expected,
+                "({}, {}) vs ({}, {})",
+                a.id,
+                a.position,
+                b.id,
+                b.position
+            );
+        }
+    }
+
+    #[test]
+    pub fn highest_role_picks_top() {
+        let roles = [role(3, 1), role(1, 5), role(2, 5)];
+
+        assert_eq!(
+            highest_role(roles.iter()).unwrap().id,
+            Snowflake::from_u64(1)
+        );
+        assert!(highest_role([].iter()).is_none());
+    }
+
+    #[test]
+    pub fn moderation_hierarchy() {
+        let guild_roles = [role(10, 1), role(11, 2), role(12, 2), role(13, 3)];
+        let owner = Snowflake::from_u64(1);
+        let invoker = Snowflake::from_u64(2);
+        let target = Snowflake::from_u64(3);
+
+        let ids = |ids: &[u64]| {
+            ids.iter()
+                .map(|id| Snowflake::from_u64(*id))
+                .collect::<Vec<_>>()
+        };
+
+        let cases = [
+            // (invoker id, invoker roles, target id, target roles, expected)
+            (&invoker, ids(&[13]), &target, ids(&[11]), Ok(())),
+            (
+                &invoker,
+                ids(&[11]),
+                &target,
+                ids(&[13]),
+                Err(HierarchyError::LowerPosition),
+            ),
+            (
+                &invoker,
+                ids(&[11]),
+                &target,
+                ids(&[11]),
+                Err(HierarchyError::EqualPosition),
+            ),
+            // id tiebreaker between roles at the same position
+            (&invoker, ids(&[11]), &target, ids(&[12]), Ok(())),
+            (
+                &invoker,
+                ids(&[12]),
+                &target,
+                ids(&[11]),
+                Err(HierarchyError::LowerPosition),
+            ),
+            // highest role counts, not the first listed
+            (&invoker, ids(&[10, 13]), &target, ids(&[12]), Ok(())),
+            (&invoker, ids(&[10]), &target, ids(&[]), Ok(())),
+            (
+                &invoker,
+                ids(&[]),
+                &target,
+                ids(&[]),
+                Err(HierarchyError::EqualPosition),
+            ),
+            (
+                &invoker,
+                ids(&[]),
+                &target,
+                ids(&[10]),
+                Err(HierarchyError::LowerPosition),
+            ),
+            // owner
+            (&owner, ids(&[]), &target, ids(&[13]), Ok(())),
+            (
+                &invoker,
+                ids(&[13]),
+                &owner,
+                ids(&[]),
+                Err(HierarchyError::TargetIsOwner),
+            ),
+        ];
+
+        for (i, (invoker_id, invoker_roles, target_id, target_roles, expected)) in
+            cases.into_iter().enumerate()
+        {
+            let res = can_moderate(
+                HierarchyMember::new(invoker_id, &invoker_roles),
+                HierarchyMember::new(target_id, &target_roles),
+                &guild_roles,
+                &owner,
+            );
+
+            assert_eq!(res, expected, "case {i}");
+        }
+    }
+
     #[test]
     pub fn role_tags_deserializes_all_true() {
         let json = r#"{
@@ -0,0 +1,127 @@
+use bitflags::bitflags;
+use serde::Deserialize;
+use serde_repr::Deserialize_repr;
+
+use crate::models::Snowflake;
+
+/// [SKU Object](https://discord.com/developers/docs/monetization/skus#sku-object)
+#[derive(Debug, Deserialize)]
+pub struct Sku {
+    /// ID of SKU
+    pub id: Snowflake,
+
+    /// [type of SKU](https://discord.com/developers/docs/monetization/skus#sku-object-sku-types)
+    #[serde(rename = "type")]
+    pub t: SkuType,
+
+    /// ID of the parent application
+    pub application_id: Snowflake,
+
+    /// customer-facing name of your premium offering
+    pub name: String,
+
+    /// system-generated URL slug based on the SKU's name
+    pub slug: String,
+
+    /// [SKU flags](https://discord.com/developers/docs/monetization/skus#sku-object-sku-flags) combined as a [bitfield](https://en.wikipedia.org/wiki/Bit_field)
+    pub flags: SkuFlags,
+}
+
+impl Sku {
+    /// true if the SKU can be purchased
+    pub fn is_available(&self) -> bool {
+        self.flags.contains(SkuFlags::Available)
+    }
+}
+
+/// [SKU Types](https://discord.com/developers/docs/monetization/skus#sku-object-sku-types)
+#[derive(Debug, Deserialize_repr, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum SkuType {
+    /// Durable one-time purchase
+    Durable = 2,
+
+    /// Consumable one-time purchase
+    Consumable = 3,
+
+    /// Represents a recurring subscription
+    Subscription = 5,
+
+    /// System-generated group for each SUBSCRIPTION SKU created
+    SubscriptionGroup = 6,
+}
+
+bitflags! {
+    /// [SKU Flags](https://discord.com/developers/docs/monetization/skus#sku-object-sku-flags)
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct SkuFlags: u32 {
+        /// SKU is available for purchase
+        const Available = 1 << 2;
+
+        /// Recurring SKU that can be purchased by a user and applied to a single server. Grants access to every user in that server.
+        const GuildSubscription = 1 << 7;
+
+        /// Recurring SKU purchased by a user for themselves. Grants access to the purchasing user in every server.
+        const UserSubscription = 1 << 8;
+    }
+}
+
+impl<'de> Deserialize<'de> for SkuFlags {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let bits = u32::deserialize(deserializer)?;
+        Ok(SkuFlags::from_bits_retain(bits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn subscription_sku() {
+        let skus: Vec<Sku> = serde_json::from_str(
+            r#"[
+                {
+                    "id": "1088510053304066049",
+                    "type": 5,
+                    "dependent_sku_id": null,
+                    "application_id": "788708323867885999",
+                    "manifest_labels": null,
+                    "access_type": 1,
+                    "name": "Test Premium",
+                    "features": [],
+                    "release_date": null,
+                    "premium": false,
+                    "slug": "test-premium",
+                    "flags": 128,
+                    "show_age_gate": false
+                },
+                {
+                    "id": "1088510058284990888",
+                    "type": 6,
+                    "dependent_sku_id": null,
+                    "application_id": "788708323867885999",
+                    "manifest_labels": null,
+                    "access_type": 1,
+                    "name": "Test Premium",
+                    "features": [],
+                    "release_date": null,
+                    "premium": false,
+                    "slug": "test-premium",
+                    "flags": 128,
+                    "show_age_gate": false
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(skus[0].t, SkuType::Subscription);
+        assert_eq!(skus[0].flags, SkuFlags::GuildSubscription);
+        assert!(!skus[0].is_available());
+        assert_eq!(skus[1].t, SkuType::SubscriptionGroup);
+        assert_eq!(skus[1].slug, "test-premium");
+    }
+}
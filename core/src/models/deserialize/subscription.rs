@@ -0,0 +1,91 @@
+use serde::Deserialize;
+use serde_repr::Deserialize_repr;
+
+use crate::models::Snowflake;
+
+/// [Subscription Object](https://discord.com/developers/docs/resources/subscription#subscription-object)
+#[derive(Debug, Deserialize)]
+pub struct Subscription {
+    /// ID of the subscription
+    pub id: Snowflake,
+
+    /// ID of the user who is subscribed
+    pub user_id: Snowflake,
+
+    /// list of SKUs subscribed to
+    pub sku_ids: Vec<Snowflake>,
+
+    /// list of entitlements granted for this subscription
+    pub entitlement_ids: Vec<Snowflake>,
+
+    /// list of SKUs that this user will be subscribed to at renewal
+    pub renewal_sku_ids: Option<Vec<Snowflake>>,
+
+    /// start of the current subscription period
+    pub current_period_start: String,
+
+    /// end of the current subscription period
+    pub current_period_end: String,
+
+    /// current status of the subscription
+    pub status: SubscriptionStatus,
+
+    /// when the subscription was canceled
+    pub canceled_at: Option<String>,
+
+    /// ISO3166-1 alpha-2 country code of the payment source used to purchase the subscription, missing unless queried with a private OAuth scope
+    pub country: Option<String>,
+}
+
+impl Subscription {
+    /// true until the end of the paid period, even if the subscription won't renew
+    pub fn is_active(&self) -> bool {
+        self.status != SubscriptionStatus::Inactive
+    }
+}
+
+/// [Subscription Statuses](https://discord.com/developers/docs/resources/subscription#subscription-statuses)
+#[derive(Debug, Deserialize_repr, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum SubscriptionStatus {
+    /// Subscription is active and scheduled to renew
+    Active = 0,
+
+    /// Subscription is active but will not renew
+    Ending = 1,
+
+    /// Subscription is inactive and not being charged
+    Inactive = 2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn ending_subscription() {
+        let subscription: Subscription = serde_json::from_str(
+            r#"{
+                "id": "1278078770116427839",
+                "user_id": "1088605110638227537",
+                "sku_ids": ["1158857122189168803"],
+                "entitlement_ids": [],
+                "renewal_sku_ids": null,
+                "current_period_start": "2024-08-27T19:48:44.406602+00:00",
+                "current_period_end": "2024-09-27T19:48:44.406602+00:00",
+                "status": 1,
+                "canceled_at": "2024-08-28T10:12:03.000000+00:00"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(subscription.status, SubscriptionStatus::Ending);
+        assert!(subscription.is_active());
+        assert_eq!(
+            subscription.sku_ids,
+            [Snowflake::from_u64(1158857122189168803)]
+        );
+        assert!(subscription.renewal_sku_ids.is_none());
+        assert!(subscription.country.is_none());
+    }
+}
@@ -0,0 +1,9 @@
+mod channel;
+mod interaction_response;
+mod message_content;
+mod role;
+
+pub use channel::*;
+pub use interaction_response::*;
+pub use message_content::*;
+pub use role::*;
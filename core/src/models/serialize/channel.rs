@@ -0,0 +1,373 @@
+use serde::Serialize;
+
+use crate::models::{
+    ChannelFlags, ChannelType, DefaultReaction, ForumLayoutType, Overwrite, Patch, Snowflake,
+    SortOrderType,
+};
+
+/// Tag of a forum or media channel for [CreateChannel] and [ModifyChannel]
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ForumTagData {
+    /// id of an existing tag to keep, `None` creates a new tag
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Snowflake>,
+
+    /// the name of the tag (0-20 characters)
+    pub name: String,
+
+    /// whether this tag can only be added to or removed from threads by a member with the MANAGE_THREADS permission
+    pub moderated: bool,
+
+    /// the id of a guild's custom emoji, at most one of `emoji_id` and `emoji_name` may be set
+    pub emoji_id: Option<Snowflake>,
+
+    /// the unicode character of the emoji
+    pub emoji_name: Option<String>,
+}
+
+impl ForumTagData {
+    pub fn new(name: impl Into<String>) -> Self {
+        ForumTagData {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_emoji_name(mut self, emoji: impl Into<String>) -> Self {
+        self.emoji_name = Some(emoji.into());
+        self
+    }
+
+    pub fn with_emoji_id(mut self, emoji_id: Snowflake) -> Self {
+        self.emoji_id = Some(emoji_id);
+        self
+    }
+
+    pub fn moderated(mut self) -> Self {
+        self.moderated = true;
+        self
+    }
+}
+
+/// [Create Guild Channel](https://discord.com/developers/docs/resources/guild#create-guild-channel-json-params) body
+#[derive(Debug, Serialize)]
+pub struct CreateChannel {
+    /// channel name (1-100 characters)
+    pub name: String,
+
+    /// the [type of channel](https://discord.com/developers/docs/resources/channel#channel-object-channel-types)
+    #[serde(rename = "type")]
+    pub t: ChannelType,
+
+    /// channel topic (0-1024 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+
+    /// the bitrate (in bits) of the voice or stage channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bitrate: Option<u32>,
+
+    /// the user limit of the voice channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_limit: Option<u32>,
+
+    /// amount of seconds a user has to wait before sending another message (0-21600)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<u32>,
+
+    /// sorting position of the channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i32>,
+
+    /// the channel's permission overwrites
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_overwrites: Option<Vec<Overwrite>>,
+
+    /// id of the parent category for a channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Snowflake>,
+
+    /// whether the channel is nsfw
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nsfw: Option<bool>,
+
+    /// the default duration in minutes that clients use to automatically archive new threads
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_auto_archive_duration: Option<u32>,
+
+    /// emoji to show in the add reaction button on a thread in a forum or media channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_reaction_emoji: Option<DefaultReaction>,
+
+    /// set of tags that can be used in a forum or media channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_tags: Option<Vec<ForumTagData>>,
+
+    /// the default sort order for posts in a forum or media channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_sort_order: Option<SortOrderType>,
+
+    /// the default layout of posts in a forum channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_forum_layout: Option<ForumLayoutType>,
+
+    /// the initial rate_limit_per_user to set on newly created threads in a channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_thread_rate_limit_per_user: Option<u32>,
+}
+
+impl CreateChannel {
+    pub fn new(name: impl Into<String>, t: ChannelType) -> Self {
+        CreateChannel {
+            name: name.into(),
+            t,
+            topic: None,
+            bitrate: None,
+            user_limit: None,
+            rate_limit_per_user: None,
+            position: None,
+            permission_overwrites: None,
+            parent_id: None,
+            nsfw: None,
+            default_auto_archive_duration: None,
+            default_reaction_emoji: None,
+            available_tags: None,
+            default_sort_order: None,
+            default_forum_layout: None,
+            default_thread_rate_limit_per_user: None,
+        }
+    }
+
+    pub fn with_topic(mut self, topic: impl Into<String>) -> Self {
+        self.topic = Some(topic.into());
+        self
+    }
+
+    pub fn with_rate_limit_per_user(mut self, seconds: u32) -> Self {
+        self.rate_limit_per_user = Some(seconds);
+        self
+    }
+
+    pub fn with_position(mut self, position: i32) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn with_permission_overwrites(mut self, overwrites: Vec<Overwrite>) -> Self {
+        self.permission_overwrites = Some(overwrites);
+        self
+    }
+
+    /// Places the channel in the category `parent_id`
+    pub fn with_parent(mut self, parent_id: Snowflake) -> Self {
+        self.parent_id = Some(parent_id);
+        self
+    }
+
+    pub fn with_nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = Some(nsfw);
+        self
+    }
+
+    pub fn with_default_reaction_emoji(mut self, emoji: DefaultReaction) -> Self {
+        self.default_reaction_emoji = Some(emoji);
+        self
+    }
+
+    pub fn with_available_tags(mut self, tags: Vec<ForumTagData>) -> Self {
+        self.available_tags = Some(tags);
+        self
+    }
+
+    pub fn with_default_sort_order(mut self, sort_order: SortOrderType) -> Self {
+        self.default_sort_order = Some(sort_order);
+        self
+    }
+}
+
+/// [Modify Channel](https://discord.com/developers/docs/resources/channel#modify-channel-json-params-guild-channel) body for guild channels
+///
+/// Only the fields that are set or cleared are sent, the rest of the channel is left untouched.
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyChannel {
+    /// channel name (1-100 characters)
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub name: Patch<String>,
+
+    /// only text and announcement channels can be converted to each other
+    #[serde(rename = "type", skip_serializing_if = "Patch::is_keep")]
+    pub t: Patch<ChannelType>,
+
+    /// sorting position of the channel
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub position: Patch<i32>,
+
+    /// channel topic (0-1024 characters)
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub topic: Patch<String>,
+
+    /// whether the channel is nsfw
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub nsfw: Patch<bool>,
+
+    /// amount of seconds a user has to wait before sending another message (0-21600)
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub rate_limit_per_user: Patch<u32>,
+
+    /// the bitrate (in bits) of the voice or stage channel
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub bitrate: Patch<u32>,
+
+    /// the user limit of the voice channel
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub user_limit: Patch<u32>,
+
+    /// channel or category-specific permissions
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub permission_overwrites: Patch<Vec<Overwrite>>,
+
+    /// id of the new parent category for a channel
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub parent_id: Patch<Snowflake>,
+
+    /// the default duration in minutes that clients use to automatically archive new threads
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub default_auto_archive_duration: Patch<u32>,
+
+    /// [channel flags](https://discord.com/developers/docs/resources/channel#channel-object-channel-flags), only REQUIRE_TAG is supported
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub flags: Patch<ChannelFlags>,
+
+    /// set of tags that can be used in a forum or media channel, tags not listed are removed
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub available_tags: Patch<Vec<ForumTagData>>,
+
+    /// emoji to show in the add reaction button on a thread in a forum or media channel
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub default_reaction_emoji: Patch<DefaultReaction>,
+
+    /// the initial rate_limit_per_user to set on newly created threads in a channel
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub default_thread_rate_limit_per_user: Patch<u32>,
+
+    /// the default sort order for posts in a forum or media channel
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub default_sort_order: Patch<SortOrderType>,
+
+    /// the default layout of posts in a forum channel
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub default_forum_layout: Patch<ForumLayoutType>,
+}
+
+impl ModifyChannel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Patch::Set(name.into());
+        self
+    }
+
+    pub fn with_topic(mut self, topic: impl Into<String>) -> Self {
+        self.topic = Patch::Set(topic.into());
+        self
+    }
+
+    pub fn clear_topic(mut self) -> Self {
+        self.topic = Patch::Clear;
+        self
+    }
+
+    /// Slowmode, 0 turns it off
+    pub fn with_rate_limit_per_user(mut self, seconds: u32) -> Self {
+        self.rate_limit_per_user = Patch::Set(seconds);
+        self
+    }
+
+    pub fn with_nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = Patch::Set(nsfw);
+        self
+    }
+
+    pub fn with_permission_overwrites(mut self, overwrites: Vec<Overwrite>) -> Self {
+        self.permission_overwrites = Patch::Set(overwrites);
+        self
+    }
+
+    /// Moves the channel into the category `parent_id`
+    pub fn with_parent(mut self, parent_id: Snowflake) -> Self {
+        self.parent_id = Patch::Set(parent_id);
+        self
+    }
+
+    /// Moves the channel out of its category
+    pub fn clear_parent(mut self) -> Self {
+        self.parent_id = Patch::Clear;
+        self
+    }
+
+    pub fn with_available_tags(mut self, tags: Vec<ForumTagData>) -> Self {
+        self.available_tags = Patch::Set(tags);
+        self
+    }
+
+    pub fn with_default_reaction_emoji(mut self, emoji: DefaultReaction) -> Self {
+        self.default_reaction_emoji = Patch::Set(emoji);
+        self
+    }
+
+    pub fn clear_default_reaction_emoji(mut self) -> Self {
+        self.default_reaction_emoji = Patch::Clear;
+        self
+    }
+
+    pub fn with_default_sort_order(mut self, sort_order: SortOrderType) -> Self {
+        self.default_sort_order = Patch::Set(sort_order);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::models::Permissions;
+
+    #[test]
+    pub fn create_channel_serializes() {
+        let channel = CreateChannel::new("rules", ChannelType::GuildText)
+            .with_parent(Snowflake::from_u64(300000000000000010))
+            .with_permission_overwrites(vec![Overwrite::role(
+                Snowflake::from_u64(200000000000000001),
+                Permissions::ViewChannel,
+                Permissions::SendMessages,
+            )]);
+
+        assert_eq!(
+            serde_json::to_value(channel).unwrap(),
+            json!({
+                "name": "rules",
+                "type": 0,
+                "parent_id": "300000000000000010",
+                "permission_overwrites": [
+                    {"id": "200000000000000001", "type": 0, "allow": "1024", "deny": "2048"}
+                ]
+            })
+        );
+    }
+
+    #[test]
+    pub fn modify_channel_patch() {
+        let channel = ModifyChannel::new()
+            .with_name("general")
+            .clear_topic()
+            .clear_parent();
+
+        assert_eq!(
+            serde_json::to_value(channel).unwrap(),
+            json!({ "name": "general", "topic": null, "parent_id": null })
+        );
+    }
+}
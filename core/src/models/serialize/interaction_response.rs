@@ -0,0 +1,770 @@
+use std::collections::HashMap;
+
+use serde::{ser::SerializeMap, Serialize};
+
+use crate::models::{
+    ActionRow, AllowedMentions, Component, Embed, Label, MessageFlags, PartialAttachment,
+};
+
+/// Most choices an autocomplete response may hold
+pub const AUTOCOMPLETE_CHOICE_LIMIT: usize = 25;
+
+const TYPE_KEY: &str = "type";
+const DATA_KEY: &str = "data";
+
+/// [Interaction Response Structure](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-interaction-response-structure)
+#[derive(Debug)]
+pub enum InteractionResponse {
+    /// ACK to a `ping`
+    Pong,
+
+    /// Respond to an interaction with a message
+    ChannelMessageWithSource(MessageCallbackData),
+
+    /// ACK an interaction and edit a response later, the user sees a loading state
+    DeferredChannelMessageWithSource,
+
+    /// for components, ACK an interaction and edit the original message later; the user does not see a loading state
+    DeferredUpdateMessage,
+
+    /// for components, edit the message the component was attached to
+    UpdateMessage(UpdateMessageData),
+
+    /// respond to an autocomplete interaction with suggested choices
+    ApplicationCommandAutocompleteResult(AutocompleteCallbackData),
+
+    /// respond to an interaction with a popup modal
+    Modal(ModalCallbackData),
+}
+
+impl InteractionResponse {
+    /// [Interaction callback type](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-interaction-callback-type) sent as `type`
+    pub fn response_type(&self) -> u8 {
+        match self {
+            InteractionResponse::Pong => 1,
+            InteractionResponse::ChannelMessageWithSource(_) => 4,
+            InteractionResponse::DeferredChannelMessageWithSource => 5,
+            InteractionResponse::DeferredUpdateMessage => 6,
+            InteractionResponse::UpdateMessage(_) => 7,
+            InteractionResponse::ApplicationCommandAutocompleteResult(_) => 8,
+            InteractionResponse::Modal(_) => 9,
+        }
+    }
+
+    pub fn respond_with_message(content: impl Into<String>) -> Self {
+        InteractionResponse::ChannelMessageWithSource(MessageCallbackData {
+            tts: None,
+            content: Some(content.into()),
+            embeds: None,
+            allowed_mentions: None,
+            flags: None,
+            components: None,
+            attachments: None,
+        })
+    }
+
+    /// Message that pings no one, for content echoing user input
+    pub fn respond_with_message_safe(content: impl Into<String>) -> Self {
+        InteractionResponse::ChannelMessageWithSource(
+            MessageCallbackData::new()
+                .with_content(content)
+                .with_allowed_mentions(AllowedMentions::none()),
+        )
+    }
+
+    pub fn respond_with_embed(embed: Embed) -> Self {
+        InteractionResponse::ChannelMessageWithSource(MessageCallbackData {
+            tts: None,
+            content: None,
+            embeds: Some(vec![embed]),
+            allowed_mentions: None,
+            flags: None,
+            components: None,
+            attachments: None,
+        })
+    }
+
+    pub fn respond_with_autocomplete_choices(choices: Vec<ApplicationCommandOptionChoice>) -> Self {
+        InteractionResponse::ApplicationCommandAutocompleteResult(AutocompleteCallbackData {
+            choices,
+        })
+    }
+
+    /// Body to send back to Discord, fails for responses Discord can't accept, like a modal
+    /// with components other than text inputs
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+impl Serialize for InteractionResponse {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry(TYPE_KEY, &self.response_type())?;
+        match self {
+            InteractionResponse::Pong
+            | InteractionResponse::DeferredChannelMessageWithSource
+            | InteractionResponse::DeferredUpdateMessage => {}
+            InteractionResponse::ChannelMessageWithSource(data) => {
+                map.serialize_entry(DATA_KEY, &data)?;
+            }
+            InteractionResponse::UpdateMessage(data) => {
+                map.serialize_entry(DATA_KEY, &data)?;
+            }
+            InteractionResponse::ApplicationCommandAutocompleteResult(data) => {
+                map.serialize_entry(DATA_KEY, &data)?;
+            }
+            InteractionResponse::Modal(data) => {
+                data.validate().map_err(serde::ser::Error::custom)?;
+                map.serialize_entry(DATA_KEY, &data)?;
+            }
+        };
+        map.end()
+    }
+}
+
+/// [Message Callback Data Structure](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-messages)
+#[derive(Debug, Default, Serialize)]
+pub struct MessageCallbackData {
+    /// is the response TTS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tts: Option<bool>,
+
+    /// message content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    /// supports up to 10 embeds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Embed>>,
+
+    /// [allowed mentions](https://discord.com/developers/docs/resources/channel#allowed-mentions-object) object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+
+    /// [message flags](https://discord.com/developers/docs/resources/channel#message-object-message-flags) combined as a [bitfield](https://en.wikipedia.org/wiki/Bit_field) (only SUPPRESS_EMBEDS and EPHEMERAL can be set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<MessageFlags>,
+
+    /// message components
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ActionRow>>,
+
+    /// attachment objects with filename and description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<PartialAttachment>>,
+}
+
+impl MessageCallbackData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    pub fn with_embeds(mut self, embeds: Vec<Embed>) -> Self {
+        self.embeds = Some(embeds);
+        self
+    }
+
+    pub fn with_allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    /// Replaces the flags, use [ephemeral](Self::ephemeral) to keep the others
+    pub fn with_flags(mut self, flags: MessageFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Only shows the message to the user who invoked the interaction
+    pub fn ephemeral(mut self) -> Self {
+        let flags = self.flags.take().unwrap_or(MessageFlags::empty());
+        self.flags = Some(flags | MessageFlags::Ephemeral);
+        self
+    }
+
+    pub fn is_ephemeral(&self) -> bool {
+        self.flags
+            .as_ref()
+            .map_or(false, |f| f.contains(MessageFlags::Ephemeral))
+    }
+
+    pub fn with_components(mut self, components: Vec<ActionRow>) -> Self {
+        self.components = Some(components);
+        self
+    }
+
+    pub fn with_attachments(mut self, attachments: Vec<PartialAttachment>) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+}
+
+/// A field of an [UpdateMessageData], distinguishing "leave as is" from "remove"
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Patch<T> {
+    /// omitted from the payload, the message keeps its current value
+    #[default]
+    Keep,
+
+    /// sent as `null`, the value is removed from the message
+    Clear,
+
+    /// replaces the current value
+    Set(T),
+}
+
+impl<T> Patch<T> {
+    pub fn is_keep(&self) -> bool {
+        matches!(self, Patch::Keep)
+    }
+}
+
+impl<T: Serialize> Serialize for Patch<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            // skipped by the containing struct, `null` if serialized on its own
+            Patch::Keep | Patch::Clear => serializer.serialize_none(),
+            Patch::Set(value) => value.serialize(serializer),
+        }
+    }
+}
+
+/// Data for [InteractionResponse::UpdateMessage]
+///
+/// Only the fields that are set or cleared are sent, everything else on the message is left untouched.
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateMessageData {
+    /// message content
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub content: Patch<String>,
+
+    /// supports up to 10 embeds
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub embeds: Patch<Vec<Embed>>,
+
+    /// [allowed mentions](https://discord.com/developers/docs/resources/channel#allowed-mentions-object) object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+
+    /// [message flags](https://discord.com/developers/docs/resources/channel#message-object-message-flags) combined as a [bitfield](https://en.wikipedia.org/wiki/Bit_field) (only SUPPRESS_EMBEDS can be set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<MessageFlags>,
+
+    /// message components
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub components: Patch<Vec<ActionRow>>,
+
+    /// attachment objects with filename and description, attachments not listed are removed
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub attachments: Patch<Vec<PartialAttachment>>,
+}
+
+impl UpdateMessageData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_content(mut self, content: impl Into<String>) -> Self {
+        self.content = Patch::Set(content.into());
+        self
+    }
+
+    pub fn clear_content(mut self) -> Self {
+        self.content = Patch::Clear;
+        self
+    }
+
+    pub fn with_embeds(mut self, embeds: Vec<Embed>) -> Self {
+        self.embeds = Patch::Set(embeds);
+        self
+    }
+
+    pub fn clear_embeds(mut self) -> Self {
+        self.embeds = Patch::Clear;
+        self
+    }
+
+    pub fn with_allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    pub fn with_flags(mut self, flags: MessageFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    pub fn with_components(mut self, components: Vec<ActionRow>) -> Self {
+        self.components = Patch::Set(components);
+        self
+    }
+
+    pub fn clear_components(mut self) -> Self {
+        self.components = Patch::Clear;
+        self
+    }
+
+    pub fn with_attachments(mut self, attachments: Vec<PartialAttachment>) -> Self {
+        self.attachments = Patch::Set(attachments);
+        self
+    }
+
+    /// Removes every attachment, Discord only does so for an empty list, not `null`
+    pub fn clear_attachments(mut self) -> Self {
+        self.attachments = Patch::Set(Vec::new());
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AutocompleteCallbackData {
+    /// autocomplete choices (max of [AUTOCOMPLETE_CHOICE_LIMIT] choices)
+    pub choices: Vec<ApplicationCommandOptionChoice>,
+}
+
+/// [Application Command Option Choice Structure](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-choice-structure)
+#[derive(Debug, Serialize)]
+pub struct ApplicationCommandOptionChoice {
+    /// 1-100 character choice name
+    pub name: String,
+
+    /// Localization dictionary for the name field. Values follow the same restrictions as name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<HashMap<String, String>>,
+
+    /// Value for the choice, up to 100 characters if string
+    pub value: ApplicationCommandOptionChoiceValue,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum ApplicationCommandOptionChoiceValue {
+    String(String),
+    Integer(i64),
+    Double(f64),
+}
+
+/// [Modal](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-modal)
+#[derive(Debug, Serialize)]
+pub struct ModalCallbackData {
+    /// a developer-defined identifier for the modal, max 100 characters
+    pub custom_id: String,
+
+    /// the title of the popup modal, max 45 characters
+    pub title: String,
+
+    /// between 1 and 5 (inclusive) components that make up the modal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ModalComponent>>,
+
+    /// is the response TTS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tts: Option<bool>,
+
+    /// message content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    /// supports up to 10 embeds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Embed>>,
+
+    /// [allowed mentions](https://discord.com/developers/docs/resources/channel#allowed-mentions-object) object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+
+    /// [message flags](https://discord.com/developers/docs/resources/channel#message-object-message-flags) combined as a [bitfield](https://en.wikipedia.org/wiki/Bit_field) (only SUPPRESS_EMBEDS and EPHEMERAL can be set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<MessageFlags>,
+
+    /// components the modal may hold beyond text inputs, checked when serializing
+    #[serde(skip)]
+    pub capabilities: ModalCapabilities,
+}
+
+impl ModalCallbackData {
+    pub fn new(custom_id: impl Into<String>, title: impl Into<String>) -> Self {
+        ModalCallbackData {
+            custom_id: custom_id.into(),
+            title: title.into(),
+            components: None,
+            tts: None,
+            content: None,
+            embeds: None,
+            allowed_mentions: None,
+            flags: None,
+            capabilities: ModalCapabilities::default(),
+        }
+    }
+
+    pub fn with_components(mut self, components: Vec<ModalComponent>) -> Self {
+        self.components = Some(components);
+        self
+    }
+
+    pub fn with_capabilities(mut self, capabilities: ModalCapabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Fails if a component is something other than a text input, or a select menu when
+    /// [select_menus](ModalCapabilities::select_menus) is enabled
+    fn validate(&self) -> Result<(), &'static str> {
+        let allowed = |component: &Component| match component {
+            Component::TextInput(_) => true,
+            Component::StringSelect(_)
+            | Component::UserSelect(_)
+            | Component::RoleSelect(_)
+            | Component::MentionableSelect(_)
+            | Component::ChannelSelect(_) => self.capabilities.select_menus,
+            Component::Button(_) => false,
+        };
+
+        let valid = self
+            .components
+            .iter()
+            .flatten()
+            .all(|component| match component {
+                ModalComponent::ActionRow(row) => row.components.iter().all(allowed),
+                ModalComponent::Label(label) => allowed(&label.component),
+            });
+
+        match (valid, self.capabilities.select_menus) {
+            (true, _) => Ok(()),
+            (false, false) => Err("modals can only contain text inputs"),
+            (false, true) => Err("modals can only contain text inputs and select menus"),
+        }
+    }
+}
+
+/// Top level component of a [ModalCallbackData]
+#[derive(Debug, Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ModalComponent {
+    ActionRow(ActionRow),
+    Label(Label),
+}
+
+impl From<ActionRow> for ModalComponent {
+    fn from(row: ActionRow) -> Self {
+        ModalComponent::ActionRow(row)
+    }
+}
+
+impl From<Label> for ModalComponent {
+    fn from(label: Label) -> Self {
+        ModalComponent::Label(label)
+    }
+}
+
+/// Components a modal may hold beyond text inputs
+///
+/// Discord is still rolling out other components in modals, the default only allows text inputs
+/// so a modal isn't rejected for clients that don't support them yet.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModalCapabilities {
+    /// string, user, role, mentionable and channel select menus, best wrapped in a [Label]
+    pub select_menus: bool,
+}
+
+impl ModalCapabilities {
+    pub fn with_select_menus(mut self) -> Self {
+        self.select_menus = true;
+        self
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::models::TextInputStyle;
+
+    #[test]
+    pub fn serialize_test() {
+        let response = InteractionResponse::ChannelMessageWithSource(MessageCallbackData {
+            tts: None,
+            content: Some(String::from("hello")),
+            embeds: None,
+            allowed_mentions: None,
+            flags: None,
+            components: None,
+            attachments: None,
+        });
+
+        println!("{}", serde_json::to_string_pretty(&response).unwrap());
+    }
+
+    #[test]
+    pub fn respond_with_message_safe() {
+        let response = InteractionResponse::respond_with_message_safe("@everyone hi");
+
+        assert_eq!(
+            serde_json::to_value(response).unwrap(),
+            serde_json::json!({
+                "type": 4,
+                "data": {
+                    "content": "@everyone hi",
+                    "allowed_mentions": {
+                        "parse": [],
+                        "roles": [],
+                        "users": [],
+                        "replied_user": false
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    pub fn update_message_patch() {
+        let cases = [
+            (UpdateMessageData::new(), r#"{}"#),
+            (
+                UpdateMessageData::new().with_content("hello"),
+                r#"{"content":"hello"}"#,
+            ),
+            (
+                UpdateMessageData::new().clear_content(),
+                r#"{"content":null}"#,
+            ),
+            (
+                UpdateMessageData::new().with_embeds(vec![]),
+                r#"{"embeds":[]}"#,
+            ),
+            (
+                UpdateMessageData::new().clear_embeds(),
+                r#"{"embeds":null}"#,
+            ),
+            (
+                UpdateMessageData::new().with_components(vec![]),
+                r#"{"components":[]}"#,
+            ),
+            (
+                UpdateMessageData::new().clear_components(),
+                r#"{"components":null}"#,
+            ),
+            (
+                UpdateMessageData::new().clear_attachments(),
+                r#"{"attachments":[]}"#,
+            ),
+            (
+                UpdateMessageData::new()
+                    .clear_content()
+                    .with_components(vec![]),
+                r#"{"content":null,"components":[]}"#,
+            ),
+        ];
+
+        for (data, expected) in cases {
+            assert_eq!(serde_json::to_string(&data).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    pub fn clear_attachments() {
+        let cleared = serde_json::to_value(
+            UpdateMessageData::new()
+                .with_content("no files")
+                .clear_attachments(),
+        )
+        .unwrap();
+
+        assert_eq!(cleared["attachments"], serde_json::json!([]));
+
+        let kept = serde_json::to_value(UpdateMessageData::new().with_content("no files")).unwrap();
+
+        assert!(kept.get("attachments").is_none());
+    }
+
+    #[test]
+    pub fn update_message_response() {
+        let response = InteractionResponse::UpdateMessage(
+            UpdateMessageData::new()
+                .clear_content()
+                .with_embeds(vec![Embed::new().with_title("done")]),
+        );
+
+        let json = serde_json::to_value(response).unwrap();
+
+        assert_eq!(json["type"], 7);
+        assert!(json["data"]["content"].is_null());
+        assert!(json["data"].as_object().unwrap().contains_key("content"));
+        assert_eq!(json["data"]["embeds"][0]["title"], "done");
+        assert!(!json["data"].as_object().unwrap().contains_key("components"));
+    }
+
+    #[test]
+    pub fn response_type_numbers() {
+        let cases = [
+            (InteractionResponse::Pong, 1),
+            (
+                InteractionResponse::respond_with_message(String::from("hello")),
+                4,
+            ),
+            (InteractionResponse::DeferredChannelMessageWithSource, 5),
+            (InteractionResponse::DeferredUpdateMessage, 6),
+            (
+                InteractionResponse::UpdateMessage(UpdateMessageData::new()),
+                7,
+            ),
+            (
+                InteractionResponse::respond_with_autocomplete_choices(vec![]),
+                8,
+            ),
+            (
+                InteractionResponse::Modal(ModalCallbackData::new("modal", "Modal")),
+                9,
+            ),
+        ];
+
+        for (response, expected) in cases {
+            assert_eq!(response.response_type(), expected, "{:?}", response);
+
+            let json = serde_json::to_value(&response).unwrap();
+            assert_eq!(json["type"], expected, "{:?}", response);
+        }
+    }
+
+    fn modal(components: Vec<Component>) -> InteractionResponse {
+        InteractionResponse::Modal(
+            ModalCallbackData::new("feedback", "Feedback")
+                .with_components(vec![ActionRow::new(components).into()]),
+        )
+    }
+
+    #[test]
+    pub fn to_json() {
+        let response = modal(vec![Component::new_text_input(
+            String::from("comment"),
+            TextInputStyle::Paragraph,
+            String::from("Comment"),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )]);
+
+        let json: serde_json::Value = serde_json::from_str(&response.to_json().unwrap()).unwrap();
+        assert_eq!(json["type"], 9);
+        assert_eq!(json["data"]["components"][0]["components"][0]["type"], 4);
+
+        let response = modal(vec![Component::link_button(
+            String::from("Docs"),
+            String::from("https://discord.com/developers/docs"),
+        )]);
+
+        let err = response.to_json().unwrap_err();
+        assert!(err.to_string().contains("text inputs"), "{}", err);
+    }
+
+    #[test]
+    pub fn modal_with_select_menu() {
+        let data = || {
+            ModalCallbackData::new("report", "Report").with_components(vec![
+                ActionRow::new(vec![Component::new_text_input(
+                    String::from("details"),
+                    TextInputStyle::Short,
+                    String::from("Details"),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )])
+                .into(),
+                Label::new(
+                    "Reason",
+                    Component::string_select_from(
+                        String::from("reason"),
+                        [(String::from("Spam"), String::from("spam"))],
+                    ),
+                )
+                .with_description("Why are you reporting this?")
+                .into(),
+            ])
+        };
+
+        let err = serde_json::to_value(InteractionResponse::Modal(data()))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "modals can only contain text inputs");
+
+        let response = InteractionResponse::Modal(
+            data().with_capabilities(ModalCapabilities::default().with_select_menus()),
+        );
+
+        assert_eq!(
+            serde_json::to_value(response).unwrap(),
+            serde_json::json!({
+                "type": 9,
+                "data": {
+                    "custom_id": "report",
+                    "title": "Report",
+                    "components": [
+                        {
+                            "type": 1,
+                            "components": [{
+                                "type": 4,
+                                "custom_id": "details",
+                                "style": 1,
+                                "label": "Details",
+                                "min_length": null,
+                                "max_length": null,
+                                "required": null,
+                                "value": null,
+                                "placeholder": null
+                            }]
+                        },
+                        {
+                            "type": 18,
+                            "label": "Reason",
+                            "description": "Why are you reporting this?",
+                            "component": {
+                                "type": 3,
+                                "custom_id": "reason",
+                                "options": [{
+                                    "label": "Spam",
+                                    "value": "spam",
+                                    "description": null,
+                                    "emoji": null,
+                                    "default": null
+                                }],
+                                "channel_types": null,
+                                "placeholder": null,
+                                "min_values": null,
+                                "max_values": null,
+                                "disabled": null
+                            }
+                        }
+                    ]
+                }
+            })
+        );
+
+        let buttons = ModalCallbackData::new("report", "Report")
+            .with_components(vec![ActionRow::new(vec![Component::link_button(
+                String::from("Docs"),
+                String::from("https://discord.com/developers/docs"),
+            )])
+            .into()])
+            .with_capabilities(ModalCapabilities::default().with_select_menus());
+
+        let err = serde_json::to_value(InteractionResponse::Modal(buttons))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "modals can only contain text inputs and select menus");
+    }
+}
@@ -0,0 +1,492 @@
+use std::fmt::{self, Display};
+
+use crate::{models::Snowflake, Mentionable};
+
+/// Most characters Discord accepts in a message's content
+pub const MESSAGE_CONTENT_LIMIT: usize = 2000;
+
+/// Characters with a meaning in Discord's markdown, or that start a mention
+const ESCAPED: &[char] = &[
+    '\\', '*', '_', '~', '`', '|', '>', '<', '@', '#', '-', '[', ']',
+];
+
+/// Text for a [MessageContent] segment, user text is escaped unless wrapped in [raw]
+pub enum Segment {
+    Escaped(String),
+    Raw(String),
+}
+
+impl Segment {
+    fn into_text(self) -> String {
+        match self {
+            Segment::Escaped(text) => escape(&text),
+            Segment::Raw(text) => text,
+        }
+    }
+}
+
+impl From<&str> for Segment {
+    fn from(text: &str) -> Self {
+        Segment::Escaped(text.to_string())
+    }
+}
+
+impl From<String> for Segment {
+    fn from(text: String) -> Self {
+        Segment::Escaped(text)
+    }
+}
+
+impl From<&String> for Segment {
+    fn from(text: &String) -> Self {
+        Segment::Escaped(text.clone())
+    }
+}
+
+/// Marks text as already formatted so [MessageContent] adds it as is
+pub fn raw(text: impl Into<String>) -> Segment {
+    Segment::Raw(text.into())
+}
+
+/// Backslash escapes markdown and mention syntax so text renders exactly as written
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if ESCAPED.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// [Timestamp Styles](https://discord.com/developers/docs/reference#message-formatting-timestamp-styles)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimestampStyle {
+    /// 16:20
+    ShortTime,
+
+    /// 16:20:30
+    LongTime,
+
+    /// 20/04/2021
+    ShortDate,
+
+    /// 20 April 2021
+    LongDate,
+
+    /// 20 April 2021 16:20
+    #[default]
+    ShortDateTime,
+
+    /// Tuesday, 20 April 2021 16:20
+    LongDateTime,
+
+    /// 2 months ago
+    Relative,
+}
+
+impl TimestampStyle {
+    fn flag(self) -> char {
+        match self {
+            TimestampStyle::ShortTime => 't',
+            TimestampStyle::LongTime => 'T',
+            TimestampStyle::ShortDate => 'd',
+            TimestampStyle::LongDate => 'D',
+            TimestampStyle::ShortDateTime => 'f',
+            TimestampStyle::LongDateTime => 'F',
+            TimestampStyle::Relative => 'R',
+        }
+    }
+}
+
+/// Builds message content from text, mentions and formatting
+///
+/// Text is escaped by default so user input can't add formatting or mentions, wrap it in [raw] to
+/// keep it as is. The length is tracked against [MESSAGE_CONTENT_LIMIT] but never enforced,
+/// check [MessageContent::exceeds_limit] before sending.
+///
+/// ```
+/// use composure_core::models::{raw, MessageContent, Snowflake, TimestampStyle};
+///
+/// let content = MessageContent::new()
+///     .bold("Reminder")
+///     .line()
+///     .text("set by ")
+///     .channel(&Snowflake::from_u64(300000000000000001))
+///     .text(" *ends* ")
+///     .timestamp(1618953630, TimestampStyle::Relative)
+///     .text(raw(" _soon_"));
+///
+/// assert_eq!(
+///     content.to_string(),
+///     "**Reminder**\nset by <#300000000000000001> \\*ends\\* <t:1618953630:R> _soon_"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageContent {
+    content: String,
+    len: usize,
+}
+
+impl MessageContent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(mut self, text: &str) -> Self {
+        self.len += text.chars().count();
+        self.content.push_str(text);
+        self
+    }
+
+    pub fn text(self, text: impl Into<Segment>) -> Self {
+        self.push(&text.into().into_text())
+    }
+
+    /// Mention of a user, member, role or channel
+    pub fn mention(self, mentionable: &impl Mentionable) -> Self {
+        self.push(&mentionable.to_mention())
+    }
+
+    /// Mention of a channel by id
+    pub fn channel(self, channel_id: &Snowflake) -> Self {
+        self.push(&format!("<#{channel_id}>"))
+    }
+
+    pub fn bold(self, text: impl Into<Segment>) -> Self {
+        self.push(&format!("**{}**", text.into().into_text()))
+    }
+
+    pub fn italic(self, text: impl Into<Segment>) -> Self {
+        self.push(&format!("*{}*", text.into().into_text()))
+    }
+
+    /// Inline code, contents are shown as is so they are never escaped
+    pub fn code(self, code: &str) -> Self {
+        // a fence longer than any run of backticks in the code can't be closed by it
+        let longest = code
+            .split(|c| c != '`')
+            .map(str::len)
+            .max()
+            .unwrap_or_default();
+        let fence = "`".repeat(longest + 1);
+        let pad = if code.starts_with('`') || code.ends_with('`') {
+            " "
+        } else {
+            ""
+        };
+
+        self.push(&format!("{fence}{pad}{code}{pad}{fence}"))
+    }
+
+    /// Code block highlighted as `language`, contents are shown as is
+    pub fn code_block(self, language: Option<&str>, code: &str) -> Self {
+        // a zero width space keeps a fence inside the code from closing the block
+        let code = code.replace("```", "`\u{200b}``");
+        let newline = if code.ends_with('\n') { "" } else { "\n" };
+
+        self.push(&format!(
+            "```{}\n{code}{newline}```",
+            language.unwrap_or_default()
+        ))
+    }
+
+    /// Timestamp shown in each reader's timezone, `unix` in seconds
+    pub fn timestamp(self, unix: i64, style: TimestampStyle) -> Self {
+        self.push(&format!("<t:{unix}:{}>", style.flag()))
+    }
+
+    /// Starts a new line
+    pub fn line(self) -> Self {
+        self.push("\n")
+    }
+
+    /// Length in characters
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Characters left before [MESSAGE_CONTENT_LIMIT], 0 once over it
+    pub fn remaining(&self) -> usize {
+        MESSAGE_CONTENT_LIMIT.saturating_sub(self.len)
+    }
+
+    /// true if Discord would reject the content for being too long
+    pub fn exceeds_limit(&self) -> bool {
+        self.len > MESSAGE_CONTENT_LIMIT
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.content
+    }
+}
+
+impl Display for MessageContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.content)
+    }
+}
+
+impl From<MessageContent> for String {
+    fn from(content: MessageContent) -> Self {
+        content.content
+    }
+}
+
+/// Splits content into chunks of at most `limit` characters, pass [MESSAGE_CONTENT_LIMIT] to
+/// send each chunk as its own message or follow-up
+///
+/// Chunks end at the last line break that fits, or the last whitespace when the line is too long,
+/// and the break they end at is dropped. Only a word longer than `limit` is cut. Blank content
+/// gives no chunks.
+///
+/// ```
+/// use composure_core::models::split_content;
+///
+/// assert_eq!(
+///     split_content("first line\nsecond line", 15),
+///     ["first line", "second line"]
+/// );
+/// ```
+pub fn split_content(content: &str, limit: usize) -> Vec<String> {
+    let limit = limit.max(1);
+    let mut chunks = Vec::new();
+    let mut rest = content;
+
+    while rest.chars().count() > limit {
+        // the character after the limit is included, a break there still fits
+        let end = rest
+            .char_indices()
+            .nth(limit + 1)
+            .map_or(rest.len(), |(i, _)| i);
+        let window = &rest[..end];
+
+        let split = window
+            .rfind('\n')
+            .or_else(|| window.rfind(char::is_whitespace))
+            .map(|i| {
+                let separator = window[i..].chars().next().map_or(0, char::len_utf8);
+                (i, i + separator)
+            });
+
+        let (chunk_end, next) = match split {
+            Some(split) => split,
+            None => {
+                // no break in reach, cut the word at the limit
+                let cut = window.char_indices().nth(limit).map_or(end, |(i, _)| i);
+                (cut, cut)
+            }
+        };
+
+        // Discord rejects blank messages, runs of line breaks don't make a chunk of their own
+        if !rest[..chunk_end].trim().is_empty() {
+            chunks.push(rest[..chunk_end].to_string());
+        }
+        rest = &rest[next..];
+    }
+
+    if !rest.trim().is_empty() {
+        chunks.push(rest.to_string());
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::InteractionResponse;
+
+    struct Named(&'static str);
+
+    impl Mentionable for Named {
+        fn to_mention(&self) -> String {
+            format!("<@{}>", self.0)
+        }
+    }
+
+    #[test]
+    pub fn escapes_by_default() {
+        let cases = [
+            ("plain text", "plain text"),
+            ("**bold**", "\\*\\*bold\\*\\*"),
+            ("_under_ ~~strike~~", "\\_under\\_ \\~\\~strike\\~\\~"),
+            ("`code` ||spoiler||", "\\`code\\` \\|\\|spoiler\\|\\|"),
+            ("> quote", "\\> quote"),
+            ("# heading", "\\# heading"),
+            ("- item", "\\- item"),
+            (
+                "[link](https://example.com)",
+                "\\[link\\](https://example.com)",
+            ),
+            ("@everyone", "\\@everyone"),
+            ("<@400000000000000001>", "\\<\\@400000000000000001\\>"),
+            ("back\\slash", "back\\\\slash"),
+            ("ünïcödé 🦀", "ünïcödé 🦀"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(MessageContent::new().text(input).as_str(), expected);
+        }
+    }
+
+    #[test]
+    pub fn raw_opts_out() {
+        let content = MessageContent::new()
+            .text(raw("**kept**"))
+            .bold(raw("_both_"))
+            .italic("*escaped*");
+
+        assert_eq!(content.as_str(), "**kept****_both_***\\*escaped\\**");
+    }
+
+    #[test]
+    pub fn helpers() {
+        let cases = [
+            (MessageContent::new().bold("hi"), "**hi**"),
+            (MessageContent::new().italic("hi"), "*hi*"),
+            (
+                MessageContent::new().mention(&Named("400000000000000001")),
+                "<@400000000000000001>",
+            ),
+            (
+                MessageContent::new().channel(&Snowflake::from_u64(300000000000000001)),
+                "<#300000000000000001>",
+            ),
+            (MessageContent::new().code("let x = 1;"), "`let x = 1;`"),
+            (MessageContent::new().code("a`b"), "``a`b``"),
+            (MessageContent::new().code("`tick`"), "`` `tick` ``"),
+            (MessageContent::new().code("a``b"), "```a``b```"),
+            (MessageContent::new().code("```"), "```` ``` ````"),
+            // code is never escaped
+            (MessageContent::new().code("**"), "`**`"),
+            (
+                MessageContent::new().code_block(Some("rust"), "fn main() {}"),
+                "```rust\nfn main() {}\n```",
+            ),
+            (
+                MessageContent::new().code_block(None, "line\n"),
+                "```\nline\n```",
+            ),
+            (
+                MessageContent::new().code_block(None, "```"),
+                "```\n`\u{200b}``\n```",
+            ),
+            (MessageContent::new().line(), "\n"),
+        ];
+
+        for (content, expected) in cases {
+            assert_eq!(content.as_str(), expected);
+        }
+    }
+
+    #[test]
+    pub fn timestamps() {
+        let cases = [
+            (TimestampStyle::ShortTime, "<t:1618953630:t>"),
+            (TimestampStyle::LongTime, "<t:1618953630:T>"),
+            (TimestampStyle::ShortDate, "<t:1618953630:d>"),
+            (TimestampStyle::LongDate, "<t:1618953630:D>"),
+            (TimestampStyle::ShortDateTime, "<t:1618953630:f>"),
+            (TimestampStyle::LongDateTime, "<t:1618953630:F>"),
+            (TimestampStyle::Relative, "<t:1618953630:R>"),
+        ];
+
+        for (style, expected) in cases {
+            assert_eq!(
+                MessageContent::new().timestamp(1618953630, style).as_str(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    pub fn length_tracking() {
+        let content = MessageContent::new().text("🦀🦀").line().bold("é");
+
+        // characters, not bytes
+        assert_eq!(content.len(), 8);
+        assert_eq!(content.len(), content.as_str().chars().count());
+        assert_eq!(content.remaining(), MESSAGE_CONTENT_LIMIT - 8);
+        assert!(!content.exceeds_limit());
+        assert!(MessageContent::new().is_empty());
+
+        let full = MessageContent::new().text("a".repeat(MESSAGE_CONTENT_LIMIT));
+        assert!(!full.exceeds_limit());
+        assert_eq!(full.remaining(), 0);
+
+        // escaping counts towards the limit
+        let over = full.text("*");
+        assert_eq!(over.len(), MESSAGE_CONTENT_LIMIT + 2);
+        assert!(over.exceeds_limit());
+        assert_eq!(over.remaining(), 0);
+    }
+
+    #[test]
+    pub fn into_response() {
+        let content = MessageContent::new().bold("done");
+
+        let json =
+            serde_json::to_value(InteractionResponse::respond_with_message(content)).unwrap();
+        assert_eq!(json["data"]["content"], "**done**");
+
+        let json =
+            serde_json::to_value(InteractionResponse::respond_with_message("plain")).unwrap();
+        assert_eq!(json["data"]["content"], "plain");
+    }
+
+    #[test]
+    pub fn split_content_sizes() {
+        let under = "short message";
+        assert_eq!(split_content(under, MESSAGE_CONTENT_LIMIT), [under]);
+
+        let exact = "a".repeat(MESSAGE_CONTENT_LIMIT);
+        assert_eq!(
+            split_content(&exact, MESSAGE_CONTENT_LIMIT),
+            [exact.clone()]
+        );
+
+        assert!(split_content("", MESSAGE_CONTENT_LIMIT).is_empty());
+
+        let words = (0..1000).map(|i| format!("word{i}")).collect::<Vec<_>>();
+        let over = words.join(" ");
+        let chunks = split_content(&over, MESSAGE_CONTENT_LIMIT);
+
+        assert!(over.len() > MESSAGE_CONTENT_LIMIT * 3);
+        assert_eq!(chunks.len(), 4);
+        for chunk in &chunks {
+            assert!(chunk.chars().count() <= MESSAGE_CONTENT_LIMIT);
+            assert!(!chunk.starts_with(' ') && !chunk.ends_with(' '));
+        }
+
+        // no word was cut
+        assert_eq!(chunks.join(" "), over);
+    }
+
+    #[test]
+    pub fn split_content_breaks() {
+        let cases = [
+            // line breaks win over closer spaces
+            ("one two\nthree four", 12, vec!["one two", "three four"]),
+            ("one two three", 9, vec!["one two", "three"]),
+            // a break right after the limit still fits
+            ("abc def", 3, vec!["abc", "def"]),
+            // words longer than the limit are cut
+            ("abcdefgh ij", 3, vec!["abc", "def", "gh", "ij"]),
+            // counted in characters, not bytes
+            ("ééé ééé", 4, vec!["ééé", "ééé"]),
+            ("a\n\n\nb", 1, vec!["a", "b"]),
+        ];
+
+        for (content, limit, expected) in cases {
+            assert_eq!(split_content(content, limit), expected, "{content:?}");
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use serde::Serialize;
+
+use crate::{
+    models::{Patch, Permissions, Snowflake},
+    util::ImageData,
+};
+
+/// [Create Guild Role](https://discord.com/developers/docs/resources/guild#create-guild-role-json-params) body
+///
+/// Anything left unset takes Discord's default, `@everyone`'s permissions for `permissions`
+#[derive(Debug, Default, Serialize)]
+pub struct CreateRole {
+    /// name of the role, max 100 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// bitwise value of the enabled/disabled permissions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permissions>,
+
+    /// RGB color value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<i32>,
+
+    /// whether the role should be displayed separately in the sidebar
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hoist: Option<bool>,
+
+    /// the role's icon as [image data](crate::util::image_data), needs the ROLE_ICONS guild feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+
+    /// the role's unicode emoji as a standard emoji, needs the ROLE_ICONS guild feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unicode_emoji: Option<String>,
+
+    /// whether the role should be mentionable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mentionable: Option<bool>,
+}
+
+impl CreateRole {
+    pub fn new(name: impl Into<String>) -> Self {
+        CreateRole {
+            name: Some(name.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+
+    pub fn with_color(mut self, color: i32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn with_hoist(mut self, hoist: bool) -> Self {
+        self.hoist = Some(hoist);
+        self
+    }
+
+    /// `icon` is [ImageData], check it against [ROLE_ICON_SIZE_LIMIT](crate::util::ROLE_ICON_SIZE_LIMIT)
+    pub fn with_icon(mut self, icon: impl Into<ImageData>) -> Self {
+        self.icon = Some(String::from(icon.into()));
+        self
+    }
+
+    pub fn with_unicode_emoji(mut self, emoji: impl Into<String>) -> Self {
+        self.unicode_emoji = Some(emoji.into());
+        self
+    }
+
+    pub fn with_mentionable(mut self, mentionable: bool) -> Self {
+        self.mentionable = Some(mentionable);
+        self
+    }
+}
+
+/// [Modify Guild Role](https://discord.com/developers/docs/resources/guild#modify-guild-role-json-params) body
+///
+/// Only the fields that are set or cleared are sent, the rest of the role is left untouched.
+#[derive(Debug, Default, Serialize)]
+pub struct ModifyRole {
+    /// name of the role, max 100 characters
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub name: Patch<String>,
+
+    /// bitwise value of the enabled/disabled permissions
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub permissions: Patch<Permissions>,
+
+    /// RGB color value
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub color: Patch<i32>,
+
+    /// whether the role should be displayed separately in the sidebar
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub hoist: Patch<bool>,
+
+    /// the role's icon as [image data](crate::util::image_data)
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub icon: Patch<String>,
+
+    /// the role's unicode emoji as a standard emoji
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub unicode_emoji: Patch<String>,
+
+    /// whether the role should be mentionable
+    #[serde(skip_serializing_if = "Patch::is_keep")]
+    pub mentionable: Patch<bool>,
+}
+
+impl ModifyRole {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Patch::Set(name.into());
+        self
+    }
+
+    pub fn with_permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions = Patch::Set(permissions);
+        self
+    }
+
+    pub fn with_color(mut self, color: i32) -> Self {
+        self.color = Patch::Set(color);
+        self
+    }
+
+    pub fn with_hoist(mut self, hoist: bool) -> Self {
+        self.hoist = Patch::Set(hoist);
+        self
+    }
+
+    /// `icon` is [ImageData], check it against [ROLE_ICON_SIZE_LIMIT](crate::util::ROLE_ICON_SIZE_LIMIT)
+    pub fn with_icon(mut self, icon: impl Into<ImageData>) -> Self {
+        self.icon = Patch::Set(String::from(icon.into()));
+        self
+    }
+
+    pub fn clear_icon(mut self) -> Self {
+        self.icon = Patch::Clear;
+        self
+    }
+
+    pub fn with_unicode_emoji(mut self, emoji: impl Into<String>) -> Self {
+        self.unicode_emoji = Patch::Set(emoji.into());
+        self
+    }
+
+    pub fn clear_unicode_emoji(mut self) -> Self {
+        self.unicode_emoji = Patch::Clear;
+        self
+    }
+
+    pub fn with_mentionable(mut self, mentionable: bool) -> Self {
+        self.mentionable = Patch::Set(mentionable);
+        self
+    }
+}
+
+/// Entry of [Modify Guild Role Positions](https://discord.com/developers/docs/resources/guild#modify-guild-role-positions-json-params)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RolePosition {
+    /// role
+    pub id: Snowflake,
+
+    /// sorting position of the role
+    pub position: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    pub fn create_role_serializes() {
+        let role = CreateRole::new("mods")
+            .with_permissions(Permissions::KickMembers | Permissions::BanMembers)
+            .with_color(0x3498db)
+            .with_hoist(true)
+            .with_icon(ImageData::from_png_bytes(b"\x89PNG\r\n\x1a\n").unwrap());
+
+        assert_eq!(
+            serde_json::to_value(role).unwrap(),
+            json!({
+                "name": "mods",
+                "permissions": "6",
+                "color": 3447003,
+                "hoist": true,
+                "icon": "data:image/png;base64,iVBORw0KGgo="
+            })
+        );
+
+        assert_eq!(
+            serde_json::to_value(CreateRole::default()).unwrap(),
+            json!({})
+        );
+    }
+
+    #[test]
+    pub fn modify_role_patch() {
+        let role = ModifyRole::new()
+            .with_name("admins")
+            .with_mentionable(false)
+            .clear_icon();
+
+        assert_eq!(
+            serde_json::to_value(role).unwrap(),
+            json!({ "name": "admins", "icon": null, "mentionable": false })
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use std::cell::RefCell;
+
+use serde::{
+    de::{
+        value::BorrowedStrDeserializer, DeserializeOwned, DeserializeSeed, MapAccess, SeqAccess,
+        Visitor,
+    },
+    forward_to_deserialize_any, Deserialize, Deserializer,
+};
+use serde_json::{map, Value};
+
+use crate::models::Interaction;
+
+thread_local! {
+    /// Unknown paths collected by the strict parse running on this thread, `None` otherwise
+    static UNKNOWN: RefCell<Option<Vec<String>>> = RefCell::new(None);
+
+    /// Path of the last value the tracking deserializer finished with
+    static LAST_PATH: RefCell<String> = RefCell::new(String::new());
+}
+
+/// Parses an interaction while recording every JSON field the models do not know about
+///
+/// Unknown fields do not fail the parse, they are returned as dotted paths (`data.options[0].foo`)
+/// alongside the interaction so the models can be kept in step with Discord.
+pub fn parse_interaction_strict(json: &str) -> serde_json::Result<(Interaction, Vec<String>)> {
+    let value: Value = serde_json::from_str(json)?;
+
+    UNKNOWN.with(|unknown| *unknown.borrow_mut() = Some(Vec::new()));
+
+    let interaction = Interaction::deserialize(Tracked::new(&value, String::new()));
+
+    let unknown = UNKNOWN
+        .with(|unknown| unknown.borrow_mut().take())
+        .unwrap_or_default();
+
+    interaction.map(|interaction| (interaction, unknown))
+}
+
+fn tracking() -> bool {
+    UNKNOWN.with(|unknown| unknown.borrow().is_some())
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+/// A JSON value buffered by a hand written `Deserialize` impl, usually to dispatch on `type`
+///
+/// Keeps track of where the value sits in the payload so a strict parse can keep reporting paths
+/// for whatever gets deserialized out of it.
+pub(crate) struct Buffered {
+    value: Value,
+    path: Option<String>,
+}
+
+impl Buffered {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.value.get(key)
+    }
+
+    /// Removes a field so it can be deserialized on its own
+    pub fn take(&mut self, key: &str) -> Option<Buffered> {
+        let value = self.value.as_object_mut()?.remove(key)?;
+
+        Some(Buffered {
+            value,
+            path: self.path.as_deref().map(|path| join(path, key)),
+        })
+    }
+
+    pub fn deserialize_into<T: DeserializeOwned>(self) -> serde_json::Result<T> {
+        match self.path {
+            Some(path) => T::deserialize(Tracked::new(&self.value, path)),
+            None => T::deserialize(self.value),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Buffered {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        let path = match tracking() {
+            true => Some(LAST_PATH.with(|last| last.take())),
+            false => None,
+        };
+
+        Ok(Buffered { value, path })
+    }
+}
+
+/// Deserializer over a [Value] that records the path of every field the target type ignores
+struct Tracked<'a> {
+    value: &'a Value,
+    path: String,
+}
+
+impl<'a> Tracked<'a> {
+    fn new(value: &'a Value, path: String) -> Self {
+        Tracked { value, path }
+    }
+}
+
+impl<'de> Deserializer<'de> for Tracked<'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let res = match self.value {
+            Value::Object(map) => visitor.visit_map(TrackedMap {
+                iter: map.iter(),
+                next: None,
+                path: self.path.clone(),
+            }),
+            Value::Array(seq) => visitor.visit_seq(TrackedSeq {
+                iter: seq.iter().enumerate(),
+                path: self.path.clone(),
+            }),
+            value => value.deserialize_any(visitor),
+        };
+
+        LAST_PATH.with(|last| *last.borrow_mut() = self.path);
+
+        res
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.value.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        UNKNOWN.with(|unknown| {
+            if let Some(unknown) = unknown.borrow_mut().as_mut() {
+                unknown.push(self.path);
+            }
+        });
+
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+struct TrackedMap<'a> {
+    iter: map::Iter<'a>,
+    next: Option<(&'a Value, String)>,
+    path: String,
+}
+
+impl<'de> MapAccess<'de> for TrackedMap<'de> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.next = Some((value, join(&self.path, key)));
+                seed.deserialize(BorrowedStrDeserializer::new(key))
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<S>(&mut self, seed: S) -> Result<S::Value, Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        let (value, path) = self
+            .next
+            .take()
+            .ok_or_else(|| serde::de::Error::custom("value is missing"))?;
+
+        seed.deserialize(Tracked::new(value, path))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+struct TrackedSeq<'a> {
+    iter: std::iter::Enumerate<std::slice::Iter<'a, Value>>,
+    path: String,
+}
+
+impl<'de> SeqAccess<'de> for TrackedSeq<'de> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        match self.iter.next() {
+            Some((i, value)) => seed
+                .deserialize(Tracked::new(value, format!("{}[{i}]", self.path)))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
@@ -0,0 +1,59 @@
+//! Assertions for testing handlers, add the `test-util` feature to the crate in
+//! `dev-dependencies` to use them
+
+use serde_json::Value;
+
+use crate::models::InteractionResponse;
+
+/// The JSON Discord would receive for `response`, to compare against `serde_json::json!`
+///
+/// Panics if the response can't be serialized, like a modal with components Discord doesn't
+/// allow in one.
+#[track_caller]
+pub fn response_to_value(response: &InteractionResponse) -> Value {
+    match serde_json::to_value(response) {
+        Ok(value) => value,
+        Err(err) => panic!("response doesn't serialize: {}", err),
+    }
+}
+
+/// Panics unless `response` is sent with the
+/// [callback type](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-interaction-callback-type)
+/// `expected_type`, showing the response's JSON
+#[track_caller]
+pub fn assert_response_type(response: &InteractionResponse, expected_type: u8) {
+    let actual = response.response_type();
+
+    if actual != expected_type {
+        panic!(
+            "expected a response of type {} but got type {}: {}",
+            expected_type,
+            actual,
+            response_to_value(response)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    pub fn respond_with_message() {
+        let response = InteractionResponse::respond_with_message("pong");
+
+        assert_response_type(&response, 4);
+        assert_eq!(
+            response_to_value(&response),
+            json!({"type": 4, "data": {"content": "pong"}})
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a response of type 7 but got type 4")]
+    pub fn wrong_type() {
+        assert_response_type(&InteractionResponse::respond_with_message("pong"), 7);
+    }
+}
@@ -0,0 +1,25 @@
+mod args;
+mod config;
+mod context_menu;
+mod dedupe;
+mod handler_error;
+mod image;
+mod namespace;
+mod option_rules;
+mod redact;
+mod reminder;
+mod responder;
+mod store;
+
+pub use args::*;
+pub use config::*;
+pub use context_menu::*;
+pub use dedupe::*;
+pub use handler_error::*;
+pub use image::*;
+pub use namespace::*;
+pub use option_rules::*;
+pub use redact::*;
+pub use reminder::*;
+pub use responder::*;
+pub use store::*;
@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+
+/// Why a string couldn't be split into arguments
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseArgsError {
+    /// A quote was opened at this byte offset but never closed
+    UnterminatedQuote { quote: char, position: usize },
+
+    /// The input ends with a backslash that has nothing to escape
+    TrailingEscape,
+}
+
+impl std::fmt::Display for ParseArgsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseArgsError::UnterminatedQuote { quote, position } => {
+                write!(f, "missing closing {quote} for the quote at {position}")
+            }
+            ParseArgsError::TrailingEscape => write!(f, "nothing to escape after trailing \\"),
+        }
+    }
+}
+
+impl std::error::Error for ParseArgsError {}
+
+/// Splits a string into arguments the way a shell would
+///
+/// Arguments are separated by whitespace. Text inside single quotes is taken literally, inside
+/// double quotes a backslash escapes the next character, as it does outside of quotes. Quoted and
+/// unquoted parts that touch are joined, `a"b c"` is the single argument `ab c`.
+pub fn parse_args(input: &str) -> Result<Vec<String>, ParseArgsError> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    // distinguishes `""` (an empty argument) from no argument at all
+    let mut in_arg = false;
+
+    let mut chars = input.char_indices();
+
+    while let Some((position, c)) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            '\\' => {
+                let (_, escaped) = chars.next().ok_or(ParseArgsError::TrailingEscape)?;
+                current.push(escaped);
+                in_arg = true;
+            }
+            '\'' | '"' => {
+                let quote = c;
+                loop {
+                    match chars.next() {
+                        None => return Err(ParseArgsError::UnterminatedQuote { quote, position }),
+                        Some((_, c)) if c == quote => break,
+                        Some((_, '\\')) if quote == '"' => {
+                            let (_, escaped) = chars
+                                .next()
+                                .ok_or(ParseArgsError::UnterminatedQuote { quote, position })?;
+                            current.push(escaped);
+                        }
+                        Some((_, c)) => current.push(c),
+                    }
+                }
+                in_arg = true;
+            }
+            c => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+
+    if in_arg {
+        args.push(current);
+    }
+
+    Ok(args)
+}
+
+/// Arguments split into positionals and `--flags`, see [flags]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Flags {
+    /// Arguments that aren't flags or flag values, in order
+    pub positional: Vec<String>,
+
+    /// Flags by name without the leading `--`, `None` for a switch without a value
+    pub flags: HashMap<String, Option<String>>,
+}
+
+impl Flags {
+    /// Value given to `--name`
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.flags.get(name).and_then(|v| v.as_deref())
+    }
+
+    /// true if `--name` was given, with or without a value
+    pub fn has(&self, name: &str) -> bool {
+        self.flags.contains_key(name)
+    }
+}
+
+/// Splits `--key value`, `--key=value` and `--switch` out of parsed arguments
+///
+/// A flag takes the argument after it as its value unless that argument is another flag.
+/// Everything after a lone `--` is positional. If a flag is repeated the last value wins.
+pub fn flags<S: AsRef<str>>(args: &[S]) -> Flags {
+    let mut parsed = Flags::default();
+    let mut args = args.iter().map(AsRef::as_ref).peekable();
+
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            parsed.positional.extend(args.map(String::from));
+            break;
+        }
+
+        let Some(name) = arg.strip_prefix("--").filter(|name| !name.is_empty()) else {
+            parsed.positional.push(arg.to_string());
+            continue;
+        };
+
+        let (name, value) = match name.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => {
+                let value = args
+                    .next_if(|next| !next.starts_with("--"))
+                    .map(String::from);
+                (name, value)
+            }
+        };
+
+        parsed.flags.insert(name.to_string(), value);
+    }
+
+    parsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(input: &str) -> Vec<String> {
+        parse_args(input).unwrap()
+    }
+
+    #[test]
+    pub fn split_and_quote() {
+        let cases: [(&str, &[&str]); 12] = [
+            ("", &[]),
+            ("   ", &[]),
+            ("a b  c", &["a", "b", "c"]),
+            ("  padded\t\nwords ", &["padded", "words"]),
+            (r#""two words" after"#, &["two words", "after"]),
+            ("'single quoted' x", &["single quoted", "x"]),
+            (r#"a"b c"d"#, &["ab cd"]),
+            (r#""" ''"#, &["", ""]),
+            (r#"'it"s'"#, &["it\"s"]),
+            (r#""it's""#, &["it's"]),
+            (
+                r#"SELECT * LIMIT 10 --format csv"#,
+                &["SELECT", "*", "LIMIT", "10", "--format", "csv"],
+            ),
+            (
+                r#""SELECT * FROM t" --format csv"#,
+                &["SELECT * FROM t", "--format", "csv"],
+            ),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(args(input), expected, "{input}");
+        }
+    }
+
+    #[test]
+    pub fn escapes() {
+        let cases: [(&str, &[&str]); 6] = [
+            (r#"a\ b"#, &["a b"]),
+            (r#"\"quoted\""#, &["\"quoted\""]),
+            (r#""say \"hi\"""#, &["say \"hi\""]),
+            (r#""back\\slash""#, &["back\\slash"]),
+            // backslashes are literal inside single quotes
+            (r#"'C:\path\'"#, &["C:\\path\\"]),
+            (r#"\\"#, &["\\"]),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(args(input), expected, "{input}");
+        }
+    }
+
+    #[test]
+    pub fn errors() {
+        let cases = [
+            (
+                r#"ok "open"#,
+                ParseArgsError::UnterminatedQuote {
+                    quote: '"',
+                    position: 3,
+                },
+            ),
+            (
+                "'open",
+                ParseArgsError::UnterminatedQuote {
+                    quote: '\'',
+                    position: 0,
+                },
+            ),
+            (
+                r#""escaped end\""#,
+                ParseArgsError::UnterminatedQuote {
+                    quote: '"',
+                    position: 0,
+                },
+            ),
+            (r#"trailing \"#, ParseArgsError::TrailingEscape),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(parse_args(input), Err(expected), "{input}");
+        }
+    }
+
+    #[test]
+    pub fn unicode() {
+        assert_eq!(args("héllo wörld"), ["héllo", "wörld"]);
+        assert_eq!(args("\"日本 語\" 🦀"), ["日本 語", "🦀"]);
+        assert_eq!(args("a\u{3000}b"), ["a", "b"]);
+
+        // positions are byte offsets
+        assert_eq!(
+            parse_args("é \"x"),
+            Err(ParseArgsError::UnterminatedQuote {
+                quote: '"',
+                position: 3
+            })
+        );
+    }
+
+    #[test]
+    pub fn split_flags() {
+        let parsed = flags(&args(
+            r#""SELECT * FROM t" --format csv --verbose --limit=10 extra"#,
+        ));
+
+        assert_eq!(parsed.positional, ["SELECT * FROM t", "extra"]);
+        assert_eq!(parsed.value("format"), Some("csv"));
+        assert_eq!(parsed.value("limit"), Some("10"));
+        assert!(parsed.has("verbose"));
+        assert_eq!(parsed.value("verbose"), None);
+        assert!(!parsed.has("missing"));
+    }
+
+    #[test]
+    pub fn flags_edge_cases() {
+        // a switch followed by another flag doesn't take it as a value
+        let parsed = flags(&["--dry-run", "--format", "json"]);
+        assert_eq!(parsed.flags["dry-run"], None);
+        assert_eq!(parsed.value("format"), Some("json"));
+
+        // trailing switch, empty value, repeated flag
+        let parsed = flags(&["--a=", "--b", "1", "--b", "2", "--c"]);
+        assert_eq!(parsed.value("a"), Some(""));
+        assert_eq!(parsed.value("b"), Some("2"));
+        assert!(parsed.has("c"));
+
+        // everything after -- is positional
+        let parsed = flags(&["--x", "1", "--", "--not-a-flag", "y"]);
+        assert_eq!(parsed.value("x"), Some("1"));
+        assert_eq!(parsed.positional, ["--not-a-flag", "y"]);
+
+        // single dashes are positional
+        let parsed = flags(&["-n", "5"]);
+        assert_eq!(parsed.positional, ["-n", "5"]);
+        assert!(parsed.flags.is_empty());
+    }
+}
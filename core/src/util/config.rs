@@ -0,0 +1,286 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{InteractionResponse, MessageCallbackData, Snowflake};
+
+/// Prefix of the environment variables [BotConfig::with_env_overrides] reads
+pub const ENV_PREFIX: &str = "COMPOSURE_";
+
+/// Settings shared by the command builder and the adapters, so a bot is configured in one place
+///
+/// Deserialize it from any serde format, like a TOML or JSON file, then apply
+/// [environment overrides](Self::with_env_overrides) for secrets and per-deployment values.
+/// Only `application_id` is required.
+///
+/// ```
+/// use composure_core::util::BotConfig;
+///
+/// let config: BotConfig = serde_json::from_str(
+///     r#"{"application_id": "100000000000000001", "disabled_commands": ["ban"]}"#,
+/// )
+/// .unwrap();
+///
+/// assert!(config.is_disabled("ban"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BotConfig {
+    pub application_id: Snowflake,
+
+    /// guild commands are registered in, globally when missing
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+
+    /// users still allowed to use the bot in maintenance mode
+    #[serde(default)]
+    pub owner_ids: Vec<Snowflake>,
+
+    /// answers everyone but the owners with a maintenance message instead of running handlers
+    #[serde(default)]
+    pub maintenance: bool,
+
+    /// makes every message sent as an interaction response ephemeral
+    #[serde(default)]
+    pub ephemeral_by_default: bool,
+
+    /// names of commands answered with a "temporarily disabled" message instead of running
+    #[serde(default)]
+    pub disabled_commands: Vec<String>,
+}
+
+impl BotConfig {
+    pub fn new(application_id: Snowflake) -> Self {
+        BotConfig {
+            application_id,
+            guild_id: None,
+            owner_ids: vec![],
+            maintenance: false,
+            ephemeral_by_default: false,
+            disabled_commands: vec![],
+        }
+    }
+
+    /// Config read entirely from the environment, `COMPOSURE_APPLICATION_ID` is required
+    ///
+    /// `var` looks up a variable, pass `|name| std::env::var(name).ok()` or read the worker's
+    /// `Env`. See [with_env_overrides](Self::with_env_overrides) for the other variables.
+    pub fn from_env(var: impl Fn(&str) -> Option<String>) -> Result<Self, ConfigError> {
+        let name = env_name("APPLICATION_ID");
+        let application_id = var(&name).ok_or(ConfigError::Missing(name.clone()))?;
+        let application_id = parse(&name, &application_id)?;
+
+        BotConfig::new(application_id).with_env_overrides(var)
+    }
+
+    /// Replaces settings with the environment variables that are set
+    ///
+    /// | Variable | Value |
+    /// |---|---|
+    /// | `COMPOSURE_APPLICATION_ID` | snowflake |
+    /// | `COMPOSURE_GUILD_ID` | snowflake, empty to register globally |
+    /// | `COMPOSURE_OWNER_IDS` | comma separated snowflakes |
+    /// | `COMPOSURE_MAINTENANCE` | `true` or `false` |
+    /// | `COMPOSURE_EPHEMERAL_BY_DEFAULT` | `true` or `false` |
+    /// | `COMPOSURE_DISABLED_COMMANDS` | comma separated command names |
+    pub fn with_env_overrides(
+        mut self,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, ConfigError> {
+        let lookup = |key: &str| {
+            let name = env_name(key);
+            var(&name).map(|value| (name, value))
+        };
+
+        if let Some((name, value)) = lookup("APPLICATION_ID") {
+            self.application_id = parse(&name, &value)?;
+        }
+
+        if let Some((name, value)) = lookup("GUILD_ID") {
+            self.guild_id = match value.trim() {
+                "" => None,
+                value => Some(parse(&name, value)?),
+            };
+        }
+
+        if let Some((name, value)) = lookup("OWNER_IDS") {
+            self.owner_ids = split(&value)
+                .map(|id| parse(&name, id))
+                .collect::<Result<_, _>>()?;
+        }
+
+        if let Some((name, value)) = lookup("MAINTENANCE") {
+            self.maintenance = parse(&name, &value)?;
+        }
+
+        if let Some((name, value)) = lookup("EPHEMERAL_BY_DEFAULT") {
+            self.ephemeral_by_default = parse(&name, &value)?;
+        }
+
+        if let Some((_, value)) = lookup("DISABLED_COMMANDS") {
+            self.disabled_commands = split(&value).map(str::to_string).collect();
+        }
+
+        Ok(self)
+    }
+
+    pub fn is_disabled(&self, command: &str) -> bool {
+        self.disabled_commands.iter().any(|name| name == command)
+    }
+
+    pub fn is_owner(&self, user_id: &Snowflake) -> bool {
+        self.owner_ids.contains(user_id)
+    }
+
+    /// Ephemeral message for a command in [disabled_commands](Self::disabled_commands)
+    pub fn disabled_response(command: &str) -> InteractionResponse {
+        InteractionResponse::ChannelMessageWithSource(
+            MessageCallbackData::new()
+                .with_content(format!("`/{}` is temporarily disabled", command))
+                .ephemeral(),
+        )
+    }
+
+    /// Ephemeral message for anyone but the owners while in [maintenance](Self::maintenance)
+    pub fn maintenance_response() -> InteractionResponse {
+        InteractionResponse::ChannelMessageWithSource(
+            MessageCallbackData::new()
+                .with_content("The bot is down for maintenance, try again later")
+                .ephemeral(),
+        )
+    }
+}
+
+fn env_name(key: &str) -> String {
+    format!("{}{}", ENV_PREFIX, key)
+}
+
+fn split(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+fn parse<T: std::str::FromStr>(name: &str, value: &str) -> Result<T, ConfigError> {
+    value.trim().parse().map_err(|_| ConfigError::Invalid {
+        name: name.to_string(),
+        value: value.to_string(),
+    })
+}
+
+/// Why [BotConfig] couldn't be read from the environment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// a required variable isn't set
+    Missing(String),
+
+    /// a variable isn't a valid value for its setting
+    Invalid { name: String, value: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Missing(name) => write!(f, "{} is not set", name),
+            ConfigError::Invalid { name, value } => {
+                write!(f, "{} has an invalid value: {:?}", name, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    pub fn deserialize() {
+        let config: BotConfig = serde_json::from_value(json!({
+            "application_id": "100000000000000001",
+            "owner_ids": ["400000000000000001"],
+            "maintenance": true,
+        }))
+        .unwrap();
+
+        assert_eq!(config.application_id.to_u64(), 100000000000000001);
+        assert_eq!(config.guild_id, None);
+        assert!(config.is_owner(&Snowflake::from_u64(400000000000000001)));
+        assert!(config.maintenance);
+        assert!(!config.ephemeral_by_default);
+        assert!(config.disabled_commands.is_empty());
+
+        assert!(serde_json::from_value::<BotConfig>(json!({"maintenance": true})).is_err());
+    }
+
+    #[test]
+    pub fn env_overrides() {
+        let config = BotConfig {
+            guild_id: Some(Snowflake::from_u64(200000000000000001)),
+            disabled_commands: vec!["ban".to_string()],
+            ..BotConfig::new(Snowflake::from_u64(100000000000000001))
+        };
+
+        let config = config
+            .with_env_overrides(env(&[
+                ("COMPOSURE_GUILD_ID", ""),
+                (
+                    "COMPOSURE_OWNER_IDS",
+                    "400000000000000001, 400000000000000002",
+                ),
+                ("COMPOSURE_MAINTENANCE", "true"),
+                ("COMPOSURE_DISABLED_COMMANDS", "kick,,purge"),
+            ]))
+            .unwrap();
+
+        // unset variables keep their value
+        assert_eq!(config.application_id.to_u64(), 100000000000000001);
+        assert!(!config.ephemeral_by_default);
+
+        assert_eq!(config.guild_id, None);
+        assert_eq!(config.owner_ids.len(), 2);
+        assert!(config.maintenance);
+        assert_eq!(config.disabled_commands, ["kick", "purge"]);
+        assert!(!config.is_disabled("ban"));
+    }
+
+    #[test]
+    pub fn from_env() {
+        assert_eq!(
+            BotConfig::from_env(env(&[])).unwrap_err(),
+            ConfigError::Missing("COMPOSURE_APPLICATION_ID".to_string())
+        );
+
+        let config = BotConfig::from_env(env(&[
+            ("COMPOSURE_APPLICATION_ID", "100000000000000001"),
+            ("COMPOSURE_EPHEMERAL_BY_DEFAULT", "true"),
+        ]))
+        .unwrap();
+        assert_eq!(config.application_id.to_u64(), 100000000000000001);
+        assert!(config.ephemeral_by_default);
+
+        let err = BotConfig::from_env(env(&[
+            ("COMPOSURE_APPLICATION_ID", "100000000000000001"),
+            ("COMPOSURE_MAINTENANCE", "yes"),
+        ]))
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "COMPOSURE_MAINTENANCE has an invalid value: \"yes\""
+        );
+    }
+}
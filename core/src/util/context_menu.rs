@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use crate::{
+    models::{ApplicationCommandInteractionData, ApplicationCommandType, Message, TargetUser},
+    util::{HandlerError, HandlerResult},
+};
+
+type UserHandler<C> = Box<dyn Fn(&C, TargetUser) -> HandlerResult>;
+type MessageHandler<C> = Box<dyn Fn(&C, &Message) -> HandlerResult>;
+
+/// Routes context-menu commands by name to handlers that get the user or message they were used on
+///
+/// `C` is whatever the handlers need alongside the target, like an adapter's context.
+///
+/// ```
+/// use composure_core::{models::InteractionResponse, util::ContextMenuRouter};
+///
+/// let router = ContextMenuRouter::<()>::new()
+///     .user_command("Report", |_, target| {
+///         Ok(InteractionResponse::respond_with_message(format!(
+///             "Reported {}",
+///             target.user.username
+///         )))
+///     })
+///     .message_command("Quote", |_, message| {
+///         Ok(InteractionResponse::respond_with_message(message.content.clone()))
+///     });
+/// ```
+pub struct ContextMenuRouter<C> {
+    user: HashMap<String, UserHandler<C>>,
+    message: HashMap<String, MessageHandler<C>>,
+}
+
+impl<C> Default for ContextMenuRouter<C> {
+    fn default() -> Self {
+        ContextMenuRouter {
+            user: HashMap::new(),
+            message: HashMap::new(),
+        }
+    }
+}
+
+impl<C> ContextMenuRouter<C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Handles the user command called `name` with the user it was used on
+    pub fn user_command(
+        mut self,
+        name: impl Into<String>,
+        handler: impl Fn(&C, TargetUser) -> HandlerResult + 'static,
+    ) -> Self {
+        self.user.insert(name.into(), Box::new(handler));
+        self
+    }
+
+    /// Handles the message command called `name` with the message it was used on
+    pub fn message_command(
+        mut self,
+        name: impl Into<String>,
+        handler: impl Fn(&C, &Message) -> HandlerResult + 'static,
+    ) -> Self {
+        self.message.insert(name.into(), Box::new(handler));
+        self
+    }
+
+    /// Runs the handler registered for `data`, `None` if there is none
+    ///
+    /// A target Discord didn't resolve is answered with [HandlerError::NotFound].
+    pub fn route(
+        &self,
+        ctx: &C,
+        data: &ApplicationCommandInteractionData,
+    ) -> Option<HandlerResult> {
+        match data.t {
+            ApplicationCommandType::User => {
+                let handler = self.user.get(&data.name)?;
+
+                Some(match data.target_user() {
+                    Some(target) => handler(ctx, target),
+                    None => Err(HandlerError::NotFound("Target user".to_string())),
+                })
+            }
+            ApplicationCommandType::Message => {
+                let handler = self.message.get(&data.name)?;
+
+                Some(match data.target_message() {
+                    Some(message) => handler(ctx, message),
+                    None => Err(HandlerError::NotFound("Target message".to_string())),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<C> std::fmt::Debug for ContextMenuRouter<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContextMenuRouter")
+            .field("user", &self.user.keys().collect::<Vec<_>>())
+            .field("message", &self.message.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
@@ -0,0 +1,258 @@
+use std::time::{Duration, SystemTime};
+
+use crate::{
+    models::Snowflake,
+    util::{store::millis, StateStore},
+};
+
+/// Prefix of the keys [dedupe] records interactions under
+pub const DEDUPE_PREFIX: &str = "dedupe:";
+
+/// How long [IdempotentConsumer] remembers an interaction, as long as its token is valid
+pub const DEFAULT_DEDUPE_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Whether [dedupe] saw the interaction before
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    /// first delivery, or the first since the last one expired, process it
+    First,
+
+    /// delivered before within the ttl, skip it
+    Duplicate,
+}
+
+/// Records that `interaction_id` is being processed, for queues that deliver at least once
+///
+/// The id is remembered for `ttl` after `now`, redeliveries within it are
+/// [Duplicate](Delivery::Duplicate). The store keeps the expiry time as the value, remove expired
+/// entries with [prune_dedupe].
+///
+/// The check and the write are a separate [get](StateStore::get) and [put](StateStore::put), not
+/// one atomic step. Two consumers reading the same id at once both see
+/// [First](Delivery::First), so this only dedupes deliveries to a single consumer at a time. Stores
+/// like Cloudflare KV have no compare-and-swap to do better, route an id to one consumer when it
+/// must run exactly once.
+pub fn dedupe<S: StateStore>(
+    store: &mut S,
+    interaction_id: &Snowflake,
+    ttl: Duration,
+    now: SystemTime,
+) -> Result<Delivery, S::Error> {
+    let key = dedupe_key(interaction_id);
+    let now = millis(now);
+
+    let expires_at = store.get(&key)?.and_then(|value| value.parse::<u64>().ok());
+
+    if matches!(expires_at, Some(expires_at) if expires_at > now) {
+        return Ok(Delivery::Duplicate);
+    }
+
+    store.put(&key, (now + ttl.as_millis() as u64).to_string())?;
+    Ok(Delivery::First)
+}
+
+/// Deletes the entries [dedupe] recorded that expired at `now`, returns how many were deleted
+pub fn prune_dedupe<S: StateStore>(store: &mut S, now: SystemTime) -> Result<usize, S::Error> {
+    let now = millis(now);
+    let mut pruned = 0;
+
+    for key in store.list(DEDUPE_PREFIX)? {
+        let expired = store
+            .get(&key)?
+            .and_then(|value| value.parse::<u64>().ok())
+            .map_or(true, |expires_at| expires_at <= now);
+
+        if expired {
+            store.delete(&key)?;
+            pruned += 1;
+        }
+    }
+
+    Ok(pruned)
+}
+
+fn dedupe_key(interaction_id: &Snowflake) -> String {
+    format!("{}{}", DEDUPE_PREFIX, interaction_id)
+}
+
+/// Runs each interaction taken off a queue once, duplicates within the ttl are skipped
+///
+/// The interaction is recorded before it is handled, so a redelivery while it runs is skipped.
+/// When the handler fails the record is deleted again, so the queue's retry runs it.
+///
+/// Only one consumer should share a store at a time, see [dedupe] for why concurrent consumers
+/// can both run the same interaction.
+pub struct IdempotentConsumer<S> {
+    store: S,
+    ttl: Duration,
+}
+
+impl<S: StateStore> IdempotentConsumer<S> {
+    /// Remembers interactions for [DEFAULT_DEDUPE_TTL]
+    pub fn new(store: S) -> Self {
+        IdempotentConsumer {
+            store,
+            ttl: DEFAULT_DEDUPE_TTL,
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Runs `handle` unless `interaction_id` was consumed within the ttl, `None` for a duplicate
+    ///
+    /// An `Err` from `handle` is returned as is and forgets the interaction, so it isn't a
+    /// duplicate when it is delivered again.
+    pub fn consume<T, E>(
+        &mut self,
+        interaction_id: &Snowflake,
+        now: SystemTime,
+        handle: impl FnOnce() -> Result<T, E>,
+    ) -> Result<Option<Result<T, E>>, S::Error> {
+        if dedupe(&mut self.store, interaction_id, self.ttl, now)? == Delivery::Duplicate {
+            return Ok(None);
+        }
+
+        let result = handle();
+        if result.is_err() {
+            self.store.delete(&dedupe_key(interaction_id))?;
+        }
+
+        Ok(Some(result))
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn into_store(self) -> S {
+        self.store
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::UNIX_EPOCH;
+
+    use super::*;
+    use crate::util::MemoryStore;
+
+    fn at(seconds: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_700_000_000 + seconds)
+    }
+
+    #[test]
+    pub fn deliveries() {
+        let mut store = MemoryStore::new();
+        let id = Snowflake::from_u64(700000000000000001);
+        let ttl = Duration::from_secs(60);
+
+        let cases = [
+            (0, Delivery::First),
+            (1, Delivery::Duplicate),
+            (59, Delivery::Duplicate),
+            // expired, redelivered and remembered again
+            (60, Delivery::First),
+            (90, Delivery::Duplicate),
+            (120, Delivery::First),
+        ];
+
+        for (seconds, delivery) in cases {
+            assert_eq!(
+                dedupe(&mut store, &id, ttl, at(seconds)).unwrap(),
+                delivery,
+                "at {seconds}s"
+            );
+        }
+
+        let other = Snowflake::from_u64(700000000000000002);
+        assert_eq!(
+            dedupe(&mut store, &other, ttl, at(121)).unwrap(),
+            Delivery::First
+        );
+    }
+
+    #[test]
+    pub fn consumer() {
+        let mut consumer = IdempotentConsumer::new(MemoryStore::new());
+        let id = Snowflake::from_u64(700000000000000001);
+        let mut purchases = 0;
+
+        for seconds in [0, 5, 14 * 60] {
+            consumer
+                .consume(&id, at(seconds), || {
+                    purchases += 1;
+                    Ok::<_, ()>(())
+                })
+                .unwrap();
+        }
+        assert_eq!(purchases, 1);
+
+        // redelivered once the default ttl passed
+        let result = consumer
+            .consume(&id, at(15 * 60), || {
+                purchases += 1;
+                Ok::<_, ()>(())
+            })
+            .unwrap();
+        assert!(result.is_some());
+        assert_eq!(purchases, 2);
+
+        // a shorter ttl applies to interactions recorded after it is set
+        let mut consumer = consumer.with_ttl(Duration::from_secs(1));
+        let other = Snowflake::from_u64(700000000000000002);
+        for seconds in [0, 1] {
+            assert!(consumer
+                .consume(&other, at(seconds), || Ok::<_, ()>(()))
+                .unwrap()
+                .is_some());
+        }
+        assert!(consumer
+            .consume(&id, at(15 * 60 + 1), || Ok::<_, ()>(()))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    pub fn failed_then_redelivered() {
+        let mut consumer = IdempotentConsumer::new(MemoryStore::new());
+        let id = Snowflake::from_u64(700000000000000001);
+
+        let failed = consumer
+            .consume(&id, at(0), || Err::<(), _>("KV unavailable"))
+            .unwrap();
+        assert_eq!(failed, Some(Err("KV unavailable")));
+        assert!(consumer.store().is_empty());
+
+        let retried = consumer
+            .consume(&id, at(5), || Ok::<_, &str>("done"))
+            .unwrap();
+        assert_eq!(retried, Some(Ok("done")));
+
+        let duplicate = consumer
+            .consume(&id, at(10), || Ok::<_, &str>("again"))
+            .unwrap();
+        assert_eq!(duplicate, None);
+    }
+
+    #[test]
+    pub fn prune() {
+        let mut store = MemoryStore::new();
+        let ttl = Duration::from_secs(60);
+
+        for (i, seconds) in [0, 30, 90].into_iter().enumerate() {
+            let id = Snowflake::from_u64(700000000000000001 + i as u64);
+            dedupe(&mut store, &id, ttl, at(seconds)).unwrap();
+        }
+        store.put("dedupe:broken", "soon".to_string()).unwrap();
+        store.put("other", "kept".to_string()).unwrap();
+
+        assert_eq!(prune_dedupe(&mut store, at(90)).unwrap(), 3);
+        assert_eq!(
+            store.list("").unwrap(),
+            ["dedupe:700000000000000003", "other"]
+        );
+    }
+}
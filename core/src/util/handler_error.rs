@@ -0,0 +1,222 @@
+use std::fmt;
+
+use crate::models::{InteractionResponse, MessageCallbackData, Permissions};
+
+/// What a handler answers an interaction with, the same for every adapter
+pub type HandlerResult = Result<InteractionResponse, HandlerError>;
+
+/// Why a handler couldn't answer an interaction
+///
+/// Everything but [Internal](Self::Internal) is the user's to see, [response](Self::response)
+/// turns it into an ephemeral message. Wrap other errors with [internal](Self::internal), `?`
+/// converts the crate's [Error](crate::Error), JSON and [ResponderError](super::ResponderError)s on
+/// its own.
+#[derive(Debug)]
+pub enum HandlerError {
+    /// message shown to the user as is
+    UserFacing { message: String, ephemeral: bool },
+
+    /// a bug or an outage, logged and never shown to the user
+    Internal(Box<dyn std::error::Error>),
+
+    /// the user is missing these permissions
+    MissingPermissions(Permissions),
+
+    /// what the user asked for doesn't exist, the value names it
+    NotFound(String),
+
+    /// the user is using the command too often
+    RateLimited,
+}
+
+impl HandlerError {
+    /// A bug or an outage behind `err`, logged and never shown to the user
+    pub fn internal(err: impl std::error::Error + 'static) -> Self {
+        HandlerError::Internal(Box::new(err))
+    }
+
+    /// Ephemeral message for the user
+    pub fn user_facing(message: impl Into<String>) -> Self {
+        HandlerError::UserFacing {
+            message: message.into(),
+            ephemeral: true,
+        }
+    }
+
+    /// Response answering the interaction in place of the handler's, `None` for
+    /// [Internal](Self::Internal) errors
+    pub fn response(&self) -> Option<InteractionResponse> {
+        let (message, ephemeral) = match self {
+            HandlerError::UserFacing { message, ephemeral } => (message.clone(), *ephemeral),
+            HandlerError::Internal(_) => return None,
+            HandlerError::MissingPermissions(permissions) => {
+                let names = permissions
+                    .iter_names()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>();
+
+                (
+                    format!("You are missing permissions: {}", names.join(", ")),
+                    true,
+                )
+            }
+            HandlerError::NotFound(what) => (format!("{} not found", what), true),
+            HandlerError::RateLimited => (
+                "You're doing that too often, try again in a moment".to_string(),
+                true,
+            ),
+        };
+
+        let data = MessageCallbackData::new().with_content(message);
+
+        Some(InteractionResponse::ChannelMessageWithSource(
+            if ephemeral { data.ephemeral() } else { data },
+        ))
+    }
+
+    /// HTTP status the interaction is answered with
+    pub fn status(&self) -> u16 {
+        match self {
+            HandlerError::Internal(_) => 500,
+            _ => 200,
+        }
+    }
+}
+
+impl fmt::Display for HandlerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandlerError::UserFacing { message, .. } => write!(f, "{}", message),
+            HandlerError::Internal(err) => write!(f, "internal error: {}", err),
+            HandlerError::MissingPermissions(permissions) => {
+                write!(f, "missing permissions: {:?}", permissions)
+            }
+            HandlerError::NotFound(what) => write!(f, "{} not found", what),
+            HandlerError::RateLimited => write!(f, "rate limited"),
+        }
+    }
+}
+
+impl std::error::Error for HandlerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HandlerError::Internal(err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<crate::Error> for HandlerError {
+    fn from(err: crate::Error) -> Self {
+        HandlerError::internal(err)
+    }
+}
+
+impl From<serde_json::Error> for HandlerError {
+    fn from(err: serde_json::Error) -> Self {
+        HandlerError::internal(err)
+    }
+}
+
+impl From<super::ResponderError> for HandlerError {
+    fn from(err: super::ResponderError) -> Self {
+        HandlerError::internal(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    pub fn responses() {
+        let cases = [
+            (
+                HandlerError::UserFacing {
+                    message: "Pick a tag first".to_string(),
+                    ephemeral: false,
+                },
+                json!({"type": 4, "data": {"content": "Pick a tag first"}}),
+            ),
+            (
+                HandlerError::user_facing("Pick a tag first"),
+                json!({"type": 4, "data": {"content": "Pick a tag first", "flags": 64}}),
+            ),
+            (
+                HandlerError::MissingPermissions(
+                    Permissions::KickMembers | Permissions::BanMembers,
+                ),
+                json!({"type": 4, "data": {
+                    "content": "You are missing permissions: KickMembers, BanMembers",
+                    "flags": 64
+                }}),
+            ),
+            (
+                HandlerError::NotFound("Tag `rules`".to_string()),
+                json!({"type": 4, "data": {"content": "Tag `rules` not found", "flags": 64}}),
+            ),
+            (
+                HandlerError::RateLimited,
+                json!({"type": 4, "data": {
+                    "content": "You're doing that too often, try again in a moment",
+                    "flags": 64
+                }}),
+            ),
+        ];
+
+        for (err, expected) in cases {
+            let response = err.response().unwrap();
+
+            assert_eq!(serde_json::to_value(response).unwrap(), expected, "{err}");
+            assert_eq!(err.status(), 200);
+        }
+    }
+
+    /// Handler written against the portable types only
+    fn tag(name: &str, tags: &str) -> HandlerResult {
+        let tags: std::collections::HashMap<String, String> = serde_json::from_str(tags)?;
+
+        let content = tags
+            .get(name)
+            .ok_or_else(|| HandlerError::NotFound(format!("Tag `{}`", name)))?;
+
+        Ok(InteractionResponse::respond_with_message(content.as_str()))
+    }
+
+    #[test]
+    pub fn portable_handler() {
+        let tags = r#"{"rules": "Be nice"}"#;
+
+        let response = tag("rules", tags).unwrap();
+        assert_eq!(
+            serde_json::to_value(response).unwrap(),
+            json!({"type": 4, "data": {"content": "Be nice"}})
+        );
+
+        let err = tag("faq", tags).unwrap_err();
+        assert_eq!(err.to_string(), "Tag `faq` not found");
+        assert_eq!(err.status(), 200);
+
+        let err = tag("rules", "{").unwrap_err();
+        assert!(matches!(err, HandlerError::Internal(_)));
+    }
+
+    #[test]
+    pub fn internal() {
+        use std::error::Error as _;
+
+        let err = HandlerError::internal(std::fmt::Error);
+
+        assert!(matches!(err, HandlerError::Internal(_)));
+        assert!(err.response().is_none());
+        assert_eq!(err.status(), 500);
+        assert!(err.source().unwrap().is::<std::fmt::Error>());
+
+        let err: HandlerError = serde_json::from_str::<u8>("{").unwrap_err().into();
+        assert!(err.source().unwrap().is::<serde_json::Error>());
+
+        assert!(HandlerError::RateLimited.source().is_none());
+    }
+}
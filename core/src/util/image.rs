@@ -0,0 +1,313 @@
+use std::fmt;
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Largest role icon Discord accepts, in bytes before encoding
+pub const ROLE_ICON_SIZE_LIMIT: usize = 256 * 1024;
+
+/// Largest emoji Discord accepts, in bytes before encoding
+pub const EMOJI_SIZE_LIMIT: usize = 256 * 1024;
+
+/// [Image Data](https://discord.com/developers/docs/reference#image-data) URI for uploading an
+/// icon or avatar, such as `data:image/png;base64,BASE64_DATA`
+///
+/// `content_type` is the image's mime type, one of `image/jpeg`, `image/png` or `image/gif`
+pub fn image_data(content_type: &str, bytes: &[u8]) -> String {
+    let mut uri = format!("data:{content_type};base64,");
+    uri.reserve((bytes.len() + 2) / 3 * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                uri.push(BASE64[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                uri.push('=');
+            }
+        }
+    }
+
+    uri
+}
+
+/// Image formats Discord accepts as [ImageData]
+///
+/// Unlike the CDN's [ImageFormat](crate::models::ImageFormat) there is no WebP, uploads don't
+/// accept it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageDataFormat {
+    Png,
+    Jpeg,
+    Gif,
+}
+
+impl ImageDataFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ImageDataFormat::Png => "image/png",
+            ImageDataFormat::Jpeg => "image/jpeg",
+            ImageDataFormat::Gif => "image/gif",
+        }
+    }
+
+    /// Format of `bytes` read from their magic bytes, `None` if it isn't one Discord accepts
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(ImageDataFormat::Png)
+        } else if bytes.starts_with(&[0xff, 0xd8, 0xff]) {
+            Some(ImageDataFormat::Jpeg)
+        } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+            Some(ImageDataFormat::Gif)
+        } else {
+            None
+        }
+    }
+}
+
+/// Checked [image data](image_data) for icons, avatars and emojis
+///
+/// The constructors make sure the bytes are the declared format. Setters like
+/// [CreateRole::with_icon](crate::models::CreateRole::with_icon) take it instead of a `String`.
+///
+/// ```
+/// use composure_core::util::{ImageData, ROLE_ICON_SIZE_LIMIT};
+///
+/// let png = b"\x89PNG\r\n\x1a\n";
+/// let icon = ImageData::from_png_bytes(png)
+///     .and_then(|icon| icon.check_size(ROLE_ICON_SIZE_LIMIT))
+///     .unwrap();
+///
+/// assert_eq!(icon.as_data_uri(), "data:image/png;base64,iVBORw0KGgo=");
+/// assert!(ImageData::from_gif_bytes(png).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageData {
+    format: ImageDataFormat,
+    size: usize,
+    uri: String,
+}
+
+impl ImageData {
+    pub fn from_png_bytes(bytes: &[u8]) -> Result<Self, ImageError> {
+        Self::from_bytes(ImageDataFormat::Png, bytes)
+    }
+
+    pub fn from_jpeg_bytes(bytes: &[u8]) -> Result<Self, ImageError> {
+        Self::from_bytes(ImageDataFormat::Jpeg, bytes)
+    }
+
+    pub fn from_gif_bytes(bytes: &[u8]) -> Result<Self, ImageError> {
+        Self::from_bytes(ImageDataFormat::Gif, bytes)
+    }
+
+    /// Fails if the magic bytes don't match `format`
+    pub fn from_bytes(format: ImageDataFormat, bytes: &[u8]) -> Result<Self, ImageError> {
+        let found = ImageDataFormat::sniff(bytes);
+
+        if found != Some(format) {
+            return Err(ImageError::Mismatch {
+                declared: format,
+                found,
+            });
+        }
+
+        Ok(ImageData {
+            format,
+            size: bytes.len(),
+            uri: image_data(format.content_type(), bytes),
+        })
+    }
+
+    /// Fails if the image is over `limit` bytes, like [ROLE_ICON_SIZE_LIMIT]
+    pub fn check_size(self, limit: usize) -> Result<Self, ImageError> {
+        if self.size > limit {
+            return Err(ImageError::TooLarge {
+                size: self.size,
+                limit,
+            });
+        }
+
+        Ok(self)
+    }
+
+    pub fn format(&self) -> ImageDataFormat {
+        self.format
+    }
+
+    /// Size of the image in bytes, before encoding
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The image as `data:image/png;base64,BASE64_DATA`
+    pub fn as_data_uri(&self) -> &str {
+        &self.uri
+    }
+}
+
+impl From<ImageData> for String {
+    fn from(image: ImageData) -> Self {
+        image.uri
+    }
+}
+
+/// Why bytes were rejected as [ImageData]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageError {
+    /// the bytes aren't the declared format, `found` is what they look like if Discord accepts it
+    Mismatch {
+        declared: ImageDataFormat,
+        found: Option<ImageDataFormat>,
+    },
+
+    /// the image is bigger than the endpoint accepts
+    TooLarge { size: usize, limit: usize },
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::Mismatch {
+                declared,
+                found: Some(found),
+            } => write!(
+                f,
+                "image declared as {} is {}",
+                declared.content_type(),
+                found.content_type()
+            ),
+            ImageError::Mismatch {
+                declared,
+                found: None,
+            } => write!(
+                f,
+                "image declared as {} is not a png, jpeg or gif",
+                declared.content_type()
+            ),
+            ImageError::TooLarge { size, limit } => {
+                write!(f, "image is {} bytes, over the {} byte limit", size, limit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImageError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn encodes_base64() {
+        let cases: [(&[u8], &str); 7] = [
+            (b"", ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg=="),
+            (b"fooba", "Zm9vYmE="),
+            (&[0xff, 0xfe, 0x00, 0x3e, 0x3f], "//4APj8="),
+        ];
+
+        for (bytes, expected) in cases {
+            assert_eq!(
+                image_data("image/png", bytes),
+                format!("data:image/png;base64,{expected}")
+            );
+        }
+    }
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+    const JPEG: &[u8] = &[0xff, 0xd8, 0xff, 0xe0, 0x00, 0x10, b'J', b'F', b'I', b'F'];
+    const GIF: &[u8] = b"GIF89a\x01\0\x01\0";
+
+    #[test]
+    pub fn sniff() {
+        let cases: [(&[u8], Option<ImageDataFormat>); 7] = [
+            (PNG, Some(ImageDataFormat::Png)),
+            (JPEG, Some(ImageDataFormat::Jpeg)),
+            (GIF, Some(ImageDataFormat::Gif)),
+            (b"GIF87a", Some(ImageDataFormat::Gif)),
+            (b"\x89PNG", None),
+            (b"RIFF\0\0\0\0WEBP", None),
+            (b"", None),
+        ];
+
+        for (bytes, expected) in cases {
+            assert_eq!(ImageDataFormat::sniff(bytes), expected, "{bytes:?}");
+        }
+    }
+
+    #[test]
+    pub fn declared_format_must_match() {
+        assert_eq!(
+            ImageData::from_png_bytes(PNG).unwrap().format(),
+            ImageDataFormat::Png
+        );
+        assert_eq!(
+            ImageData::from_jpeg_bytes(JPEG).unwrap().format(),
+            ImageDataFormat::Jpeg
+        );
+        assert_eq!(
+            ImageData::from_gif_bytes(GIF).unwrap().format(),
+            ImageDataFormat::Gif
+        );
+
+        let err = ImageData::from_png_bytes(JPEG).unwrap_err();
+        assert_eq!(
+            err,
+            ImageError::Mismatch {
+                declared: ImageDataFormat::Png,
+                found: Some(ImageDataFormat::Jpeg),
+            }
+        );
+        assert_eq!(err.to_string(), "image declared as image/png is image/jpeg");
+
+        let err = ImageData::from_gif_bytes(b"not an image").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "image declared as image/gif is not a png, jpeg or gif"
+        );
+    }
+
+    #[test]
+    pub fn data_uri() {
+        let image = ImageData::from_jpeg_bytes(JPEG).unwrap();
+
+        assert_eq!(image.size(), JPEG.len());
+        assert_eq!(image.as_data_uri(), image_data("image/jpeg", JPEG));
+        assert_eq!(
+            image.as_data_uri(),
+            "data:image/jpeg;base64,/9j/4AAQSkZJRg=="
+        );
+        assert_eq!(String::from(image.clone()), image.as_data_uri());
+    }
+
+    #[test]
+    pub fn size_limit() {
+        let mut bytes = PNG.to_vec();
+        bytes.resize(ROLE_ICON_SIZE_LIMIT, 0);
+
+        let image = ImageData::from_png_bytes(&bytes).unwrap();
+        assert!(image.check_size(ROLE_ICON_SIZE_LIMIT).is_ok());
+
+        bytes.push(0);
+        let err = ImageData::from_png_bytes(&bytes)
+            .unwrap()
+            .check_size(ROLE_ICON_SIZE_LIMIT)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ImageError::TooLarge {
+                size: ROLE_ICON_SIZE_LIMIT + 1,
+                limit: ROLE_ICON_SIZE_LIMIT,
+            }
+        );
+    }
+}
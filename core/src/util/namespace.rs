@@ -0,0 +1,210 @@
+use std::{collections::HashMap, fmt};
+
+/// Separates a [Namespace] from the rest of a custom_id
+pub const NAMESPACE_SEPARATOR: char = '/';
+
+/// Prefix keeping the custom_ids of one feature apart from every other feature's
+///
+/// Ids are built as `name/rest`, like `polls/vote:3`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Namespace(String);
+
+impl Namespace {
+    /// Panics if `name` is empty or contains [NAMESPACE_SEPARATOR]
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+
+        assert!(!name.is_empty(), "namespace can't be empty");
+        assert!(
+            !name.contains(NAMESPACE_SEPARATOR),
+            "namespace {:?} can't contain {:?}",
+            name,
+            NAMESPACE_SEPARATOR
+        );
+
+        Namespace(name)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// custom_id for `rest` in this namespace
+    pub fn id(&self, rest: &str) -> String {
+        format!("{}{}{}", self.0, NAMESPACE_SEPARATOR, rest)
+    }
+
+    /// The part of `custom_id` after this namespace, if it is in it
+    pub fn strip<'a>(&self, custom_id: &'a str) -> Option<&'a str> {
+        custom_id
+            .strip_prefix(self.0.as_str())?
+            .strip_prefix(NAMESPACE_SEPARATOR)
+    }
+}
+
+impl fmt::Display for Namespace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Builds custom_ids for a feature, handed out by [ComponentRouter::register]
+///
+/// The router only registers a namespace once, so ids built by different factories can't clash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamespacedIdFactory {
+    namespace: Namespace,
+}
+
+impl NamespacedIdFactory {
+    pub fn namespace(&self) -> &Namespace {
+        &self.namespace
+    }
+
+    /// custom_id for `rest` in the feature's namespace
+    pub fn id(&self, rest: &str) -> String {
+        self.namespace.id(rest)
+    }
+}
+
+/// Routes components to the handler registered for their custom_id's [Namespace]
+///
+/// ```
+/// use composure_core::util::{ComponentRouter, Namespace};
+///
+/// let mut router = ComponentRouter::new();
+/// let polls = router.register(Namespace::new("polls"), "polls handler").unwrap();
+///
+/// let custom_id = polls.id("vote:3");
+/// assert_eq!(custom_id, "polls/vote:3");
+/// assert_eq!(router.route(&custom_id), Some((&"polls handler", "vote:3")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ComponentRouter<H> {
+    handlers: HashMap<String, H>,
+}
+
+impl<H> Default for ComponentRouter<H> {
+    fn default() -> Self {
+        ComponentRouter {
+            handlers: HashMap::new(),
+        }
+    }
+}
+
+impl<H> ComponentRouter<H> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Routes `namespace` to `handler`, returns the factory the feature builds its ids with
+    pub fn register(
+        &mut self,
+        namespace: Namespace,
+        handler: H,
+    ) -> Result<NamespacedIdFactory, DuplicateNamespace> {
+        if self.handlers.contains_key(namespace.name()) {
+            return Err(DuplicateNamespace(namespace));
+        }
+
+        self.handlers.insert(namespace.name().to_string(), handler);
+        Ok(NamespacedIdFactory { namespace })
+    }
+
+    /// Handler for `custom_id` and the part of it after the namespace, `None` for ids without a
+    /// registered namespace
+    pub fn route<'a>(&self, custom_id: &'a str) -> Option<(&H, &'a str)> {
+        let (namespace, rest) = custom_id.split_once(NAMESPACE_SEPARATOR)?;
+
+        self.handlers.get(namespace).map(|handler| (handler, rest))
+    }
+}
+
+/// A namespace was registered twice with a [ComponentRouter]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateNamespace(pub Namespace);
+
+impl fmt::Display for DuplicateNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "namespace {} is already registered", self.0)
+    }
+}
+
+impl std::error::Error for DuplicateNamespace {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Feature {
+        Polls,
+        Tickets,
+    }
+
+    #[test]
+    pub fn routes_by_namespace() {
+        let mut router = ComponentRouter::new();
+        let polls = router
+            .register(Namespace::new("polls"), Feature::Polls)
+            .unwrap();
+        let tickets = router
+            .register(Namespace::new("tickets"), Feature::Tickets)
+            .unwrap();
+
+        // the same id in both features stays apart
+        let close_poll = polls.id("close");
+        let close_ticket = tickets.id("close");
+        assert_ne!(close_poll, close_ticket);
+
+        assert_eq!(router.route(&close_poll), Some((&Feature::Polls, "close")));
+        assert_eq!(
+            router.route(&close_ticket),
+            Some((&Feature::Tickets, "close"))
+        );
+
+        // only the first separator belongs to the namespace
+        assert_eq!(
+            router.route("tickets/open/general"),
+            Some((&Feature::Tickets, "open/general"))
+        );
+
+        assert_eq!(router.route("giveaways/enter"), None);
+        assert_eq!(router.route("close"), None);
+        assert_eq!(router.route("pollsclose"), None);
+    }
+
+    #[test]
+    pub fn duplicate_namespace() {
+        let mut router = ComponentRouter::new();
+        router
+            .register(Namespace::new("polls"), Feature::Polls)
+            .unwrap();
+
+        let err = router
+            .register(Namespace::new("polls"), Feature::Tickets)
+            .unwrap_err();
+
+        assert_eq!(err, DuplicateNamespace(Namespace::new("polls")));
+        assert_eq!(err.to_string(), "namespace polls is already registered");
+
+        // the first registration is kept
+        assert_eq!(router.route("polls/vote"), Some((&Feature::Polls, "vote")));
+    }
+
+    #[test]
+    pub fn strip() {
+        let polls = Namespace::new("polls");
+
+        assert_eq!(polls.strip("polls/vote:3"), Some("vote:3"));
+        assert_eq!(polls.strip("polls/"), Some(""));
+        assert_eq!(polls.strip("pollsters/vote"), None);
+        assert_eq!(polls.strip("tickets/polls/vote"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't contain")]
+    pub fn separator_in_namespace() {
+        Namespace::new("polls/admin");
+    }
+}
@@ -0,0 +1,313 @@
+use std::fmt;
+
+use crate::{
+    models::{InteractionResponse, MessageCallbackData, OptionList},
+    util::HandlerError,
+};
+
+/// Constraint between a command's options, checked with [OptionRules]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OptionRule {
+    /// when the first option is given the second must be too
+    Requires(&'static str, &'static str),
+
+    /// the two options can't be given together
+    MutuallyExclusive(&'static str, &'static str),
+
+    /// at least one of the options must be given
+    AtLeastOne(&'static [&'static str]),
+}
+
+impl OptionRule {
+    fn check(&self, options: &OptionList) -> bool {
+        let given = |name: &str| options.get_option(name).is_some();
+
+        match self {
+            OptionRule::Requires(option, required) => !given(option) || given(required),
+            OptionRule::MutuallyExclusive(a, b) => !(given(a) && given(b)),
+            OptionRule::AtLeastOne(names) => names.iter().any(|name| given(name)),
+        }
+    }
+}
+
+impl fmt::Display for OptionRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionRule::Requires(option, required) => {
+                write!(f, "`{}` requires `{}`", option, required)
+            }
+            OptionRule::MutuallyExclusive(a, b) => {
+                write!(f, "`{}` and `{}` can't be used together", a, b)
+            }
+            OptionRule::AtLeastOne(names) => {
+                let names = names
+                    .iter()
+                    .map(|name| format!("`{}`", name))
+                    .collect::<Vec<_>>();
+
+                write!(f, "Use at least one of {}", names.join(", "))
+            }
+        }
+    }
+}
+
+/// Rules a command's options must follow, checked before its handler runs
+///
+/// Options are looked up in the subcommand that was invoked, rules added with
+/// [rule_for](Self::rule_for) only apply to one subcommand path.
+///
+/// ```
+/// use composure_core::util::{OptionRule::*, OptionRules};
+///
+/// let rules = OptionRules::new()
+///     .rule(MutuallyExclusive("user", "role"))
+///     .rule(AtLeastOne(&["user", "role"]))
+///     .rule_for(&["timeout"], Requires("duration", "unit"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OptionRules {
+    rules: Vec<(&'static [&'static str], OptionRule)>,
+}
+
+impl OptionRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule checked whichever subcommand was invoked
+    pub fn rule(self, rule: OptionRule) -> Self {
+        self.rule_for(&[], rule)
+    }
+
+    /// Adds a rule only checked when `path` was invoked, like `&["settings", "reset"]` for a
+    /// subcommand in a group
+    pub fn rule_for(mut self, path: &'static [&'static str], rule: OptionRule) -> Self {
+        self.rules.push((path, rule));
+        self
+    }
+
+    /// The first rule `options` break, in the order they were added
+    pub fn check(&self, options: &OptionList) -> Result<(), OptionRuleError> {
+        let (path, options) = invoked(options);
+
+        match self
+            .rules
+            .iter()
+            .filter(|(rule_path, _)| rule_path.is_empty() || *rule_path == path.as_slice())
+            .find(|(_, rule)| !rule.check(options))
+        {
+            Some((_, rule)) => Err(OptionRuleError { rule: rule.clone() }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Names of the invoked subcommand group and subcommand, and the options given to it
+fn invoked(options: &OptionList) -> (Vec<&str>, &OptionList) {
+    if let Some(group) = options.subcommand_group() {
+        return (
+            vec![&group.name, &group.subcommand.name],
+            &group.subcommand.options,
+        );
+    }
+
+    match options.subcommand() {
+        Some(subcommand) => (vec![&subcommand.name], &subcommand.options),
+        None => (vec![], options),
+    }
+}
+
+/// A rule the options broke
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionRuleError {
+    pub rule: OptionRule,
+}
+
+impl OptionRuleError {
+    /// Ephemeral message explaining the broken rule to the user
+    pub fn to_response(&self) -> InteractionResponse {
+        InteractionResponse::ChannelMessageWithSource(
+            MessageCallbackData::new()
+                .with_content(self.to_string())
+                .ephemeral(),
+        )
+    }
+}
+
+impl fmt::Display for OptionRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.rule)
+    }
+}
+
+impl std::error::Error for OptionRuleError {}
+
+/// Broken rules are the user's mistake, so they are answered with the ephemeral message
+impl From<OptionRuleError> for HandlerError {
+    fn from(err: OptionRuleError) -> Self {
+        HandlerError::user_facing(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::util::OptionRule::*;
+
+    fn options(json: serde_json::Value) -> OptionList {
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn string(name: &str) -> serde_json::Value {
+        json!({"type": 3, "name": name, "value": "x"})
+    }
+
+    #[test]
+    pub fn rules() {
+        let cases = [
+            (Requires("duration", "unit"), vec![], true),
+            (Requires("duration", "unit"), vec!["unit"], true),
+            (Requires("duration", "unit"), vec!["duration", "unit"], true),
+            (Requires("duration", "unit"), vec!["duration"], false),
+            (MutuallyExclusive("user", "role"), vec![], true),
+            (MutuallyExclusive("user", "role"), vec!["user"], true),
+            (
+                MutuallyExclusive("user", "role"),
+                vec!["user", "role"],
+                false,
+            ),
+            (AtLeastOne(&["user", "role"]), vec!["role"], true),
+            (AtLeastOne(&["user", "role"]), vec!["reason"], false),
+            (AtLeastOne(&["user", "role"]), vec![], false),
+        ];
+
+        for (rule, given, ok) in cases {
+            let list = options(given.iter().map(|name| string(name)).collect());
+            let result = OptionRules::new().rule(rule.clone()).check(&list);
+
+            assert_eq!(result.is_ok(), ok, "{rule:?} with {given:?}");
+            if !ok {
+                assert_eq!(result.unwrap_err().rule, rule);
+            }
+        }
+    }
+
+    #[test]
+    pub fn messages() {
+        let cases = [
+            (Requires("duration", "unit"), "`duration` requires `unit`"),
+            (
+                MutuallyExclusive("user", "role"),
+                "`user` and `role` can't be used together",
+            ),
+            (
+                AtLeastOne(&["user", "role"]),
+                "Use at least one of `user`, `role`",
+            ),
+        ];
+
+        for (rule, message) in cases {
+            let err = OptionRuleError { rule };
+
+            assert_eq!(err.to_string(), message);
+            assert_eq!(
+                serde_json::to_value(err.to_response()).unwrap(),
+                json!({"type": 4, "data": {"content": message, "flags": 64}})
+            );
+
+            let err: HandlerError = err.into();
+            assert_eq!(err.to_string(), message);
+            assert!(err.response().is_some());
+        }
+    }
+
+    #[test]
+    pub fn first_broken_rule_wins() {
+        let rules = OptionRules::new()
+            .rule(MutuallyExclusive("user", "role"))
+            .rule(AtLeastOne(&["user", "role"]));
+
+        let err = rules.check(&options(json!([]))).unwrap_err();
+        assert_eq!(err.rule, AtLeastOne(&["user", "role"]));
+
+        let err = rules
+            .check(&options(json!([string("user"), string("role")])))
+            .unwrap_err();
+        assert_eq!(err.rule, MutuallyExclusive("user", "role"));
+    }
+
+    #[test]
+    pub fn resolves_subcommands() {
+        let rules = OptionRules::new()
+            .rule(AtLeastOne(&["user", "role"]))
+            .rule_for(&["timeout"], Requires("duration", "unit"))
+            .rule_for(&["settings", "reset"], MutuallyExclusive("all", "key"));
+
+        let timeout = |given: Vec<serde_json::Value>| {
+            options(json!([{"type": 1, "name": "timeout", "options": given}]))
+        };
+
+        assert!(rules
+            .check(&timeout(vec![
+                string("user"),
+                string("duration"),
+                string("unit")
+            ]))
+            .is_ok());
+        assert_eq!(
+            rules
+                .check(&timeout(vec![string("user"), string("duration")]))
+                .unwrap_err()
+                .rule,
+            Requires("duration", "unit")
+        );
+        assert_eq!(
+            rules.check(&timeout(vec![])).unwrap_err().rule,
+            AtLeastOne(&["user", "role"])
+        );
+
+        let reset = |given: Vec<serde_json::Value>| {
+            options(json!([{
+                "type": 2,
+                "name": "settings",
+                "options": [{"type": 1, "name": "reset", "options": given}]
+            }]))
+        };
+
+        assert_eq!(
+            rules
+                .check(&reset(vec![string("role"), string("all"), string("key")]))
+                .unwrap_err()
+                .rule,
+            MutuallyExclusive("all", "key")
+        );
+
+        // scoped to timeout, so a duration without a unit is fine under settings reset
+        assert!(rules
+            .check(&reset(vec![string("role"), string("duration")]))
+            .is_ok());
+
+        // options next to the invoked subcommand aren't looked at, `key` would break the rule
+        let beside = options(json!([
+            string("key"),
+            {
+                "type": 2,
+                "name": "settings",
+                "options": [{"type": 1, "name": "reset", "options": [string("role"), string("all")]}]
+            }
+        ]));
+        assert!(rules.check(&beside).is_ok());
+
+        // and can't satisfy one either
+        let beside = options(json!([
+            string("user"),
+            {"type": 1, "name": "timeout", "options": [string("duration"), string("unit")]}
+        ]));
+        assert_eq!(
+            rules.check(&beside).unwrap_err().rule,
+            AtLeastOne(&["user", "role"])
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt;
+
+/// Shown in place of a secret, like an interaction or webhook token, when debug printing
+///
+/// Only the first 8 characters are kept, enough to tell tokens apart in logs. The
+/// `unredacted-debug` feature prints the whole value for local debugging.
+pub struct Redacted<'a>(pub &'a str);
+
+impl fmt::Debug for Redacted<'_> {
+    #[cfg(not(feature = "unredacted-debug"))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.char_indices().nth(8) {
+            Some((end, _)) => write!(f, "\"{}…\"", &self.0[..end]),
+            None => write!(f, "{:?}", self.0),
+        }
+    }
+
+    #[cfg(feature = "unredacted-debug")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+#[cfg(all(test, not(feature = "unredacted-debug")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn keeps_first_eight_characters() {
+        let cases = [
+            ("", r#""""#),
+            ("short", r#""short""#),
+            ("12345678", r#""12345678""#),
+            ("123456789", r#""12345678…""#),
+            ("ééééééééé", r#""éééééééé…""#),
+        ];
+
+        for (secret, expected) in cases {
+            assert_eq!(format!("{:?}", Redacted(secret)), expected);
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::Snowflake,
+    util::{store::millis, StateStore},
+};
+
+/// Prefix of every key [Reminder::key] builds
+pub const REMINDER_PREFIX: &str = "reminder:";
+
+/// Message to send to a user once it is due, stored in a [StateStore] until then
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reminder {
+    /// when the reminder is due, milliseconds since the unix epoch
+    pub due_at: u64,
+
+    /// channel the reminder is sent to
+    pub channel_id: Snowflake,
+
+    /// user to remind
+    pub user_id: Snowflake,
+
+    pub content: String,
+}
+
+impl Reminder {
+    /// Reminder due `delay` after `now`
+    pub fn new(
+        now: SystemTime,
+        delay: Duration,
+        channel_id: Snowflake,
+        user_id: Snowflake,
+        content: impl Into<String>,
+    ) -> Self {
+        Reminder {
+            due_at: millis(now + delay),
+            channel_id,
+            user_id,
+            content: content.into(),
+        }
+    }
+
+    pub fn is_due(&self, now: SystemTime) -> bool {
+        self.due_at <= millis(now)
+    }
+
+    /// Key to store the reminder under, `id` tells apart reminders due at the same time
+    ///
+    /// Keys start with a snowflake built from the due time, zero padded so they sort by it. Pass
+    /// the id of the interaction that set the reminder as `id`.
+    pub fn key(&self, id: &Snowflake) -> String {
+        format!(
+            "{}{:020}:{}",
+            REMINDER_PREFIX,
+            Snowflake::from_timestamp(self.due_at).to_u64(),
+            id
+        )
+    }
+
+    /// Stores the reminder under its [key](Self::key) as JSON
+    pub fn save<S: StateStore>(&self, store: &mut S, id: &Snowflake) -> Result<String, S::Error> {
+        let key = self.key(id);
+        let value = serde_json::to_string(self).expect("reminders always serialize");
+
+        store.put(&key, value)?;
+        Ok(key)
+    }
+}
+
+/// Keys built by [Reminder::key] that are due at `now`, oldest first
+///
+/// For stores [deliver_due_reminders] can't use: list the keys starting with [REMINDER_PREFIX]
+/// in ascending order, then get, send and delete the reminders under the keys returned here.
+/// Keys that weren't built by [Reminder::key] are skipped.
+pub fn due_reminder_keys(keys: &[String], now: SystemTime) -> Vec<&str> {
+    let now = millis(now);
+
+    keys.iter()
+        .filter_map(|key| Some((key.as_str(), due_at(key)?)))
+        .take_while(|(_, due_at)| *due_at <= now)
+        .map(|(key, _)| key)
+        .collect()
+}
+
+/// Due time in milliseconds since the unix epoch of a key built by [Reminder::key]
+fn due_at(key: &str) -> Option<u64> {
+    let snowflake = key.strip_prefix(REMINDER_PREFIX)?.split(':').next()?;
+
+    Some(Snowflake::from_u64(snowflake.parse().ok()?).timestamp())
+}
+
+/// Sends a due [Reminder], implemented by the API client
+pub trait ReminderSender {
+    type Error;
+
+    fn send_reminder(&self, reminder: &Reminder) -> Result<(), Self::Error>;
+}
+
+/// Outcome of [deliver_due_reminders]
+#[derive(Debug)]
+pub struct ReminderDelivery<E> {
+    /// reminders sent and deleted from the store
+    pub delivered: Vec<Reminder>,
+
+    /// keys of reminders that failed to send, they stay in the store to retry on the next run
+    pub failed: Vec<(String, E)>,
+
+    /// keys whose value wasn't a reminder, deleted from the store
+    pub invalid: Vec<String>,
+}
+
+/// Sends every reminder due at `now` and deletes the ones that were sent, call it from a
+/// scheduled job
+///
+/// Reminders are sent oldest first and the scan stops at the first one that isn't due, so only
+/// due reminders are read. A store error stops delivery, reminders sent before it are deleted.
+///
+/// `store` and `sender` are synchronous, a worker whose store and requests are async, like
+/// Cloudflare KV from a cron trigger, finds the due keys with [due_reminder_keys] instead.
+pub fn deliver_due_reminders<S, R>(
+    store: &mut S,
+    sender: &R,
+    now: SystemTime,
+) -> Result<ReminderDelivery<R::Error>, S::Error>
+where
+    S: StateStore,
+    R: ReminderSender,
+{
+    let mut delivery = ReminderDelivery {
+        delivered: vec![],
+        failed: vec![],
+        invalid: vec![],
+    };
+
+    let now = millis(now);
+
+    for key in store.list(REMINDER_PREFIX)? {
+        match due_at(&key) {
+            Some(due_at) if due_at > now => break,
+            Some(_) => {}
+            None => {
+                store.delete(&key)?;
+                delivery.invalid.push(key);
+                continue;
+            }
+        }
+
+        let reminder = match store.get(&key)? {
+            Some(value) => serde_json::from_str::<Reminder>(&value).ok(),
+            // deleted since it was listed
+            None => continue,
+        };
+
+        let reminder = match reminder {
+            Some(reminder) => reminder,
+            None => {
+                store.delete(&key)?;
+                delivery.invalid.push(key);
+                continue;
+            }
+        };
+
+        match sender.send_reminder(&reminder) {
+            Ok(()) => {
+                store.delete(&key)?;
+                delivery.delivered.push(reminder);
+            }
+            Err(err) => delivery.failed.push((key, err)),
+        }
+    }
+
+    Ok(delivery)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, time::UNIX_EPOCH};
+
+    use super::*;
+    use crate::util::MemoryStore;
+
+    const BROKEN_CHANNEL: Snowflake = Snowflake::from_u64(300000000000000002);
+
+    /// Records what it sends, fails for [BROKEN_CHANNEL]
+    #[derive(Default)]
+    struct FakeSender {
+        sent: RefCell<Vec<String>>,
+    }
+
+    impl ReminderSender for FakeSender {
+        type Error = String;
+
+        fn send_reminder(&self, reminder: &Reminder) -> Result<(), Self::Error> {
+            if reminder.channel_id == BROKEN_CHANNEL {
+                return Err("Missing Access".to_string());
+            }
+
+            self.sent.borrow_mut().push(reminder.content.clone());
+            Ok(())
+        }
+    }
+
+    fn at(seconds: u64) -> SystemTime {
+        // 2023-11-14, after the Discord epoch
+        UNIX_EPOCH + Duration::from_secs(1_700_000_000 + seconds)
+    }
+
+    fn reminder(delay: u64, channel_id: Snowflake, content: &str) -> Reminder {
+        Reminder::new(
+            at(0),
+            Duration::from_secs(delay),
+            channel_id,
+            Snowflake::from_u64(400000000000000001),
+            content,
+        )
+    }
+
+    #[test]
+    pub fn keys_sort_by_due_time() {
+        let channel = Snowflake::from_u64(300000000000000001);
+        let id = Snowflake::from_u64(700000000000000001);
+
+        let soon = reminder(60, channel.clone(), "soon").key(&id);
+        let later = reminder(7200, channel, "later").key(&id);
+
+        assert!(soon.starts_with(REMINDER_PREFIX));
+        assert!(soon < later);
+        assert_eq!(due_at(&soon), Some(1_700_000_060_000));
+        assert!(due_at("reminder:oops").is_none());
+    }
+
+    #[test]
+    pub fn delivers_due_reminders() {
+        let channel = Snowflake::from_u64(300000000000000001);
+        let mut store = MemoryStore::new();
+
+        let reminders = [
+            reminder(60, channel.clone(), "stretch"),
+            reminder(120, BROKEN_CHANNEL, "water"),
+            reminder(180, channel.clone(), "tea"),
+            reminder(7200, channel, "sleep"),
+        ];
+
+        for (i, reminder) in reminders.iter().enumerate() {
+            reminder
+                .save(
+                    &mut store,
+                    &Snowflake::from_u64(700000000000000001 + i as u64),
+                )
+                .unwrap();
+        }
+
+        let sender = FakeSender::default();
+
+        // nothing due yet
+        let delivery = deliver_due_reminders(&mut store, &sender, at(30)).unwrap();
+        assert!(delivery.delivered.is_empty());
+        assert_eq!(store.len(), 4);
+
+        let delivery = deliver_due_reminders(&mut store, &sender, at(3600)).unwrap();
+
+        assert_eq!(*sender.sent.borrow(), ["stretch", "tea"]);
+        assert_eq!(
+            delivery.delivered,
+            [reminders[0].clone(), reminders[2].clone()]
+        );
+        assert!(delivery.invalid.is_empty());
+
+        // the failed reminder stays for the next run, the one not due yet is untouched
+        assert_eq!(delivery.failed.len(), 1);
+        assert_eq!(delivery.failed[0].1, "Missing Access");
+        assert_eq!(store.len(), 2);
+        assert!(store.get(&delivery.failed[0].0).unwrap().is_some());
+
+        let delivery = deliver_due_reminders(&mut store, &sender, at(3600)).unwrap();
+        assert!(delivery.delivered.is_empty());
+        assert_eq!(delivery.failed.len(), 1);
+    }
+
+    #[test]
+    pub fn due_keys() {
+        let channel = Snowflake::from_u64(300000000000000001);
+        let id = Snowflake::from_u64(700000000000000001);
+
+        let keys = [
+            reminder(60, channel.clone(), "stretch").key(&id),
+            "reminder:oops".to_string(),
+            reminder(180, channel.clone(), "tea").key(&id),
+            reminder(7200, channel, "sleep").key(&id),
+        ];
+
+        assert!(due_reminder_keys(&keys, at(30)).is_empty());
+        assert_eq!(
+            due_reminder_keys(&keys, at(3600)),
+            [keys[0].as_str(), keys[2].as_str()]
+        );
+    }
+
+    #[test]
+    pub fn drops_invalid_entries() {
+        let mut store = MemoryStore::new();
+        let key = reminder(60, BROKEN_CHANNEL, "").key(&Snowflake::from_u64(1));
+
+        store.put(&key, "not json".to_string()).unwrap();
+        store.put("reminder:oops", "{}".to_string()).unwrap();
+        store.put("other", "kept".to_string()).unwrap();
+
+        let delivery = deliver_due_reminders(&mut store, &FakeSender::default(), at(60)).unwrap();
+
+        assert_eq!(delivery.invalid.len(), 2);
+        assert_eq!(store.list("").unwrap(), ["other"]);
+    }
+}
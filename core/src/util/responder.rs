@@ -0,0 +1,210 @@
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use crate::models::{InteractionResponse, MessageCallbackData};
+
+/// Why an [InteractionResponder] refused a response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResponderError {
+    /// the interaction was already replied to or deferred, it can only be answered once
+    AlreadyResponded,
+
+    /// followups can only be sent once the interaction was replied to or deferred
+    NotAcknowledged,
+}
+
+impl fmt::Display for ResponderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResponderError::AlreadyResponded => write!(f, "interaction was already responded to"),
+            ResponderError::NotAcknowledged => {
+                write!(
+                    f,
+                    "interaction must be replied to or deferred before a followup"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResponderError {}
+
+/// Tracks the response to a single interaction so it is only answered once
+///
+/// An interaction gets exactly one initial response, either a [reply](Self::reply) or a
+/// [defer](Self::defer). Followups are only accepted after it, and are queued for the caller to
+/// send with the interaction's token once the initial response went out. Ordering mistakes come
+/// back as a [ResponderError] instead of an error from Discord.
+///
+/// Clones are handles to the same interaction, the adapter keeps one and hands another to the
+/// handler, then answers with the recorded [response](Self::take_response). Answering consumes
+/// the handle it was called on, any other handle gets [ResponderError::AlreadyResponded].
+#[derive(Debug, Clone, Default)]
+pub struct InteractionResponder {
+    state: Rc<RefCell<ResponderState>>,
+}
+
+#[derive(Debug, Default)]
+struct ResponderState {
+    response: Option<InteractionResponse>,
+    acknowledged: bool,
+    followups: Vec<MessageCallbackData>,
+}
+
+impl InteractionResponder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers the interaction with `response`
+    pub fn reply(self, response: InteractionResponse) -> Result<(), ResponderError> {
+        let mut state = self.state.borrow_mut();
+        if state.acknowledged {
+            return Err(ResponderError::AlreadyResponded);
+        }
+
+        state.response = Some(response);
+        state.acknowledged = true;
+        Ok(())
+    }
+
+    /// Acknowledges the interaction, the user sees a loading state until a followup or edit
+    pub fn defer(self) -> Result<(), ResponderError> {
+        self.reply(InteractionResponse::DeferredChannelMessageWithSource)
+    }
+
+    /// For components, acknowledges the interaction without a loading state
+    pub fn defer_update(self) -> Result<(), ResponderError> {
+        self.reply(InteractionResponse::DeferredUpdateMessage)
+    }
+
+    /// Queues a followup message, the interaction must have been replied to or deferred
+    pub fn followup(&self, message: MessageCallbackData) -> Result<(), ResponderError> {
+        let mut state = self.state.borrow_mut();
+        if !state.acknowledged {
+            return Err(ResponderError::NotAcknowledged);
+        }
+
+        state.followups.push(message);
+        Ok(())
+    }
+
+    pub fn has_responded(&self) -> bool {
+        self.state.borrow().acknowledged
+    }
+
+    pub fn is_deferred(&self) -> bool {
+        matches!(
+            self.state.borrow().response,
+            Some(InteractionResponse::DeferredChannelMessageWithSource)
+                | Some(InteractionResponse::DeferredUpdateMessage)
+        )
+    }
+
+    /// Followups queued so far, in order, leaving none queued
+    pub fn take_followups(&self) -> Vec<MessageCallbackData> {
+        std::mem::take(&mut self.state.borrow_mut().followups)
+    }
+
+    /// The initial response, to send back to Discord
+    ///
+    /// The interaction stays answered, replying through any handle afterwards still fails.
+    pub fn take_response(&self) -> Option<InteractionResponse> {
+        self.state.borrow_mut().response.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn reply_then_reply() {
+        let responder = InteractionResponder::new();
+
+        assert!(!responder.has_responded());
+        responder
+            .clone()
+            .reply(InteractionResponse::respond_with_message("first"))
+            .unwrap();
+
+        assert_eq!(
+            responder
+                .clone()
+                .reply(InteractionResponse::respond_with_message("second")),
+            Err(ResponderError::AlreadyResponded)
+        );
+        assert_eq!(
+            responder.clone().defer(),
+            Err(ResponderError::AlreadyResponded)
+        );
+
+        match responder.take_response() {
+            Some(InteractionResponse::ChannelMessageWithSource(data)) => {
+                assert_eq!(data.content.as_deref(), Some("first"))
+            }
+            other => panic!("unexpected response {:?}", other),
+        }
+
+        // taking the response doesn't allow a second answer
+        assert!(responder.has_responded());
+        assert_eq!(
+            responder.reply(InteractionResponse::respond_with_message("third")),
+            Err(ResponderError::AlreadyResponded)
+        );
+    }
+
+    #[test]
+    pub fn defer_then_followup() {
+        let responder = InteractionResponder::new();
+        let handler = responder.clone();
+
+        handler.clone().defer().unwrap();
+        assert!(responder.is_deferred());
+
+        handler
+            .followup(MessageCallbackData::new().with_content("one"))
+            .unwrap();
+        handler
+            .followup(MessageCallbackData::new().with_content("two").ephemeral())
+            .unwrap();
+
+        assert_eq!(
+            handler.reply(InteractionResponse::respond_with_message("late")),
+            Err(ResponderError::AlreadyResponded)
+        );
+
+        let followups = responder.take_followups();
+        assert_eq!(followups.len(), 2);
+        assert_eq!(followups[0].content.as_deref(), Some("one"));
+        assert!(followups[1].is_ephemeral());
+        assert!(responder.take_followups().is_empty());
+
+        assert!(matches!(
+            responder.take_response(),
+            Some(InteractionResponse::DeferredChannelMessageWithSource)
+        ));
+    }
+
+    #[test]
+    pub fn followup_before_ack() {
+        let responder = InteractionResponder::new();
+
+        assert_eq!(
+            responder.followup(MessageCallbackData::new().with_content("too early")),
+            Err(ResponderError::NotAcknowledged)
+        );
+        assert!(responder.take_followups().is_empty());
+
+        // acknowledging a component without a loading state counts too
+        responder.clone().defer_update().unwrap();
+        assert!(responder.is_deferred());
+        assert!(responder
+            .followup(MessageCallbackData::new().with_content("done"))
+            .is_ok());
+
+        assert_eq!(
+            ResponderError::NotAcknowledged.to_string(),
+            "interaction must be replied to or deferred before a followup"
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    collections::BTreeMap,
+    convert::Infallible,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Key-value storage for state that outlives a request, like a database table
+///
+/// Values are strings, encode anything else as JSON. [list](Self::list) returns keys in
+/// ascending order, so keys built to sort by time can be scanned oldest first. The methods are
+/// synchronous, stores with an async API like Cloudflare KV can't implement it.
+pub trait StateStore {
+    type Error;
+
+    fn get(&self, key: &str) -> Result<Option<String>, Self::Error>;
+
+    fn put(&mut self, key: &str, value: String) -> Result<(), Self::Error>;
+
+    /// Deleting a missing key succeeds
+    fn delete(&mut self, key: &str) -> Result<(), Self::Error>;
+
+    /// Keys starting with `prefix`, in ascending order
+    fn list(&self, prefix: &str) -> Result<Vec<String>, Self::Error>;
+}
+
+/// `time` in milliseconds since the unix epoch, how stored state keeps times
+pub(crate) fn millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+/// [StateStore] kept in memory, for tests and bots that don't need state to survive a restart
+#[derive(Debug, Default, Clone)]
+pub struct MemoryStore {
+    entries: BTreeMap<String, String>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl StateStore for MemoryStore {
+    type Error = Infallible;
+
+    fn get(&self, key: &str) -> Result<Option<String>, Self::Error> {
+        Ok(self.entries.get(key).cloned())
+    }
+
+    fn put(&mut self, key: &str, value: String) -> Result<(), Self::Error> {
+        self.entries.insert(key.to_string(), value);
+        Ok(())
+    }
+
+    fn delete(&mut self, key: &str) -> Result<(), Self::Error> {
+        self.entries.remove(key);
+        Ok(())
+    }
+
+    fn list(&self, prefix: &str) -> Result<Vec<String>, Self::Error> {
+        Ok(self
+            .entries
+            .range(prefix.to_string()..)
+            .map(|(key, _)| key)
+            .take_while(|key| key.starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn memory_store() {
+        let mut store = MemoryStore::new();
+
+        for key in ["b:2", "a:1", "b:1", "c:1"] {
+            store.put(key, key.to_uppercase()).unwrap();
+        }
+
+        assert_eq!(store.list("b:").unwrap(), ["b:1", "b:2"]);
+        assert_eq!(store.list("").unwrap().len(), 4);
+        assert!(store.list("d").unwrap().is_empty());
+
+        assert_eq!(store.get("b:2").unwrap().as_deref(), Some("B:2"));
+
+        store.delete("b:2").unwrap();
+        store.delete("missing").unwrap();
+
+        assert!(store.get("b:2").unwrap().is_none());
+        assert_eq!(store.len(), 3);
+    }
+}
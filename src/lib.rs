@@ -1,6 +1,36 @@
-pub mod auth;
-pub mod models;
+//! Single entry point for composure. Re-exports the interaction models, request validation, utilities and
+//! command builders, plus the Discord API client behind the `api` feature.
 
-pub trait Mentionable {
-    fn to_mention(&self) -> String;
+pub use composure_core::{auth, models, util, Error, Mentionable};
+
+#[cfg(feature = "test-util")]
+pub use composure_core::test_util;
+
+pub use composure_commands::command as commands;
+
+#[cfg(feature = "api")]
+pub use composure_api as api;
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    pub fn unified_paths() {
+        use crate::auth::validate_request;
+        use crate::commands::CommandsBuilder;
+        use crate::models::Snowflake;
+        use crate::util::parse_args;
+
+        let _ = CommandsBuilder::new(Snowflake::from_u64(1), None);
+        let _ = validate_request;
+        let _ = parse_args;
+    }
+
+    #[cfg(feature = "api")]
+    #[test]
+    pub fn unified_api_path() {
+        use crate::api::{DiscordClient, DISCORD_API};
+
+        let _ = DiscordClient::new;
+        assert!(DISCORD_API.starts_with("https://"));
+    }
 }
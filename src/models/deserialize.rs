@@ -1,15 +0,0 @@
-mod application;
-mod channel;
-mod interaction;
-mod member;
-mod message;
-mod role;
-mod sticker;
-
-pub use application::*;
-pub use channel::*;
-pub use interaction::*;
-pub use member::*;
-pub use message::*;
-pub use role::*;
-pub use sticker::*;
@@ -1,3 +0,0 @@
-mod interaction_response;
-
-pub use interaction_response::*;
@@ -0,0 +1,593 @@
+use std::{fs, path::PathBuf};
+
+use composure::models::{
+    parse_interaction_strict, ApplicationCommandInteractionDataOption, ApplicationCommandType,
+    Channel, ChannelOptionError, ChannelType, Component, FocusedValue, InstallContext, Interaction,
+    InteractionContextType, InteractionResponse, MessageComponentType, SelectedEntities,
+    SelectedMentionable, Snowflake,
+};
+use composure::util::ContextMenuRouter;
+use serde_json::{json, Value};
+
+fn fixtures_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+}
+
+fn load(name: &str) -> Interaction {
+    let path = fixtures_dir().join(format!("{name}.json"));
+    let json = fs::read_to_string(&path).unwrap();
+    serde_json::from_str(&json).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+}
+
+/// Declares a test that loads `tests/fixtures/<name>.json`, asserts it deserializes into the
+/// given `Interaction` variant, then runs the assertions in the block
+macro_rules! fixture {
+    ($name:ident, $variant:ident($binding:ident) => $body:block) => {
+        #[test]
+        pub fn $name() {
+            match load(stringify!($name)) {
+                Interaction::$variant($binding) => $body,
+                other => panic!("expected {} but got {:#?}", stringify!($variant), other),
+            }
+        }
+    };
+}
+
+#[test]
+pub fn all_fixtures_deserialize() {
+    let mut count = 0;
+    let mut failures = Vec::new();
+
+    for entry in fs::read_dir(fixtures_dir()).unwrap() {
+        let path = entry.unwrap().path();
+
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+
+        let json = fs::read_to_string(&path).unwrap();
+
+        // keep going so a regression shows every payload it breaks, not just the first
+        if let Err(e) = serde_json::from_str::<Interaction>(&json) {
+            failures.push(format!("{}: {}", path.display(), e));
+        }
+
+        count += 1;
+    }
+
+    assert!(count > 0);
+    assert!(
+        failures.is_empty(),
+        "{} of {} fixtures failed to deserialize:\n{}",
+        failures.len(),
+        count,
+        failures.join("\n")
+    );
+}
+
+fixture!(ping, Ping(ping) => {
+    assert_eq!(ping.common.application_id, Snowflake::from_u64(100000000000000001));
+    assert_eq!(ping.common.token, "A_UNIQUE_TOKEN");
+});
+
+fixture!(chat_input_command, ApplicationCommand(command) => {
+    assert_eq!(command.data.name, "search");
+    assert_eq!(command.data.t, ApplicationCommandType::ChatInput);
+
+    let options = &command.data.options;
+    assert_eq!(options.get_string_option("query").unwrap().value, "composure");
+    assert_eq!(options.get_integer_option("limit").unwrap().value, 10);
+
+    let user = options.get_user_option("user").unwrap();
+    assert_eq!(command.data.resolved_user(&user.value).unwrap().username, "other");
+    assert!(command.data.resolved_member(&user.value).is_some());
+
+    let (member, resolved_user) = command.data.resolved_member_with_user(&user.value).unwrap();
+    assert_eq!(member.nick.as_deref(), Some("nick"));
+    assert_eq!(resolved_user.id, user.value);
+
+    // the invoker is in `member`, not the resolved data
+    let invoker = &command.common.member.as_ref().unwrap().user.id;
+    assert!(command.data.resolved_member_with_user(invoker).is_none());
+});
+
+fixture!(chat_input_subcommand, ApplicationCommand(command) => {
+    let options = &command.data.options;
+    assert!(options.subcommand_group().is_none());
+
+    let subcommand = options.subcommand().unwrap();
+    assert_eq!(subcommand.name, "add");
+    assert_eq!(subcommand.options.get_string_option("text").unwrap().value, "water the plants");
+    assert_eq!(subcommand.options.get_integer_option("minutes").unwrap().value, 30);
+});
+
+fixture!(chat_input_subcommand_group, ApplicationCommand(command) => {
+    let group = command.data.options.subcommand_group().unwrap();
+    assert_eq!(group.name, "notifications");
+    assert_eq!(group.subcommand.name, "set");
+    assert!(group.subcommand.options.get_boolean_option("enabled").unwrap().value);
+
+    let channel = group.subcommand.options.get_channel_option("channel").unwrap();
+    let resolved = command.data.resolved.as_ref().unwrap().channels.as_ref().unwrap();
+    assert_eq!(resolved[&channel.value].t, ChannelType::GuildText);
+});
+
+fixture!(user_context_command, ApplicationCommand(command) => {
+    assert_eq!(command.data.t, ApplicationCommandType::User);
+
+    let target = command.data.target_id.as_ref().unwrap();
+    assert_eq!(command.data.resolved_user(target).unwrap().username, "other");
+
+    let target = command.data.target_user().unwrap();
+    assert_eq!(target.id, &Snowflake::from_u64(400000000000000002));
+    assert_eq!(target.user.username, "other");
+    assert!(target.member.is_some());
+    assert!(command.data.target_message().is_none());
+});
+
+fixture!(message_context_command, ApplicationCommand(command) => {
+    assert_eq!(command.data.t, ApplicationCommandType::Message);
+
+    let target = command.data.target_id.as_ref().unwrap();
+    let messages = command.data.resolved.as_ref().unwrap().messages.as_ref().unwrap();
+    assert_eq!(messages[target].content, "hello");
+
+    assert_eq!(command.data.target_message().unwrap().content, "hello");
+    assert!(command.data.target_user().is_none());
+});
+
+#[test]
+pub fn context_menu_router() {
+    let router = ContextMenuRouter::new()
+        .user_command("Profile", |prefix: &&str, target| {
+            assert!(target.member.is_some());
+            Ok(InteractionResponse::respond_with_message(format!(
+                "{prefix} {}",
+                target.user.username
+            )))
+        })
+        .message_command("Quote", |prefix: &&str, message| {
+            Ok(InteractionResponse::respond_with_message(format!(
+                "{prefix} {}",
+                message.content
+            )))
+        });
+
+    let cases = [
+        ("user_context_command", Some("> other")),
+        ("message_context_command", Some("> hello")),
+        ("chat_input_command", None),
+    ];
+
+    for (name, expected) in cases {
+        let command = match load(name) {
+            Interaction::ApplicationCommand(command) => command,
+            other => panic!("expected ApplicationCommand but got {:#?}", other),
+        };
+
+        let content = router
+            .route(&">", &command.data)
+            .map(|response| match response.unwrap() {
+                InteractionResponse::ChannelMessageWithSource(data) => data.content.unwrap(),
+                other => panic!("unexpected response {:?}", other),
+            });
+        assert_eq!(content.as_deref(), expected, "{name}");
+    }
+}
+
+fixture!(component_button, MessageComponent(component) => {
+    assert_eq!(component.data.custom_id, "click_one");
+    assert_eq!(component.data.component_type, MessageComponentType::Button);
+    assert!(component.data.values.is_none());
+
+    let message = component.common.message.as_ref().unwrap();
+    assert_eq!(message.content, "hello");
+    assert!(message.components.as_ref().unwrap().is_empty());
+
+    assert!(!component.reply().is_ephemeral());
+});
+
+fixture!(component_ephemeral, MessageComponent(component) => {
+    assert_eq!(component.data.custom_id, "show_more");
+
+    let reply = component.reply().with_content("more details");
+    assert!(reply.is_ephemeral());
+
+    let json = serde_json::to_value(InteractionResponse::ChannelMessageWithSource(reply)).unwrap();
+    assert_eq!(json["data"]["flags"], 64);
+    assert_eq!(json["data"]["content"], "more details");
+});
+
+fixture!(component_confirm, MessageComponent(component) => {
+    let rows = component.disable_components();
+    assert_eq!(rows.len(), 2);
+
+    let mut buttons = 0;
+    for component in rows.iter().flat_map(|r| r.components.iter()) {
+        match component {
+            Component::Button(button) => {
+                assert_eq!(button.disabled, Some(true), "{:?}", button.label);
+                buttons += 1;
+            }
+            Component::StringSelect(select) => assert_eq!(select.disabled, Some(true)),
+            other => panic!("unexpected component {:#?}", other),
+        }
+    }
+    assert_eq!(buttons, 3);
+
+    // the source message is left untouched
+    let original = component.common.message.as_ref().unwrap().components.as_ref().unwrap();
+    match &original[0].components[0] {
+        Component::Button(button) => assert_eq!(button.disabled, None),
+        other => panic!("unexpected component {:#?}", other),
+    }
+});
+
+fixture!(component_string_select, MessageComponent(component) => {
+    assert_eq!(component.data.component_type, MessageComponentType::StringSelect);
+    assert_eq!(component.data.values.as_ref().unwrap(), &["rogue", "mage"]);
+    assert!(component.data.resolved_selection().is_none());
+});
+
+fixture!(component_user_select, MessageComponent(component) => {
+    assert_eq!(component.data.component_type, MessageComponentType::UserSelect);
+    assert_eq!(component.data.values.as_ref().unwrap(), &["400000000000000002"]);
+
+    let selection = component.data.resolved_selection().unwrap();
+    assert!(selection.unresolved.is_empty());
+
+    match selection.selected {
+        SelectedEntities::Users(users) => {
+            let (id, user, member) = users[0];
+            assert_eq!(*id, Snowflake::from_u64(400000000000000002));
+            assert_eq!(user.username, "other");
+            assert!(member.is_some());
+        }
+        other => panic!("expected users but got {:?}", other),
+    }
+});
+
+fixture!(component_role_select, MessageComponent(component) => {
+    assert_eq!(component.data.component_type, MessageComponentType::RoleSelect);
+    assert_eq!(component.data.values.as_ref().unwrap(), &["500000000000000001"]);
+
+    match component.data.resolved_selection().unwrap().selected {
+        SelectedEntities::Roles(roles) => {
+            assert_eq!(roles.len(), 1);
+            assert_eq!(*roles[0].0, Snowflake::from_u64(500000000000000001));
+        }
+        other => panic!("expected roles but got {:?}", other),
+    }
+});
+
+fixture!(component_mentionable_select, MessageComponent(component) => {
+    assert_eq!(component.data.component_type, MessageComponentType::MentionableSelect);
+    assert_eq!(component.data.values.as_ref().unwrap().len(), 2);
+    assert_eq!(component.data.resolved_users().len(), 1);
+    assert_eq!(component.data.resolved_roles().len(), 1);
+
+    let selection = component.data.resolved_selection().unwrap();
+    assert!(selection.unresolved.is_empty());
+
+    match selection.selected {
+        SelectedEntities::Mentionables(picked) => {
+            assert!(matches!(
+                picked[0],
+                SelectedMentionable::User(id, _, _) if *id == Snowflake::from_u64(400000000000000002)
+            ));
+            assert!(matches!(
+                picked[1],
+                SelectedMentionable::Role(id, _) if *id == Snowflake::from_u64(500000000000000001)
+            ));
+        }
+        other => panic!("expected mentionables but got {:?}", other),
+    }
+});
+
+fixture!(component_channel_select, MessageComponent(component) => {
+    assert_eq!(component.data.component_type, MessageComponentType::ChannelSelect);
+    assert_eq!(component.data.values.as_ref().unwrap(), &["300000000000000001"]);
+
+    let channels = component.data.resolved_channels();
+    assert_eq!(channels.len(), 1);
+    assert_eq!(channels[0].name.as_deref(), Some("general"));
+    assert_eq!(channels[0].t, ChannelType::GuildText);
+    assert!(component.data.resolved_users().is_empty());
+
+    match component.data.resolved_selection().unwrap().selected {
+        SelectedEntities::Channels(channels) => {
+            assert_eq!(channels[0].1.t, ChannelType::GuildText);
+        }
+        other => panic!("expected channels but got {:?}", other),
+    }
+});
+
+fixture!(autocomplete, ApplicationCommandAutocomplete(autocomplete) => {
+    assert_eq!(autocomplete.data.name, "search");
+
+    match autocomplete.data.first_option().unwrap() {
+        ApplicationCommandInteractionDataOption::String(option) => {
+            assert_eq!(option.value, "comp");
+            assert_eq!(option.focused, Some(true));
+        }
+        other => panic!("expected string option but got {:#?}", other),
+    }
+
+    assert!(autocomplete.data.resolved.is_none());
+    assert_eq!(
+        autocomplete.data.focused_value(),
+        Some(FocusedValue::String("comp".to_string()))
+    );
+});
+
+fixture!(autocomplete_integer, ApplicationCommandAutocomplete(autocomplete) => {
+    assert!(autocomplete.data.resolved.is_none());
+
+    let focused = autocomplete.data.focused_option().unwrap();
+    assert_eq!(focused.name(), Some("page"));
+
+    assert_eq!(autocomplete.data.focused_value(), Some(FocusedValue::Integer(3)));
+});
+
+#[test]
+pub fn autocomplete_integer_values() {
+    let path = fixtures_dir().join("autocomplete_integer.json");
+    let mut json: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+
+    let cases = [
+        (json!("12"), Some(FocusedValue::Integer(12))),
+        (json!(" 12 "), Some(FocusedValue::Integer(12))),
+        (
+            json!("12a"),
+            Some(FocusedValue::Unparsed("12a".to_string())),
+        ),
+    ];
+
+    for (value, expected) in cases {
+        json["data"]["options"][0]["options"][1]["value"] = value.clone();
+
+        match serde_json::from_value(json.clone()).unwrap() {
+            Interaction::ApplicationCommandAutocomplete(autocomplete) => {
+                assert_eq!(autocomplete.data.focused_value(), expected, "{value}");
+
+                // the typed text is kept whether it parsed or not
+                let raw = match autocomplete.data.focused_option().unwrap() {
+                    ApplicationCommandInteractionDataOption::Integer(o) => o.raw_value(),
+                    ApplicationCommandInteractionDataOption::Unparsed(o) => Some(o.raw.as_str()),
+                    other => panic!("unexpected focused option {:?}", other),
+                };
+                assert_eq!(raw, value.as_str(), "{value}");
+            }
+            other => panic!(
+                "expected ApplicationCommandAutocomplete but got {:#?}",
+                other
+            ),
+        }
+    }
+}
+
+fixture!(modal_submit, ModalSubmit(modal) => {
+    assert_eq!(modal.data.custom_id, "feedback_modal");
+    assert_eq!(modal.data.components.len(), 2);
+
+    let title = &modal.data.components[0].components()[0];
+    assert_eq!(title.custom_id, "title");
+    assert_eq!(title.value.as_deref(), Some("Great bot"));
+});
+
+fixture!(dm_command, ApplicationCommand(command) => {
+    assert!(command.common.guild_id.is_none());
+    assert!(command.common.member.is_none());
+    assert_eq!(command.common.user.as_ref().unwrap().username, "user");
+    assert_eq!(command.common.user.as_ref().unwrap().effective_name(), "User");
+    assert_eq!(command.common.channel.as_ref().unwrap().t, ChannelType::Dm);
+});
+
+fixture!(user_installed_command, ApplicationCommand(command) => {
+    assert!(command.common.guild_id.is_none());
+    assert_eq!(command.common.user.as_ref().unwrap().username, "user");
+    assert_eq!(command.data.name, "ping");
+    assert_eq!(command.common.context, Some(InteractionContextType::PrivateChannel));
+    assert_eq!(command.common.install_context(), InstallContext::UserInstallInDm);
+});
+
+fixture!(user_installed_guild_command, ApplicationCommand(command) => {
+    let owners = command.common.authorizing_integration_owners.as_ref().unwrap();
+    assert_eq!(owners.user_install, Some(Snowflake::from_u64(400000000000000001)));
+    assert!(owners.guild_install.is_none());
+
+    assert_eq!(command.common.context, Some(InteractionContextType::Guild));
+    assert_eq!(command.common.install_context(), InstallContext::UserInstallInGuild);
+});
+
+fixture!(chat_input_channel_options, ApplicationCommand(command) => {
+    let thread = command.data.channel_option("thread").unwrap();
+    assert_eq!(thread.id, Snowflake::from_u64(300000000000000002));
+    assert!(thread.is_thread());
+    assert!(!thread.is_text());
+    assert!(thread.channel.thread_metadata.is_some());
+
+    let voice = command.data.channel_option("voice").unwrap();
+    assert!(voice.is_voice());
+    assert!(!voice.is_category());
+
+    assert!(command.data.channel_option("missing").is_none());
+
+    let voice = command
+        .data
+        .require_channel_of_type("voice", &[ChannelType::GuildVoice, ChannelType::GuildStageVoice])
+        .unwrap();
+    assert_eq!(voice.channel.name.as_deref(), Some("Lounge"));
+
+    let err = command
+        .data
+        .require_channel_of_type("thread", &[ChannelType::GuildText])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ChannelOptionError::WrongType {
+            name: "thread".to_string(),
+            expected: vec![ChannelType::GuildText],
+            found: ChannelType::PublicThread,
+        }
+    );
+
+    let err = command.data.require_channel_of_type("missing", &[]).unwrap_err();
+    assert_eq!(err, ChannelOptionError::Missing("missing".to_string()));
+
+    let thread_id = Snowflake::from_u64(300000000000000002);
+    let thread = command
+        .data
+        .resolved_channel_of_type(&thread_id, &[ChannelType::PublicThread, ChannelType::PrivateThread])
+        .unwrap();
+    assert_eq!(thread.id, thread_id);
+    assert!(command
+        .data
+        .resolved_channel_of_type(&thread_id, &[ChannelType::GuildText])
+        .is_none());
+    assert!(command
+        .data
+        .resolved_channel_of_type(&Snowflake::from_u64(1), &[ChannelType::PublicThread])
+        .is_none());
+});
+
+fixture!(resolved_channels, ApplicationCommand(command) => {
+    let text = command.data.channel_option("text").unwrap();
+    assert!(!text.is_thread());
+    assert_eq!(text.channel.nsfw, Some(true));
+    assert_eq!(text.channel.topic.as_deref(), Some("18+ only"));
+    assert_eq!(text.channel.position, Some(4));
+    assert_eq!(text.channel.last_message_id, Some(Snowflake::from_u64(600000000000000009)));
+
+    let mut channels = command.data.resolved.unwrap().channels.unwrap();
+
+    let thread = channels.remove(&Snowflake::from_u64(300000000000000002)).unwrap();
+    assert!(thread.is_thread());
+    assert_eq!(thread.nsfw, None);
+    assert_eq!(thread.guild_id, Some(Snowflake::from_u64(200000000000000001)));
+
+    let thread = Channel::from(thread);
+    assert_eq!(thread.t, ChannelType::PublicThread);
+    assert!(thread.thread_metadata.is_some());
+    assert_eq!(thread.parent_id, Some(Snowflake::from_u64(300000000000000001)));
+    assert!(thread.member.is_none());
+
+    let text: Channel = channels.remove(&Snowflake::from_u64(300000000000000004)).unwrap().into();
+    assert_eq!(text.nsfw, Some(true));
+    assert_eq!(text.name.as_deref(), Some("after-dark"));
+    assert!(text.permissions.is_some());
+    assert!(text.rate_limit_per_user.is_none());
+});
+
+#[test]
+pub fn dm_and_guild_detection() {
+    let common = |name| match load(name) {
+        Interaction::Ping(ping) => ping.common,
+        Interaction::ApplicationCommand(command) => command.common,
+        other => panic!("unexpected interaction {:#?}", other),
+    };
+
+    let dm = common("dm_command");
+    assert!(dm.is_dm());
+    assert!(!dm.is_guild());
+
+    let guild = common("chat_input_command");
+    assert!(guild.is_guild());
+    assert!(!guild.is_dm());
+}
+
+#[test]
+pub fn install_context() {
+    let cases = [
+        ("chat_input_command", InstallContext::GuildInstall),
+        // bot DMs are authorized by the guild install with an owner of 0
+        ("dm_command", InstallContext::GuildInstall),
+        // captured before user installs, no authorizing_integration_owners
+        ("real_command", InstallContext::GuildInstall),
+        (
+            "user_installed_guild_command",
+            InstallContext::UserInstallInGuild,
+        ),
+        ("user_installed_command", InstallContext::UserInstallInDm),
+    ];
+
+    for (name, expected) in cases {
+        let common = match load(name) {
+            Interaction::ApplicationCommand(command) => command.common,
+            other => panic!("unexpected interaction {:#?}", other),
+        };
+
+        assert_eq!(common.install_context(), expected, "{name}");
+    }
+}
+
+fixture!(real_command, ApplicationCommand(command) => {
+    assert_eq!(command.data.name, "ping");
+    assert_eq!(command.common.member.as_ref().unwrap().user.username, "BlueFrog");
+    // sent without an options key
+    assert!(command.data.options.is_empty());
+});
+
+fixture!(max_options_command, ApplicationCommand(command) => {
+    let options = &command.data.options;
+
+    for i in (0..25).step_by(5) {
+        assert_eq!(options.get_string_option(&format!("text_{i}")).unwrap().value, format!("value {i}"));
+        assert_eq!(options.get_integer_option(&format!("integer_{}", i + 1)).unwrap().value, i + 1);
+        assert!(options.get_user_option(&format!("user_{}", i + 2)).is_some());
+        assert!(options.get_role_option(&format!("role_{}", i + 3)).is_some());
+        assert!(options.get_channel_option(&format!("channel_{}", i + 4)).is_some());
+    }
+
+    // right name, wrong type
+    assert!(options.get_integer_option("text_0").is_none());
+    assert!(options.get_many(&["text_0", "channel_24", "text_25"]).iter().map(Option::is_some).eq([true, true, false]));
+});
+
+fixture!(large_command, ApplicationCommand(command) => {
+    let resolved = command.data.resolved.as_ref().unwrap();
+    assert_eq!(resolved.users.as_ref().unwrap().len(), 4);
+    assert_eq!(resolved.roles.as_ref().unwrap().len(), 4);
+    assert_eq!(resolved.channels.as_ref().unwrap().len(), 4);
+    assert!(command.data.options.get_string_option("text_15").is_some());
+});
+
+#[test]
+pub fn strict_reports_unknown_fields() {
+    let json = fs::read_to_string(fixtures_dir().join("unknown_fields.json")).unwrap();
+    let (interaction, mut unknown) = parse_interaction_strict(&json).unwrap();
+
+    unknown.sort();
+
+    assert_eq!(
+        unknown,
+        [
+            "data.novel_data",
+            "data.options[0].options[0].novel_option",
+            "novel_root",
+            "user.novel_user",
+        ]
+    );
+
+    match interaction {
+        Interaction::ApplicationCommand(command) => {
+            let subcommand = command.data.options.subcommand().unwrap();
+            assert_eq!(
+                subcommand.options.get_string_option("key").unwrap().value,
+                "theme"
+            );
+        }
+        other => panic!("expected ApplicationCommand but got {:#?}", other),
+    }
+}
+
+#[test]
+pub fn strict_parse_still_fails_on_invalid() {
+    let json = fs::read_to_string(fixtures_dir().join("ping.json")).unwrap();
+    let (interaction, _) = parse_interaction_strict(&json).unwrap();
+
+    assert!(matches!(interaction, Interaction::Ping(_)));
+    assert!(parse_interaction_strict("{}").is_err());
+}